@@ -0,0 +1,167 @@
+// Simulated network conditions applied to the packets received by the client.
+// Picked from the command line, the lobby or the play scene debug panel.
+use std::time::Duration;
+
+use bevy::prelude::*;
+use lightyear::prelude::*;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NetworkPreset {
+    #[default]
+    Lan,
+    Good,
+    Average,
+    Poor,
+    Custom,
+}
+
+impl NetworkPreset {
+    pub const ALL: [NetworkPreset; 4] = [
+        NetworkPreset::Lan,
+        NetworkPreset::Good,
+        NetworkPreset::Average,
+        NetworkPreset::Poor,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            NetworkPreset::Lan => "LAN",
+            NetworkPreset::Good => "Good",
+            NetworkPreset::Average => "Average",
+            NetworkPreset::Poor => "Poor",
+            NetworkPreset::Custom => "Custom",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|preset| preset.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Next preset in [`NetworkPreset::ALL`], custom conditions go back to LAN
+    pub fn next(&self) -> Self {
+        match Self::ALL.iter().position(|preset| preset == self) {
+            Some(i) => Self::ALL[(i + 1) % Self::ALL.len()],
+            None => NetworkPreset::Lan,
+        }
+    }
+}
+
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct NetworkConditions {
+    pub preset: NetworkPreset,
+    pub latency: Duration,
+    pub jitter: Duration,
+    /// Ratio of dropped packets, between 0 and 1
+    pub loss: f32,
+}
+
+impl Default for NetworkConditions {
+    fn default() -> Self {
+        Self::from_preset(NetworkPreset::Lan)
+    }
+}
+
+impl NetworkConditions {
+    pub fn from_preset(preset: NetworkPreset) -> Self {
+        let (latency, jitter, loss) = match preset {
+            NetworkPreset::Lan | NetworkPreset::Custom => (0, 0, 0.),
+            NetworkPreset::Good => (40, 6, 0.002),
+            NetworkPreset::Average => (100, 15, 0.02),
+            NetworkPreset::Poor => (200, 30, 0.1),
+        };
+        Self {
+            preset,
+            latency: Duration::from_millis(latency),
+            jitter: Duration::from_millis(jitter),
+            loss,
+        }
+    }
+
+    /// `--net <lan|good|average|poor>` picks a preset,
+    /// `--latency <ms>`, `--jitter <ms>` and `--loss <percent>` override its values
+    pub fn from_args(args: &[String]) -> Self {
+        let arg_value = |name: &str| {
+            args.iter()
+                .position(|arg| arg == name)
+                .and_then(|i| args.get(i + 1))
+        };
+
+        let mut conditions = arg_value("--net")
+            .and_then(|name| NetworkPreset::from_name(name))
+            .map(Self::from_preset)
+            .unwrap_or_default();
+
+        if let Some(latency) = arg_value("--latency").and_then(|ms| ms.parse().ok()) {
+            conditions.set_latency(Duration::from_millis(latency));
+        }
+        if let Some(jitter) = arg_value("--jitter").and_then(|ms| ms.parse().ok()) {
+            conditions.set_jitter(Duration::from_millis(jitter));
+        }
+        if let Some(loss) = arg_value("--loss").and_then(|percent| percent.parse::<f32>().ok()) {
+            conditions.set_loss(loss / 100.);
+        }
+
+        conditions
+    }
+
+    pub fn set_latency(&mut self, latency: Duration) {
+        self.preset = NetworkPreset::Custom;
+        self.latency = latency;
+    }
+
+    pub fn set_jitter(&mut self, jitter: Duration) {
+        self.preset = NetworkPreset::Custom;
+        self.jitter = jitter;
+    }
+
+    pub fn set_loss(&mut self, loss: f32) {
+        self.preset = NetworkPreset::Custom;
+        self.loss = loss.clamp(0., 1.);
+    }
+
+    pub fn link_conditioner(&self) -> LinkConditionerConfig {
+        LinkConditionerConfig {
+            incoming_latency: self.latency,
+            incoming_jitter: self.jitter,
+            incoming_loss: self.loss,
+        }
+    }
+
+    pub fn label(&self) -> String {
+        format!(
+            "NET: {} {}ms ±{}ms {:.1}% loss",
+            self.preset.name(),
+            self.latency.as_millis(),
+            self.jitter.as_millis(),
+            self.loss * 100.
+        )
+    }
+}
+
+/// Replace the link conditioner of the client config, used by the next connection
+pub fn set_link_conditioner(
+    client_config: &mut client::ClientConfig,
+    conditions: &NetworkConditions,
+) {
+    if let client::NetConfig::Netcode { io, .. } = &mut client_config.net {
+        io.conditioner = Some(conditions.link_conditioner());
+    }
+}
+
+/// Text displaying the current [`NetworkConditions`]
+#[derive(Component)]
+pub struct NetworkConditionsText;
+
+pub fn update_network_conditions_text(
+    conditions: Res<NetworkConditions>,
+    mut text_q: Query<&mut Text, With<NetworkConditionsText>>,
+) {
+    if !conditions.is_changed() {
+        return;
+    }
+    for mut text in text_q.iter_mut() {
+        text.0 = conditions.label();
+    }
+}
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use conditioner::*;
 use lightyear::prelude::{client::*, *};
 use rand::Rng;
 use lerp_common_game::prelude::*;
@@ -6,7 +7,8 @@ use std::net::IpAddr;
 use std::net::Ipv4Addr;
 use std::net::SocketAddr;
 use std::str::FromStr;
-use std::time::Duration;
+
+pub mod conditioner;
 
 fn display_network_status(state: Res<State<NetworkingState>>) {
     if state.is_changed() {
@@ -27,16 +29,15 @@ fn display_network_status(state: Res<State<NetworkingState>>) {
     }
 }
 
-pub fn get_client_net_config(server_address: IpAddr, port: u16) -> client::NetConfig {
+pub fn get_client_net_config(
+    server_address: IpAddr,
+    port: u16,
+    conditions: &NetworkConditions,
+) -> client::NetConfig {
     let mut rng = rand::rng();
     let client_id = rng.random_range(1..10001);
 
-    // let link_conditioner = LinkConditionerConfig::good_condition();
-    let link_conditioner = LinkConditionerConfig {
-        incoming_latency: Duration::from_millis(0),
-        incoming_jitter: Duration::from_millis(0),
-        incoming_loss: 0.00,
-    };
+    let link_conditioner = conditions.link_conditioner();
 
     // let server_addr = SocketAddr::new(IpAddr::from_str("15.237.150.220").unwrap(), 34255);
     let server_addr = SocketAddr::new(server_address, port);
@@ -68,24 +69,29 @@ pub struct LightyearPlugin;
 
 impl Plugin for LightyearPlugin {
     fn build(&self, app: &mut App) {
+        let args: Vec<String> = std::env::args().collect();
+        let conditions = NetworkConditions::from_args(&args);
+
         let client_config = client::ClientConfig {
             shared: shared_config(),
-            net: get_client_net_config(IpAddr::from_str("127.0.0.1").unwrap(), 34255),
+            net: get_client_net_config(IpAddr::from_str("127.0.0.1").unwrap(), 34255, &conditions),
             replication: ReplicationConfig {
                 send_updates_mode: SendUpdatesMode::SinceLastAck,
             },
-            prediction: client::PredictionConfig {
-                minimum_input_delay_ticks: 6,
-                maximum_input_delay_before_prediction: 6,
-                maximum_predicted_ticks: 100,
-                ..default()
-            },
+            prediction: client_prediction_config(),
             ..default()
         };
 
         let client_plugin = client::ClientPlugins::new(client_config);
         app.add_plugins(client_plugin);
         app.add_plugins(SharedPlugin);
-        app.add_systems(Update, display_network_status);
+        app.init_resource::<JoinMode>();
+        app.init_resource::<Username>();
+        app.insert_resource(conditions);
+        app.add_systems(
+            Update,
+            (display_network_status, update_network_conditions_text),
+        );
+        app.add_systems(OnEnter(NetworkingState::Connected), send_join_game);
     }
 }
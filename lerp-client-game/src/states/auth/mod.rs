@@ -3,6 +3,7 @@ use crate::ui::text_input::create_text_input;
 use crate::ui::*;
 use bevy::prelude::*;
 use bevy_simple_text_input::*;
+use lerp_common_game::prelude::*;
 
 #[derive(Component)]
 struct AuthSceneTag;
@@ -111,6 +112,7 @@ fn auth_scene_logic(
 
 fn auth_scene_button_logic(
     mut app_state: ResMut<NextState<AppState>>,
+    mut username_res: ResMut<Username>,
     mut app_exit_events: EventWriter<AppExit>,
     mut interaction_query: Query<
         (&Interaction, &ButtonAction),
@@ -127,6 +129,7 @@ fn auth_scene_button_logic(
                     let password = text_input_password_query.get_single().unwrap();
                     println!("Username: {}", username.0);
                     println!("Password: {}", password.0);
+                    username_res.0 = username.0.clone();
                     app_state.set(AppState::Lobby);
                 }
                 ButtonAction::Exit => {
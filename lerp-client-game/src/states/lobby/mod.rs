@@ -2,6 +2,7 @@ use std::net::IpAddr;
 use std::str::FromStr;
 
 use crate::common::*;
+use crate::lightyear::conditioner::*;
 use crate::lightyear::get_client_net_config;
 use crate::ui::text_input::create_text_input;
 use crate::ui::*;
@@ -9,6 +10,9 @@ use bevy::prelude::*;
 use bevy_simple_text_input::TextInputValue;
 use lerp_common_game::prelude::*;
 use lightyear::client::config::ClientConfig;
+use party::*;
+
+mod party;
 
 #[derive(Component)]
 pub struct LobbySceneTag;
@@ -16,17 +20,29 @@ pub struct LobbySceneTag;
 #[derive(Component)]
 enum ButtonAction {
     Play,
+    Spectate,
     Logout,
     ToggleDebugShowCollider,
     ToggleDebugShowConfirmed,
     ToggleDebugShowFlowField,
     ToggleDebugShowYSortBoundaries,
+    NetworkPreset(NetworkPreset),
 }
 
 #[derive(Component)]
 struct TextInputServerAddress;
 
-pub fn lobby_scene_setup(mut commands: Commands, debug_config: Res<DebugConfig>) {
+#[derive(Component)]
+struct TextInputInstancePort;
+
+#[derive(Component)]
+struct TextInputMap;
+
+pub fn lobby_scene_setup(
+    mut commands: Commands,
+    debug_config: Res<DebugConfig>,
+    network_conditions: Res<NetworkConditions>,
+) {
     println!("[lobby_scene_setup]");
 
     commands.spawn((LobbySceneTag, Camera2d));
@@ -51,9 +67,25 @@ pub fn lobby_scene_setup(mut commands: Commands, debug_config: Res<DebugConfig>)
         "Server Address".to_string(),
         Some("127.0.0.1".to_string()),
     );
-    commands
-        .entity(container)
-        .add_children(&[text_input_server_address_entity]);
+    let text_input_instance_port_entity = create_text_input(
+        &mut commands,
+        TextInputInstancePort,
+        "Instance port (spectate)".to_string(),
+        None,
+    );
+    let text_input_map_entity = create_text_input(
+        &mut commands,
+        TextInputMap,
+        "Map".to_string(),
+        Some(DEFAULT_MAP_NAME.to_string()),
+    );
+    let party_panel_entity = party_panel_setup(&mut commands);
+    commands.entity(container).add_children(&[
+        text_input_server_address_entity,
+        text_input_instance_port_entity,
+        text_input_map_entity,
+        party_panel_entity,
+    ]);
 
     commands.entity(container).with_children(|parent| {
         parent
@@ -76,6 +108,26 @@ pub fn lobby_scene_setup(mut commands: Commands, debug_config: Res<DebugConfig>)
                 parent.spawn(Text("Play".to_string()));
             });
 
+        parent
+            .spawn((
+                ButtonAction::Spectate,
+                Button,
+                BorderColor(Color::BLACK),
+                BorderRadius::MAX,
+                BackgroundColor(NORMAL_BUTTON),
+                Node {
+                    width: Val::Px(150.0),
+                    height: Val::Px(65.0),
+                    border: UiRect::all(Val::Px(5.0)),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+            ))
+            .with_children(|parent| {
+                parent.spawn(Text("Spectate".to_string()));
+            });
+
         parent
             .spawn((
                 ButtonAction::Logout,
@@ -120,6 +172,43 @@ pub fn lobby_scene_setup(mut commands: Commands, debug_config: Res<DebugConfig>)
             ButtonAction::ToggleDebugShowYSortBoundaries,
             debug_config.show_y_sort_boundaries,
         );
+
+        parent.spawn((
+            NetworkConditionsText,
+            Text(network_conditions.label()),
+            TextFont::from_font_size(12.),
+        ));
+        parent
+            .spawn(Node {
+                flex_direction: FlexDirection::Row,
+                column_gap: Val::Px(5.0),
+                ..default()
+            })
+            .with_children(|parent| {
+                for preset in NetworkPreset::ALL {
+                    parent
+                        .spawn((
+                            ButtonAction::NetworkPreset(preset),
+                            Button,
+                            BorderColor(Color::BLACK),
+                            BackgroundColor(NORMAL_BUTTON),
+                            Node {
+                                width: Val::Px(70.0),
+                                height: Val::Px(30.0),
+                                border: UiRect::all(Val::Px(2.0)),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn((
+                                Text(preset.name().to_string()),
+                                TextFont::from_font_size(12.),
+                            ));
+                        });
+                }
+            });
     });
 }
 
@@ -172,12 +261,25 @@ fn lobby_scene_button_logic(
     tokio_runtime: ResMut<TokioTasksRuntime>,
     mut app_state: ResMut<NextState<AppState>>,
     mut debug_config: ResMut<DebugConfig>,
+    mut join_mode: ResMut<JoinMode>,
+    mut network_conditions: ResMut<NetworkConditions>,
+    mut lightyear_client_config: ResMut<ClientConfig>,
+    username: Res<Username>,
     mut interaction_query: Query<
         (&Interaction, &ButtonAction, Option<&mut Checkbox>),
         (Changed<Interaction>, With<Button>),
     >,
     text_input_server_address_query: Query<&TextInputValue, With<TextInputServerAddress>>,
+    text_input_instance_port_query: Query<&TextInputValue, With<TextInputInstancePort>>,
+    text_input_map_query: Query<&TextInputValue, With<TextInputMap>>,
 ) {
+    // The server validates the map
+    let map = text_input_map_query
+        .get_single()
+        .map(|map| map.0.trim().to_string())
+        .ok()
+        .filter(|map| !map.is_empty());
+
     for (interaction, action, checkbox) in &mut interaction_query {
         match *interaction {
             Interaction::Pressed => match action {
@@ -187,10 +289,17 @@ fn lobby_scene_button_logic(
                         println!("Invalid server address");
                         break;
                     };
+                    *join_mode = JoinMode::Player;
+                    let network_conditions = network_conditions.clone();
+                    let input = HttpStartServerInput {
+                        username: username.0.clone(),
+                        map: map.clone(),
+                    };
 
                     tokio_runtime.spawn_background_task(move |mut ctx| async move {
                         let response = reqwest::Client::new()
                             .post(format!("http://{}:4000/server/start", server_address))
+                            .json(&input)
                             .send()
                             .await
                             .unwrap()
@@ -198,17 +307,25 @@ fn lobby_scene_button_logic(
                             .await
                             .unwrap();
                         info!(
-                            "Server: uuid: {} port: {}",
-                            response.instance_uuid, response.instance_port
+                            "Server: uuid: {} port: {} map: {}",
+                            response.instance_uuid, response.instance_port, response.map
                         );
+                        // No port left, an unknown map, or a party member that is not the leader
+                        if response.instance_port == 0 {
+                            println!("Could not start an instance");
+                            return;
+                        }
 
                         ctx.run_on_main_thread(move |ctx| {
                             let mut lightyear_client_config = ctx
                                 .world
                                 .get_resource_mut::<ClientConfig>()
                                 .expect("Lightyear ClientConfig resource not initialized");
-                            lightyear_client_config.net =
-                                get_client_net_config(server_address, response.instance_port);
+                            lightyear_client_config.net = get_client_net_config(
+                                server_address,
+                                response.instance_port,
+                                &network_conditions,
+                            );
 
                             let mut app_state = ctx
                                 .world
@@ -220,6 +337,24 @@ fn lobby_scene_button_logic(
                         .await;
                     });
                 }
+                ButtonAction::Spectate => {
+                    let server_address = text_input_server_address_query.get_single().unwrap();
+                    let Ok(server_address) = IpAddr::from_str(server_address.0.as_str()) else {
+                        println!("Invalid server address");
+                        break;
+                    };
+                    let instance_port = text_input_instance_port_query.get_single().unwrap();
+                    let Ok(instance_port) = instance_port.0.parse::<u16>() else {
+                        println!("Invalid instance port");
+                        break;
+                    };
+
+                    // Join an already running instance instead of starting a new one
+                    *join_mode = JoinMode::Spectator;
+                    lightyear_client_config.net =
+                        get_client_net_config(server_address, instance_port, &network_conditions);
+                    app_state.set(AppState::Play);
+                }
                 ButtonAction::Logout => {
                     app_state.set(AppState::Auth);
                 }
@@ -247,6 +382,9 @@ fn lobby_scene_button_logic(
                         debug_config.show_y_sort_boundaries = checkbox.checked;
                     };
                 }
+                ButtonAction::NetworkPreset(preset) => {
+                    *network_conditions = NetworkConditions::from_preset(*preset);
+                }
             },
             Interaction::Hovered => {}
             Interaction::None => {}
@@ -265,6 +403,7 @@ pub struct LobbyPlugin;
 
 impl Plugin for LobbyPlugin {
     fn build(&self, app: &mut App) {
+        app.add_plugins(LobbyPartyPlugin);
         app.add_systems(OnEnter(AppState::Lobby), lobby_scene_setup);
         app.add_systems(Update, lobby_scene_logic.run_if(in_state(AppState::Lobby)));
         app.add_systems(
@@ -0,0 +1,261 @@
+// Party panel of the lobby, the party state is owned by the HTTP API and polled.
+use std::net::IpAddr;
+use std::str::FromStr;
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::common_conditions::on_timer;
+use bevy_simple_text_input::TextInputValue;
+use lerp_common_game::prelude::*;
+use serde::Serialize;
+
+use crate::common::AppState;
+use crate::ui::text_input::create_text_input;
+use crate::ui::NORMAL_BUTTON;
+
+use super::TextInputServerAddress;
+
+#[derive(Resource, Default)]
+struct LobbyParty(HttpPartyResponse);
+
+#[derive(Component)]
+struct PartyStatusText;
+
+#[derive(Component)]
+struct TextInputPartyMember;
+
+#[derive(Component)]
+enum PartyButtonAction {
+    Create,
+    Invite,
+    AcceptInvite,
+    MakeLeader,
+    Leave,
+}
+
+/// Party panel, to be added to the lobby container
+pub(super) fn party_panel_setup(commands: &mut Commands) -> Entity {
+    let text_input_party_member = create_text_input(
+        commands,
+        TextInputPartyMember,
+        "Party member".to_string(),
+        None,
+    );
+
+    let panel = commands
+        .spawn(Node {
+            align_items: AlignItems::Center,
+            flex_direction: FlexDirection::Column,
+            margin: UiRect::top(Val::Px(10.)),
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                PartyStatusText,
+                Text("No party".to_string()),
+                TextFont::from_font_size(12.),
+            ));
+        })
+        .add_child(text_input_party_member)
+        .id();
+
+    commands.entity(panel).with_children(|parent| {
+        parent
+            .spawn(Node {
+                flex_direction: FlexDirection::Row,
+                column_gap: Val::Px(5.0),
+                ..default()
+            })
+            .with_children(|parent| {
+                for (action, label) in [
+                    (PartyButtonAction::Create, "Create party"),
+                    (PartyButtonAction::Invite, "Invite"),
+                    (PartyButtonAction::AcceptInvite, "Accept invite"),
+                    (PartyButtonAction::MakeLeader, "Make leader"),
+                    (PartyButtonAction::Leave, "Leave party"),
+                ] {
+                    parent
+                        .spawn((
+                            action,
+                            Button,
+                            BorderColor(Color::BLACK),
+                            BackgroundColor(NORMAL_BUTTON),
+                            Node {
+                                width: Val::Px(90.0),
+                                height: Val::Px(30.0),
+                                border: UiRect::all(Val::Px(2.0)),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn((Text(label.to_string()), TextFont::from_font_size(12.)));
+                        });
+                }
+            });
+    });
+
+    panel
+}
+
+/// Post to the party API and store the returned party state
+fn send_party_request<T: Serialize + Send + 'static>(
+    tokio_runtime: &TokioTasksRuntime,
+    server_address: IpAddr,
+    path: &'static str,
+    input: T,
+) {
+    tokio_runtime.spawn_background_task(move |mut ctx| async move {
+        let response = match reqwest::Client::new()
+            .post(format!("http://{}:4000/party/{}", server_address, path))
+            .json(&input)
+            .send()
+            .await
+        {
+            Ok(response) => response.json::<HttpPartyResponse>().await,
+            Err(err) => Err(err),
+        };
+        let response = match response {
+            Ok(response) => response,
+            Err(err) => {
+                println!("[send_party_request] /party/{} failed: {}", path, err);
+                return;
+            }
+        };
+
+        ctx.run_on_main_thread(move |ctx| {
+            ctx.world.insert_resource(LobbyParty(response));
+        })
+        .await;
+    });
+}
+
+fn server_address(
+    text_input_server_address_query: &Query<&TextInputValue, With<TextInputServerAddress>>,
+) -> Option<IpAddr> {
+    let server_address = text_input_server_address_query.get_single().ok()?;
+    IpAddr::from_str(server_address.0.as_str()).ok()
+}
+
+fn poll_party(
+    tokio_runtime: Res<TokioTasksRuntime>,
+    username: Res<Username>,
+    text_input_server_address_query: Query<&TextInputValue, With<TextInputServerAddress>>,
+) {
+    let Some(server_address) = server_address(&text_input_server_address_query) else {
+        return;
+    };
+    let input = HttpPartyInput {
+        username: username.0.clone(),
+    };
+    send_party_request(&tokio_runtime, server_address, "get", input);
+}
+
+fn party_button_logic(
+    tokio_runtime: Res<TokioTasksRuntime>,
+    username: Res<Username>,
+    lobby_party: Res<LobbyParty>,
+    interaction_query: Query<(&Interaction, &PartyButtonAction), Changed<Interaction>>,
+    text_input_server_address_query: Query<&TextInputValue, With<TextInputServerAddress>>,
+    text_input_party_member_query: Query<&TextInputValue, With<TextInputPartyMember>>,
+) {
+    let username = username.0.clone();
+    let member = text_input_party_member_query
+        .get_single()
+        .map(|member| member.0.trim().to_string())
+        .unwrap_or_default();
+
+    for (interaction, action) in interaction_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Some(server_address) = server_address(&text_input_server_address_query) else {
+            println!("Invalid server address");
+            continue;
+        };
+
+        let username = username.clone();
+        let member = member.clone();
+        match action {
+            PartyButtonAction::Create => {
+                let input = HttpPartyInput { username };
+                send_party_request(&tokio_runtime, server_address, "create", input);
+            }
+            PartyButtonAction::Invite => {
+                let input = HttpPartyMemberInput { username, member };
+                send_party_request(&tokio_runtime, server_address, "invite", input);
+            }
+            PartyButtonAction::AcceptInvite => {
+                let Some(invite) = lobby_party.0.invites.first() else {
+                    println!("No party invite");
+                    continue;
+                };
+                let input = HttpPartyAcceptInput {
+                    username,
+                    party_id: invite.party_id,
+                };
+                send_party_request(&tokio_runtime, server_address, "accept", input);
+            }
+            PartyButtonAction::MakeLeader => {
+                let input = HttpPartyMemberInput { username, member };
+                send_party_request(&tokio_runtime, server_address, "leader", input);
+            }
+            PartyButtonAction::Leave => {
+                let input = HttpPartyInput { username };
+                send_party_request(&tokio_runtime, server_address, "leave", input);
+            }
+        }
+    }
+}
+
+fn update_party_status_text(
+    lobby_party: Res<LobbyParty>,
+    mut text_q: Query<&mut Text, With<PartyStatusText>>,
+) {
+    if !lobby_party.is_changed() {
+        return;
+    }
+
+    let mut lines = Vec::new();
+    match &lobby_party.0.party {
+        Some(party) => {
+            lines.push(format!(
+                "Party of {}: {}",
+                party.leader,
+                party.members.join(", ")
+            ));
+            if !party.invited.is_empty() {
+                lines.push(format!("Invited: {}", party.invited.join(", ")));
+            }
+        }
+        None => lines.push("No party".to_string()),
+    }
+    for invite in lobby_party.0.invites.iter() {
+        lines.push(format!("Invited by {}", invite.leader));
+    }
+    if let Some(error) = &lobby_party.0.error {
+        lines.push(error.clone());
+    }
+
+    for mut text in text_q.iter_mut() {
+        text.0 = lines.join("\n");
+    }
+}
+
+pub(super) struct LobbyPartyPlugin;
+
+impl Plugin for LobbyPartyPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LobbyParty>();
+        app.add_systems(
+            Update,
+            (
+                poll_party.run_if(on_timer(Duration::from_secs(2))),
+                party_button_logic,
+                update_party_status_text,
+            )
+                .run_if(in_state(AppState::Lobby)),
+        );
+    }
+}
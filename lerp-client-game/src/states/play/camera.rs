@@ -11,7 +11,7 @@ pub struct PlayerCamera;
 pub const CAMERA_VIEWPORT_SIZE: Vec2 = Vec2::new(1280., 720.);
 
 /// How quickly should the camera snap to the desired location.
-const CAMERA_DECAY_RATE: f32 = 20.;
+pub const CAMERA_DECAY_RATE: f32 = 20.;
 
 fn camera_draw_border(
     mut gizmos: Gizmos,
@@ -45,7 +45,11 @@ fn on_character(
     mut player_query: Query<
         (Entity, &Position),
         (
-            Or<(With<Predicted>, With<PreSpawnedPlayerObject>)>,
+            Or<(
+                With<Predicted>,
+                With<PreSpawnedPlayerObject>,
+                With<Interpolated>,
+            )>,
             With<Character>,
             Without<CharacterRender>,
         ),
@@ -128,7 +132,11 @@ fn update_character_render_state(
         ),
         (
             With<CharacterRender>,
-            Or<(With<Predicted>, With<PreSpawnedPlayerObject>)>,
+            Or<(
+                With<Predicted>,
+                With<PreSpawnedPlayerObject>,
+                With<Interpolated>,
+            )>,
         ),
     >,
 ) {
@@ -228,13 +236,32 @@ fn update_character_render_state(
     }
 }
 
+/// Interpolated characters are not simulated, only follow the replicated Dead state
+fn set_interpolated_character_life_state(
+    mut commands: Commands,
+    query: Query<(Entity, Has<Alive>, Has<Dead>), (With<Character>, With<Interpolated>)>,
+) {
+    for (entity, is_alive, is_dead) in query.iter() {
+        if is_dead && is_alive {
+            commands.entity(entity).remove::<Alive>();
+        } else if !is_dead && !is_alive {
+            commands.entity(entity).insert(Alive);
+        }
+    }
+}
+
 pub struct CharacterPlugin;
 
 impl Plugin for CharacterPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
             Update,
-            (on_character, update_character_render_state).run_if(in_state(AppState::Play)),
+            (
+                on_character,
+                set_interpolated_character_life_state,
+                update_character_render_state,
+            )
+                .run_if(in_state(AppState::Play)),
         );
     }
 }
@@ -0,0 +1,185 @@
+// Chat panel: the last messages received from the server and an input sending on Enter.
+// Keyboard inputs are not forwarded to the game while the input is focused.
+use std::collections::VecDeque;
+
+use bevy::input::InputSystem;
+use bevy::prelude::*;
+use bevy_simple_text_input::{
+    TextInputInactive, TextInputSubmitEvent, TextInputSystem, TextInputValue,
+};
+use leafwing_input_manager::plugin::InputManagerSystem;
+use lerp_common_game::prelude::*;
+use lightyear::prelude::client::*;
+
+use crate::common::AppState;
+use crate::states::play::PlaySceneTag;
+use crate::ui::text_input::{create_text_input, set_text_input_active};
+
+/// Messages kept in the log
+const CHAT_LOG_SIZE: usize = 50;
+/// Messages displayed in the panel
+const CHAT_VISIBLE_MESSAGES: usize = 8;
+
+#[derive(Resource, Default)]
+struct ChatLog(VecDeque<ChatMessage>);
+
+#[derive(Component)]
+struct ChatMessageList;
+
+#[derive(Component)]
+struct ChatInput;
+
+fn chat_setup(mut commands: Commands, mut chat_log: ResMut<ChatLog>) {
+    chat_log.0.clear();
+
+    let chat_input = create_text_input(
+        &mut commands,
+        ChatInput,
+        "Enter to chat, /help".to_string(),
+        None,
+    );
+    commands
+        .spawn((
+            PlaySceneTag,
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(35.),
+                bottom: Val::Px(5.),
+                width: Val::Percent(30.),
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                ChatMessageList,
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    ..default()
+                },
+            ));
+        })
+        .add_child(chat_input);
+}
+
+fn chat_message_line(message: &ChatMessage) -> (String, Color) {
+    let sender = message.sender.as_deref().unwrap_or_default();
+    match message.kind {
+        ChatKind::Say => (format!("{}: {}", sender, message.text), Color::WHITE),
+        ChatKind::Party => (
+            format!("[Party] {}: {}", sender, message.text),
+            Color::srgb(0.4, 0.8, 1.),
+        ),
+        ChatKind::Emote => (
+            format!("* {} {}", sender, message.text),
+            Color::srgb(1., 0.6, 0.2),
+        ),
+        ChatKind::System => (message.text.clone(), Color::srgb(1., 1., 0.4)),
+    }
+}
+
+fn receive_chat_messages(
+    mut chat_ev: EventReader<ClientReceiveMessage<ChatMessage>>,
+    mut chat_log: ResMut<ChatLog>,
+) {
+    for ev in chat_ev.read() {
+        chat_log.0.push_back(ev.message.clone());
+        if chat_log.0.len() > CHAT_LOG_SIZE {
+            chat_log.0.pop_front();
+        }
+    }
+}
+
+fn update_chat_message_list(
+    mut commands: Commands,
+    chat_log: Res<ChatLog>,
+    list_q: Query<Entity, With<ChatMessageList>>,
+) {
+    if !chat_log.is_changed() {
+        return;
+    }
+    let Ok(list) = list_q.get_single() else {
+        return;
+    };
+
+    let skipped = chat_log.0.len().saturating_sub(CHAT_VISIBLE_MESSAGES);
+    commands
+        .entity(list)
+        .despawn_descendants()
+        .with_children(|parent| {
+            for message in chat_log.0.iter().skip(skipped) {
+                let (line, color) = chat_message_line(message);
+                parent.spawn((Text(line), TextFont::from_font_size(12.), TextColor(color)));
+            }
+        });
+}
+
+/// Enter focuses the chat input, submitting it sends the message and gives the focus back
+fn chat_input_logic(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut submit_ev: EventReader<TextInputSubmitEvent>,
+    mut connection_manager: ResMut<ConnectionManager>,
+    mut input_q: Query<(Entity, &mut TextInputInactive, &mut BorderColor), With<ChatInput>>,
+) {
+    let Ok((input_entity, mut inactive, mut border_color)) = input_q.get_single_mut() else {
+        return;
+    };
+
+    for ev in submit_ev.read() {
+        if ev.entity != input_entity {
+            continue;
+        }
+        let text = ev.value.trim();
+        if !text.is_empty() {
+            let message = SendChat {
+                text: text.to_string(),
+            };
+            if let Err(err) = connection_manager.send_message::<ChatChannel, _>(&message) {
+                error!("[chat_input_logic] Failed to send chat message: {:?}", err);
+            }
+        }
+        commands
+            .entity(input_entity)
+            .insert(TextInputValue(String::new()));
+        set_text_input_active(&mut inactive, &mut border_color, false);
+    }
+
+    if inactive.0 && keyboard.just_pressed(KeyCode::Enter) {
+        set_text_input_active(&mut inactive, &mut border_color, true);
+    }
+}
+
+/// Release all keys for the game systems while typing
+fn block_keyboard_while_chatting(
+    mut keyboard: ResMut<ButtonInput<KeyCode>>,
+    input_q: Query<&TextInputInactive, With<ChatInput>>,
+) {
+    if input_q.iter().any(|inactive| !inactive.0) {
+        keyboard.reset_all();
+    }
+}
+
+pub struct ChatPlugin;
+
+impl Plugin for ChatPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ChatLog>();
+        app.add_systems(OnEnter(AppState::Play), chat_setup);
+        app.add_systems(
+            PreUpdate,
+            block_keyboard_while_chatting
+                .after(InputSystem)
+                .before(InputManagerSystem::Update)
+                .run_if(in_state(AppState::Play)),
+        );
+        app.add_systems(
+            Update,
+            (
+                (receive_chat_messages, update_chat_message_list).chain(),
+                chat_input_logic.after(TextInputSystem),
+            )
+                .run_if(in_state(AppState::Play)),
+        );
+    }
+}
@@ -1,17 +1,21 @@
+use std::f32::consts::FRAC_PI_4;
+
 use bevy::prelude::*;
 use bevy_ecs_tilemap::tiles::{TilePos, TileStorage, TileTextureIndex};
-use lerp_common_game::flow_field::{FlowField, FlowFieldDirection};
+use lerp_common_game::flow_field::FlowFields;
 use lerp_common_game::map::map::NavTileCoord;
 
 use crate::states::play::map::{TileFlowField, TileMapFlowField};
 
 use super::DebugConfig;
 
+const OCTANT_TEXTURE_INDEXES: [u32; 8] = [7, 1, 5, 2, 4, 0, 6, 3];
+
 pub(super) fn debug_render_flow_field(
     debug_config: Res<DebugConfig>,
     mut tilemap_q: Query<&TileStorage, With<TileMapFlowField>>,
     mut tile_q: Query<&mut TileTextureIndex, With<TileFlowField>>,
-    flow_field: Res<FlowField>,
+    flow_fields: Res<FlowFields>,
 ) {
     if !debug_config.show_flow_field {
         return;
@@ -21,10 +25,11 @@ pub(super) fn debug_render_flow_field(
         return;
     };
 
-    for x in 0..flow_field.size.x {
-        for y in 0..flow_field.size.y {
+    for x in 0..flow_fields.size().x {
+        for y in 0..flow_fields.size().y {
             let map_node_pos = NavTileCoord(UVec2::new(x, y));
-            let flow_field_direction = flow_field.map.get(&map_node_pos);
+            // Towards the nearest aggro target
+            let flow_field_direction = flow_fields.get_nearest(map_node_pos);
 
             let Some(tile_entity) = tile_storage.get(&TilePos::new(map_node_pos.x, map_node_pos.y))
             else {
@@ -43,15 +48,12 @@ pub(super) fn debug_render_flow_field(
                 continue;
             };
 
+            // Arrow of the nearest octant, counterclockwise from east
             tile_texture_index.0 = match flow_field_direction {
-                Some(FlowFieldDirection::North) => 5,
-                Some(FlowFieldDirection::South) => 6,
-                Some(FlowFieldDirection::West) => 4,
-                Some(FlowFieldDirection::East) => 7,
-                Some(FlowFieldDirection::NorthWest) => 2,
-                Some(FlowFieldDirection::SouthWest) => 0,
-                Some(FlowFieldDirection::SouthEast) => 3,
-                Some(FlowFieldDirection::NorthEast) => 1,
+                Some(direction) => {
+                    let octant = (direction.to_angle() / FRAC_PI_4).round() as i32;
+                    OCTANT_TEXTURE_INDEXES[octant.rem_euclid(8) as usize]
+                }
                 None => 8,
             }
         }
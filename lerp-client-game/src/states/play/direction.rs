@@ -1,6 +1,9 @@
 use avian2d::prelude::*;
 use bevy::prelude::*;
-use lightyear::prelude::{client::Predicted, PreSpawnedPlayerObject};
+use lightyear::prelude::{
+    client::{Interpolated, Predicted},
+    PreSpawnedPlayerObject,
+};
 
 use crate::common::cartesian_to_isometric_vec2;
 
@@ -19,7 +22,11 @@ pub fn update_direction(
             &DirectionCount,
             Option<&mut Direction>,
         ),
-        Or<(With<Predicted>, With<PreSpawnedPlayerObject>)>,
+        Or<(
+            With<Predicted>,
+            With<PreSpawnedPlayerObject>,
+            With<Interpolated>,
+        )>,
     >,
 ) {
     for (entity, linear_velocity, direction_count, current_direction) in &mut q {
@@ -1,7 +1,8 @@
 use bevy::prelude::*;
 use leafwing_input_manager::{plugin::InputManagerSystem, prelude::ActionState};
 use lightyear::{
-    client::input::leafwing::InputSystemSet, prelude::client::Predicted,
+    client::input::leafwing::InputSystemSet,
+    prelude::{client::Predicted, Tick, TickManager},
     shared::replication::components::Controlled,
 };
 use lerp_common_game::prelude::*;
@@ -110,6 +111,23 @@ fn handle_mouse_move(
     action_state.set_axis_pair(&PlayerActions::Cursor, actual_world_cursor_position);
 }
 
+/// The client tests its hits against the characters it predicts, which are at the tick its inputs are applied.
+/// The input delay does not vary, its minimum being its maximum before predicting
+fn handle_target_tick(
+    tick_manager: Res<TickManager>,
+    mut action_state_query: Query<
+        &mut ActionState<PlayerActions>,
+        (With<Player>, With<Predicted>, With<Controlled>),
+    >,
+) {
+    let Ok(mut action_state) = action_state_query.get_single_mut() else {
+        return;
+    };
+
+    let target_tick = Tick(tick_manager.tick().0.wrapping_add(INPUT_DELAY_TICKS));
+    action_state.set_value(&PlayerActions::TargetTick, target_tick.0 as f32);
+}
+
 pub struct InputPlugin;
 
 impl Plugin for InputPlugin {
@@ -120,7 +138,7 @@ impl Plugin for InputPlugin {
         });
         app.add_systems(
             FixedPreUpdate,
-            (handle_mouse_click, handle_mouse_move)
+            (handle_mouse_click, handle_mouse_move, handle_target_tick)
                 // WARNING: chain() matter, create desync/rollback if removed
                 .chain()
                 .before(InputSystemSet::BufferClientInputs)
@@ -39,6 +39,17 @@ pub struct TileWall;
 #[derive(Component)]
 pub struct TileFlowField;
 
+/// Terrain floors have no texture of their own, they are tinted instead
+fn floor_tint(kind: RenderTileFloorKind, lighting: f32) -> Color {
+    let (r, g, b) = match kind {
+        RenderTileFloorKind::Standard => (1., 1., 1.),
+        RenderTileFloorKind::ShallowWater => (0.45, 0.6, 1.),
+        RenderTileFloorKind::Lava => (1., 0.45, 0.2),
+        RenderTileFloorKind::Pit => (0.15, 0.15, 0.15),
+    };
+    Color::srgb(r * lighting, g * lighting, b * lighting)
+}
+
 fn spawn_map_chunk(
     commands: &mut Commands,
     asset_server: &AssetServer,
@@ -72,6 +83,9 @@ fn spawn_map_chunk(
         None,
     );
 
+    // Ambient light of the map, darker maps tint their tiles
+    let tint = Color::srgb(map_grid.lighting, map_grid.lighting, map_grid.lighting);
+
     let wall_atlas_layout = texture_atlas_layouts.add(layout.clone());
     let wall_texture: Handle<Image> = asset_server.load("iso-tileset-wall-cata-160x80.png");
     let mut walls = Vec::new();
@@ -82,7 +96,7 @@ fn spawn_map_chunk(
             let y = offset_y as u32 * CHUNK_SIZE + chunk_y;
 
             // Spawn floor if any
-            if let Some(_map_tile_floor) = map_grid.get_render_tile_floor(UVec2::new(x, y)) {
+            if let Some(map_tile_floor) = map_grid.get_render_tile_floor(UVec2::new(x, y)) {
                 let tile_pos = TilePos {
                     x: chunk_x,
                     y: chunk_y,
@@ -94,6 +108,7 @@ fn spawn_map_chunk(
                         texture_index: TileTextureIndex(
                             *floor_tile_indexes.choose(&mut rng).unwrap(),
                         ),
+                        color: TileColor(floor_tint(map_tile_floor.kind, map_grid.lighting)),
                         ..Default::default()
                     })
                     .id();
@@ -123,6 +138,7 @@ fn spawn_map_chunk(
                                 0.0,
                                 -(0.5 - (1. / (MAP_TILE_IMG_SIZE_WALL.y / RENDER_TILE_SIZE)) / 2.),
                             )),
+                            color: tint,
                             ..default()
                         },
                         Transform::from_translation(Vec3::new(iso_coord.x, iso_coord.y, z)),
@@ -229,7 +245,27 @@ fn despawn_outofrange_map_chunks(
     }
 }
 
-pub fn render_flow_field(
+/// The map is received after entering the play state, what was rendered before is rebuilt
+fn clear_map_render(
+    mut commands: Commands,
+    mut chunk_manager: ResMut<ChunkManager>,
+    chunks_query: Query<(Entity, &TileMapFloorChunk)>,
+    flow_field_query: Query<Entity, Or<(With<TileMapFlowField>, With<TileFlowField>)>>,
+) {
+    for (entity, chunk) in chunks_query.iter() {
+        commands.entity(entity).despawn_recursive();
+        for wall_entity in &chunk.walls {
+            commands.entity(*wall_entity).despawn_recursive();
+        }
+    }
+    chunk_manager.spawned_chunks.clear();
+
+    for entity in flow_field_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn render_flow_field(
     debug_config: Res<DebugConfig>,
     mut commands: Commands,
     asset_server: Res<AssetServer>,
@@ -296,9 +332,14 @@ impl Plugin for MapPlugin {
         app.add_systems(
             Update,
             (
+                receive_map_data,
+                (clear_map_render, render_flow_field)
+                    .chain()
+                    .run_if(resource_changed::<Map>),
                 spawn_map_chunks_around_camera,
                 despawn_outofrange_map_chunks,
             )
+                .chain()
                 .run_if(in_state(AppState::Play)),
         );
     }
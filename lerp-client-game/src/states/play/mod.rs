@@ -1,6 +1,7 @@
 mod animation;
 mod camera;
 mod character;
+mod chat;
 mod cursor;
 mod debug;
 mod direction;
@@ -8,10 +9,15 @@ mod input;
 mod item_drop;
 pub mod map;
 mod name_plate;
+mod network_conditions;
+mod obstacle;
+mod party;
 mod player;
 mod projectile;
+mod spectator;
 
 use crate::common::*;
+use crate::lightyear::conditioner::NetworkConditions;
 use crate::states::play::camera::*;
 use crate::states::play::debug::*;
 use crate::states::play::map::*;
@@ -24,18 +30,19 @@ use bevy::prelude::*;
 use bevy::render::camera::ScalingMode;
 use cursor::CursorPlugin;
 use input::InputPlugin;
-use leafwing_input_manager::plugin::InputManagerSystem;
-use leafwing_input_manager::prelude::ActionState;
-use lightyear::client::input::leafwing::InputSystemSet;
 use lightyear::prelude::client::*;
-use lightyear::shared::replication::components::Controlled;
 
 use animation::animate_sprite;
 use character::*;
+use chat::ChatPlugin;
 use direction::update_direction;
 use item_drop::ItemDropPlugin;
 use name_plate::*;
+use network_conditions::*;
+use obstacle::ObstaclePlugin;
+use party::PartyHudPlugin;
 use projectile::*;
+use spectator::SpectatorPlugin;
 
 use lerp_common_game::prelude::*;
 
@@ -56,12 +63,11 @@ struct RollbackStateLineItem;
 
 #[derive(Component, PartialEq, Eq)]
 enum ButtonAction {
-    SpawnEnemies,
     CameraZoomIn,
     CameraZoomOut,
 }
 
-fn play_scene_setup(mut commands: Commands) {
+fn play_scene_setup(mut commands: Commands, network_conditions: Res<NetworkConditions>) {
     println!("[play_scene_setup]");
 
     commands.connect_client();
@@ -123,6 +129,7 @@ fn play_scene_setup(mut commands: Commands) {
                         ));
                     }
                 });
+            spawn_network_conditions_panel(parent, &network_conditions);
         });
 
     commands
@@ -139,24 +146,6 @@ fn play_scene_setup(mut commands: Commands) {
         .with_children(|parent| {
             let button_size = Vec2::new(100., 30.);
 
-            parent
-                .spawn((
-                    ButtonAction::SpawnEnemies,
-                    Button,
-                    BorderColor(Color::BLACK),
-                    BackgroundColor(NORMAL_BUTTON),
-                    Node {
-                        width: Val::Px(button_size.x),
-                        height: Val::Px(button_size.y),
-                        border: UiRect::all(Val::Px(2.0)),
-                        justify_content: JustifyContent::Center,
-                        align_items: AlignItems::Center,
-                        ..default()
-                    },
-                ))
-                .with_children(|parent| {
-                    parent.spawn((Text("Enemies".to_string()), TextFont::from_font_size(12.)));
-                });
             parent
                 .spawn((
                     ButtonAction::CameraZoomIn,
@@ -211,27 +200,10 @@ fn play_scene_button_logic(
         (Changed<Interaction>, With<Button>),
     >,
     mut camera_query: Query<&mut OrthographicProjection, With<PlayerCamera>>,
-    mut action_state_query: Query<
-        &mut ActionState<PlayerActions>,
-        (With<Player>, With<Predicted>, With<Controlled>),
-    >,
 ) {
-    if let Ok(mut action_state) = action_state_query.get_single_mut() {
-        if action_state.pressed(&PlayerActions::SpawnEnemies) {
-            action_state.set_button_value(&PlayerActions::SpawnEnemies, 0.);
-        }
-    };
-
     for (interaction, action) in &mut interaction_query {
         match *interaction {
             Interaction::Pressed => match action {
-                ButtonAction::SpawnEnemies => {
-                    let Ok(mut action_state) = action_state_query.get_single_mut() else {
-                        return;
-                    };
-
-                    action_state.set_button_value(&PlayerActions::SpawnEnemies, 1.);
-                }
                 ButtonAction::CameraZoomIn => {
                     if let Ok(mut ortho_proj) = camera_query.get_single_mut() {
                         ortho_proj.scale = (ortho_proj.scale - 0.5).max(1.)
@@ -253,6 +225,7 @@ fn play_scene_cleanup(
     mut commands: Commands,
     query: Query<Entity, Or<(With<PlaySceneTag>, With<CommonPlaySceneTag>)>>,
     mut chunk_manager: ResMut<ChunkManager>,
+    mut map_grid: ResMut<Map>,
 ) {
     println!("[play_scene_cleanup]");
     commands.disconnect_client();
@@ -260,6 +233,8 @@ fn play_scene_cleanup(
         commands.entity(entity).despawn_recursive();
     }
     chunk_manager.spawned_chunks.clear();
+    // The next instance sends its own map
+    *map_grid = Map::default();
 }
 
 fn update_fps(
@@ -310,6 +285,19 @@ fn update_rollback_state(
     }
 }
 
+/// Entities leaving the area of interest are despawned by lightyear without their render children
+fn despawn_orphan_children(
+    mut commands: Commands,
+    entities: &Entities,
+    child_query: Query<(Entity, &Parent)>,
+) {
+    for (entity, parent) in child_query.iter() {
+        if !entities.contains(parent.get()) {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
 pub struct PlayPlugin;
 
 impl Plugin for PlayPlugin {
@@ -317,37 +305,33 @@ impl Plugin for PlayPlugin {
         app.add_plugins((
             CameraPlugin,
             CharacterPlugin,
+            ChatPlugin,
             CursorPlugin,
             InputPlugin,
             DebugPlugin,
             ItemDropPlugin,
             MapPlugin,
             NamePlatePlugin,
+            NetworkConditionsPlugin,
+            ObstaclePlugin,
+            PartyHudPlugin,
             ProjectilePlugin,
+            SpectatorPlugin,
         ));
         app.insert_resource(ChunkManager::default());
-        app.add_systems(
-            OnEnter(AppState::Play),
-            (play_scene_setup, (generate_map, render_flow_field).chain()),
-        );
+        app.add_systems(OnEnter(AppState::Play), play_scene_setup);
         app.add_systems(OnExit(AppState::Play), play_scene_cleanup);
 
-        app.add_systems(
-            FixedPreUpdate,
-            (play_scene_button_logic)
-                .before(InputSystemSet::BufferClientInputs)
-                .in_set(InputManagerSystem::ManualControl)
-                .run_if(in_state(AppState::Play)),
-        );
-
         app.add_systems(
             Update,
             (
                 play_scene_logic,
+                play_scene_button_logic,
                 handle_new_client,
                 handle_new_player,
                 update_fps,
                 update_ping,
+                despawn_orphan_children,
                 (update_direction, animate_sprite).chain(),
             )
                 .run_if(in_state(AppState::Play)),
@@ -46,7 +46,11 @@ fn remove_name_plate(
         (Entity, &HasNamePlate),
         (
             Or<(With<Dying>, With<Dead>)>,
-            Or<(With<Predicted>, With<PreSpawnedPlayerObject>)>,
+            Or<(
+                With<Predicted>,
+                With<PreSpawnedPlayerObject>,
+                With<Interpolated>,
+            )>,
         ),
     >,
 ) {
@@ -65,7 +69,11 @@ fn add_name_plate(
             Without<Dying>,
             Without<Dead>,
             With<Health>,
-            Or<(With<Predicted>, With<PreSpawnedPlayerObject>)>,
+            Or<(
+                With<Predicted>,
+                With<PreSpawnedPlayerObject>,
+                With<Interpolated>,
+            )>,
         ),
     >,
 ) {
@@ -147,7 +155,14 @@ fn add_name_plate(
 }
 
 fn update_health_bar(
-    health_q: Query<&Health, (Changed<Health>, With<Predicted>, Without<HealthBar>)>,
+    health_q: Query<
+        &Health,
+        (
+            Changed<Health>,
+            Or<(With<Predicted>, With<Interpolated>)>,
+            Without<HealthBar>,
+        ),
+    >,
     mut health_bar_q: Query<(&mut Sprite, &mut Transform, &HealthBar), With<HealthBar>>,
 ) {
     for (mut sprite, mut transform, parent) in health_bar_q.iter_mut() {
@@ -161,7 +176,14 @@ fn update_health_bar(
 }
 
 fn update_mana_bar(
-    mana_q: Query<&Mana, (Changed<Mana>, With<Predicted>, Without<ManaBar>)>,
+    mana_q: Query<
+        &Mana,
+        (
+            Changed<Mana>,
+            Or<(With<Predicted>, With<Interpolated>)>,
+            Without<ManaBar>,
+        ),
+    >,
     mut mana_bar_q: Query<(&mut Sprite, &mut Transform, &ManaBar), With<ManaBar>>,
 ) {
     for (mut sprite, mut transform, parent) in mana_bar_q.iter_mut() {
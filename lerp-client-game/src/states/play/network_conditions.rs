@@ -0,0 +1,182 @@
+// Change the simulated network conditions while playing.
+// Lightyear builds the link conditioner with the transport, so new conditions are applied by
+// reconnecting to the same instance, which spawns a new player.
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::common_conditions::on_timer;
+use lightyear::prelude::client::*;
+
+use crate::common::AppState;
+use crate::lightyear::conditioner::*;
+use crate::NORMAL_BUTTON;
+
+const LATENCY_STEP: Duration = Duration::from_millis(25);
+const JITTER_STEP: Duration = Duration::from_millis(5);
+const LOSS_STEP: f32 = 0.01;
+
+#[derive(Component, Clone, Copy)]
+enum NetworkConditionsButton {
+    NextPreset,
+    Latency(bool),
+    Jitter(bool),
+    Loss(bool),
+}
+
+/// Conditions of the current connection
+#[derive(Resource, Default)]
+struct AppliedNetworkConditions(Option<NetworkConditions>);
+
+/// The client disconnected to apply new conditions and must connect again
+#[derive(Resource, Default)]
+struct ReconnectPending(bool);
+
+/// Spawn the current conditions and the buttons to change them, below the ping
+pub fn spawn_network_conditions_panel(parent: &mut ChildBuilder, conditions: &NetworkConditions) {
+    parent.spawn((
+        NetworkConditionsText,
+        Text(conditions.label()),
+        TextFont::from_font_size(12.),
+        TextColor(Color::linear_rgb(0., 1., 0.)),
+    ));
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            column_gap: Val::Px(2.0),
+            ..default()
+        })
+        .with_children(|parent| {
+            for (button, label) in [
+                (NetworkConditionsButton::NextPreset, "Preset"),
+                (NetworkConditionsButton::Latency(false), "Lat -"),
+                (NetworkConditionsButton::Latency(true), "Lat +"),
+                (NetworkConditionsButton::Jitter(false), "Jit -"),
+                (NetworkConditionsButton::Jitter(true), "Jit +"),
+                (NetworkConditionsButton::Loss(false), "Loss -"),
+                (NetworkConditionsButton::Loss(true), "Loss +"),
+            ] {
+                parent
+                    .spawn((
+                        button,
+                        Button,
+                        BorderColor(Color::BLACK),
+                        BackgroundColor(NORMAL_BUTTON),
+                        Node {
+                            width: Val::Px(45.),
+                            height: Val::Px(20.),
+                            border: UiRect::all(Val::Px(1.0)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn((Text(label.to_string()), TextFont::from_font_size(10.)));
+                    });
+            }
+        });
+}
+
+fn network_conditions_button_logic(
+    mut conditions: ResMut<NetworkConditions>,
+    interaction_q: Query<(&Interaction, &NetworkConditionsButton), Changed<Interaction>>,
+) {
+    for (interaction, button) in interaction_q.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match *button {
+            NetworkConditionsButton::NextPreset => {
+                *conditions = NetworkConditions::from_preset(conditions.preset.next());
+            }
+            NetworkConditionsButton::Latency(increase) => {
+                let latency = if increase {
+                    conditions.latency + LATENCY_STEP
+                } else {
+                    conditions.latency.saturating_sub(LATENCY_STEP)
+                };
+                conditions.set_latency(latency);
+            }
+            NetworkConditionsButton::Jitter(increase) => {
+                let jitter = if increase {
+                    conditions.jitter + JITTER_STEP
+                } else {
+                    conditions.jitter.saturating_sub(JITTER_STEP)
+                };
+                conditions.set_jitter(jitter);
+            }
+            NetworkConditionsButton::Loss(increase) => {
+                let loss = if increase {
+                    conditions.loss + LOSS_STEP
+                } else {
+                    conditions.loss - LOSS_STEP
+                };
+                conditions.set_loss(loss);
+            }
+        }
+    }
+}
+
+fn reset_applied_network_conditions(
+    conditions: Res<NetworkConditions>,
+    mut applied: ResMut<AppliedNetworkConditions>,
+    mut reconnect_pending: ResMut<ReconnectPending>,
+) {
+    applied.0 = Some(conditions.clone());
+    reconnect_pending.0 = false;
+}
+
+/// Checked on a timer so that several button presses only trigger one reconnection
+fn apply_network_conditions(
+    mut commands: Commands,
+    conditions: Res<NetworkConditions>,
+    networking_state: Res<State<NetworkingState>>,
+    mut applied: ResMut<AppliedNetworkConditions>,
+    mut reconnect_pending: ResMut<ReconnectPending>,
+    mut client_config: ResMut<ClientConfig>,
+) {
+    if applied.0.as_ref() == Some(&*conditions)
+        || *networking_state.get() != NetworkingState::Connected
+    {
+        return;
+    }
+
+    println!("[apply_network_conditions] {}", conditions.label());
+    set_link_conditioner(&mut client_config, &conditions);
+    applied.0 = Some(conditions.clone());
+    reconnect_pending.0 = true;
+    commands.disconnect_client();
+}
+
+fn reconnect_with_new_conditions(
+    mut commands: Commands,
+    mut reconnect_pending: ResMut<ReconnectPending>,
+) {
+    if reconnect_pending.0 {
+        reconnect_pending.0 = false;
+        commands.connect_client();
+    }
+}
+
+pub struct NetworkConditionsPlugin;
+
+impl Plugin for NetworkConditionsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AppliedNetworkConditions>();
+        app.init_resource::<ReconnectPending>();
+        app.add_systems(OnEnter(AppState::Play), reset_applied_network_conditions);
+        app.add_systems(
+            Update,
+            (
+                network_conditions_button_logic,
+                apply_network_conditions.run_if(on_timer(Duration::from_secs(1))),
+            )
+                .chain()
+                .run_if(in_state(AppState::Play)),
+        );
+        app.add_systems(
+            OnEnter(NetworkingState::Disconnected),
+            reconnect_with_new_conditions.run_if(in_state(AppState::Play)),
+        );
+    }
+}
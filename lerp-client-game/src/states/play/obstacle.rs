@@ -0,0 +1,60 @@
+use bevy::prelude::*;
+use bevy_prototype_lyon::prelude::*;
+use lerp_common_game::prelude::*;
+use lightyear::prelude::client::{Interpolated, Predicted};
+
+use crate::{
+    common::{cartesian_to_isometric_radius, AppState},
+    utils::ZLayer,
+};
+
+use super::PlaySceneTag;
+
+fn obstacle_style(kind: ObstacleKind) -> (Color, Color) {
+    match kind {
+        ObstacleKind::Crate => (Color::srgb_u8(120, 84, 48), Color::srgb_u8(60, 40, 20)),
+        ObstacleKind::Barrel => (Color::srgb_u8(150, 40, 30), Color::srgb_u8(70, 20, 15)),
+    }
+}
+
+/// Obstacles are drawn as their isometric footprint, y sorted like the characters
+fn handle_new_obstacle(
+    mut commands: Commands,
+    obstacle_q: Query<
+        (Entity, &Obstacle),
+        (Or<(Added<Predicted>, Added<Interpolated>)>, With<Obstacle>),
+    >,
+) {
+    for (entity, obstacle) in obstacle_q.iter() {
+        let (fill_color, stroke_color) = obstacle_style(obstacle.kind);
+        let radius = cartesian_to_isometric_radius(obstacle.kind.size() / 2.);
+        let shape = shapes::Polygon {
+            points: vec![
+                Vec2::new(-radius.x, 0.),
+                Vec2::new(0., radius.y),
+                Vec2::new(radius.x, 0.),
+                Vec2::new(0., -radius.y),
+            ],
+            closed: true,
+        };
+
+        commands.entity(entity).insert((
+            PlaySceneTag,
+            ZLayer::Default,
+            ShapeBundle {
+                path: GeometryBuilder::build_as(&shape),
+                ..default()
+            },
+            Stroke::new(stroke_color, 2.),
+            Fill::color(fill_color),
+        ));
+    }
+}
+
+pub struct ObstaclePlugin;
+
+impl Plugin for ObstaclePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, handle_new_obstacle.run_if(in_state(AppState::Play)));
+    }
+}
@@ -0,0 +1,107 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::common_conditions::on_timer;
+use lerp_common_game::prelude::*;
+use lightyear::prelude::client::*;
+use lightyear::shared::replication::components::Controlled;
+
+use crate::common::AppState;
+use crate::states::play::PlaySceneTag;
+
+const PARTY_BAR_WIDTH: f32 = 120.;
+const HEALTH_BAR_COLOR: Color = Color::srgb(0.8, 0.1, 0.1);
+const MANA_BAR_COLOR: Color = Color::srgb(0.1, 0.3, 0.9);
+
+#[derive(Component)]
+struct PartyHud;
+
+fn party_hud_setup(mut commands: Commands) {
+    commands.spawn((
+        PlaySceneTag,
+        PartyHud,
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(5.),
+            top: Val::Px(200.),
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(4.),
+            ..default()
+        },
+    ));
+}
+
+fn spawn_party_bar(parent: &mut ChildBuilder, ratio: f32, color: Color) {
+    parent
+        .spawn((
+            Node {
+                width: Val::Px(PARTY_BAR_WIDTH),
+                height: Val::Px(6.),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.15, 0.15, 0.15)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Node {
+                    width: Val::Percent(ratio.clamp(0., 1.) * 100.),
+                    height: Val::Percent(100.),
+                    ..default()
+                },
+                BackgroundColor(color),
+            ));
+        });
+}
+
+/// Health and mana of the other members of the local player's party
+fn update_party_hud(
+    mut commands: Commands,
+    local_player_q: Query<&PartyMember, (With<Player>, With<Predicted>, With<Controlled>)>,
+    member_q: Query<
+        (Entity, &PartyMember, &Health, &Mana),
+        (With<Player>, With<Predicted>, Without<Controlled>),
+    >,
+    hud_q: Query<Entity, With<PartyHud>>,
+) {
+    let Ok(hud) = hud_q.get_single() else {
+        return;
+    };
+
+    let mut members: Vec<(Entity, &Health, &Mana)> = match local_player_q.get_single() {
+        Ok(local_party) => member_q
+            .iter()
+            .filter(|(_, party, _, _)| *party == local_party)
+            .map(|(entity, _, health, mana)| (entity, health, mana))
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    members.sort_by_key(|(entity, _, _)| *entity);
+
+    commands
+        .entity(hud)
+        .despawn_descendants()
+        .with_children(|parent| {
+            for (i, (_, health, mana)) in members.iter().enumerate() {
+                parent.spawn((
+                    Text(format!("Party member {}", i + 1)),
+                    TextFont::from_font_size(12.),
+                ));
+                spawn_party_bar(parent, health.current / health.max, HEALTH_BAR_COLOR);
+                spawn_party_bar(parent, mana.current / mana.max, MANA_BAR_COLOR);
+            }
+        });
+}
+
+pub struct PartyHudPlugin;
+
+impl Plugin for PartyHudPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AppState::Play), party_hud_setup);
+        app.add_systems(
+            Update,
+            update_party_hud
+                .run_if(on_timer(Duration::from_millis(200)))
+                .run_if(in_state(AppState::Play)),
+        );
+    }
+}
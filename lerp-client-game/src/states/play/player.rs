@@ -27,7 +27,11 @@ pub fn handle_new_player(
     mut player_query: Query<
         (Entity, Has<Controlled>),
         (
-            Or<(With<Predicted>, With<PreSpawnedPlayerObject>)>,
+            Or<(
+                With<Predicted>,
+                With<PreSpawnedPlayerObject>,
+                With<Interpolated>,
+            )>,
             With<Player>,
             Without<PlayerRender>,
         ),
@@ -45,9 +49,15 @@ pub fn handle_new_player(
                     (PlayerActions::MoveLeft, KeyCode::KeyA),
                     (PlayerActions::MoveRight, KeyCode::KeyD),
                     (PlayerActions::SkillSlot3, KeyCode::KeyE),
+                    (PlayerActions::SkillSlot4, KeyCode::Space),
+                    (PlayerActions::SkillSlot5, KeyCode::KeyQ),
+                    (PlayerActions::SkillSlot6, KeyCode::KeyR),
+                    (PlayerActions::SkillSlot7, KeyCode::KeyZ),
+                    (PlayerActions::SkillSlot8, KeyCode::KeyX),
                 ])
                 // .with(PlayerActions::SkillSlot1, MouseButton::Left)
-                .with(PlayerActions::SkillSlot2, MouseButton::Right),
+                .with(PlayerActions::SkillSlot2, MouseButton::Right)
+                .with(PlayerActions::MoveToCursor, MouseButton::Middle),
             );
         }
     }
@@ -1,7 +1,10 @@
 use avian2d::prelude::*;
 use bevy::prelude::*;
 use lerp_common_game::prelude::*;
-use lightyear::prelude::{client::Predicted, PreSpawnedPlayerObject};
+use lightyear::prelude::{
+    client::{Interpolated, Predicted},
+    PreSpawnedPlayerObject,
+};
 
 use crate::{
     common::{cartesian_to_isometric_vec2, AppState},
@@ -25,14 +28,18 @@ fn handle_new_projectile(
     asset_server: Res<AssetServer>,
     mut commands: Commands,
     mut projectile_query: Query<
-        (Entity, &Position),
+        (Entity, &Position, Has<Interpolated>),
         (
-            Or<(Added<Predicted>, Added<PreSpawnedPlayerObject>)>,
+            Or<(
+                Added<Predicted>,
+                Added<PreSpawnedPlayerObject>,
+                Added<Interpolated>,
+            )>,
             With<Projectile>,
         ),
     >,
 ) {
-    for (entity, position) in projectile_query.iter_mut() {
+    for (entity, position, interpolated) in projectile_query.iter_mut() {
         let mut translation = cartesian_to_isometric_vec2(position).extend(1.);
         translation.y += 1. * PIXEL_METER;
 
@@ -45,15 +52,25 @@ fn handle_new_projectile(
             z_layer: ZLayer::Default,
         });
 
-        commands
-            .entity(entity)
-            .insert_if_new(ProjectileBundle::from_protocol());
+        // Interpolated projectiles are only moved by the interpolation
+        if !interpolated {
+            commands
+                .entity(entity)
+                .insert_if_new(ProjectileBundle::from_protocol(position.0));
+        }
     }
 }
 
 fn handle_removed_projectile(
     mut commands: Commands,
-    query: Query<Entity, Or<(With<Predicted>, With<PreSpawnedPlayerObject>)>>,
+    query: Query<
+        Entity,
+        Or<(
+            With<Predicted>,
+            With<PreSpawnedPlayerObject>,
+            With<Interpolated>,
+        )>,
+    >,
     mut projectile_query: RemovedComponents<Projectile>,
 ) {
     for entity in projectile_query.read() {
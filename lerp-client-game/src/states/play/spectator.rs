@@ -0,0 +1,255 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::common_conditions::on_timer;
+use bevy_transform_interpolation::TransformEasingSet;
+use lerp_common_game::prelude::*;
+use lightyear::prelude::client::Interpolated;
+
+use crate::common::AppState;
+use crate::states::play::camera::{PlayerCamera, CAMERA_DECAY_RATE};
+use crate::states::play::PlaySceneTag;
+use crate::NORMAL_BUTTON;
+
+/// Free camera speed in pixels per second, at zoom 1
+const FREE_CAMERA_SPEED: f32 = 15. * PIXEL_METER;
+
+const FOLLOWED_BUTTON: Color = Color::srgb(0.35, 0.35, 0.75);
+
+#[derive(Resource, Default, PartialEq)]
+enum SpectatorCamera {
+    #[default]
+    Free,
+    Follow(Entity),
+}
+
+#[derive(Component)]
+struct SpectatorPlayerList;
+
+/// Follow the given player, or roam freely if None
+#[derive(Component)]
+struct SpectatorCameraButton(Option<Entity>);
+
+fn is_spectator(join_mode: Res<JoinMode>) -> bool {
+    *join_mode == JoinMode::Spectator
+}
+
+fn spectator_hud_setup(mut commands: Commands) {
+    commands.insert_resource(SpectatorCamera::Free);
+    commands
+        .spawn((
+            PlaySceneTag,
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(5.),
+                bottom: Val::Px(5.),
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text("Spectating - Tab: next player, F: free camera".to_string()),
+                TextFont::from_font_size(12.),
+            ));
+            parent.spawn((
+                SpectatorPlayerList,
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    ..default()
+                },
+            ));
+        });
+}
+
+/// Interpolated players sorted by entity, so that the list and Tab order are stable
+fn sorted_players(
+    player_q: &Query<(Entity, &Health), (With<Player>, With<Interpolated>)>,
+) -> Vec<(Entity, Health)> {
+    let mut players: Vec<(Entity, Health)> = player_q
+        .iter()
+        .map(|(entity, health)| (entity, health.clone()))
+        .collect();
+    players.sort_by_key(|(entity, _)| *entity);
+    players
+}
+
+fn spawn_spectator_camera_button(
+    parent: &mut ChildBuilder,
+    target: Option<Entity>,
+    label: String,
+    followed: bool,
+) {
+    parent
+        .spawn((
+            SpectatorCameraButton(target),
+            Button,
+            BorderColor(Color::BLACK),
+            BackgroundColor(if followed {
+                FOLLOWED_BUTTON
+            } else {
+                NORMAL_BUTTON
+            }),
+            Node {
+                width: Val::Px(180.),
+                height: Val::Px(25.),
+                border: UiRect::all(Val::Px(2.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn((Text(label), TextFont::from_font_size(12.)));
+        });
+}
+
+fn update_spectator_player_list(
+    mut commands: Commands,
+    spectator_camera: Res<SpectatorCamera>,
+    player_q: Query<(Entity, &Health), (With<Player>, With<Interpolated>)>,
+    list_q: Query<Entity, With<SpectatorPlayerList>>,
+) {
+    let Ok(list) = list_q.get_single() else {
+        return;
+    };
+
+    let players = sorted_players(&player_q);
+    commands
+        .entity(list)
+        .despawn_descendants()
+        .with_children(|parent| {
+            spawn_spectator_camera_button(
+                parent,
+                None,
+                "Free camera".to_string(),
+                *spectator_camera == SpectatorCamera::Free,
+            );
+            for (i, (entity, health)) in players.iter().enumerate() {
+                spawn_spectator_camera_button(
+                    parent,
+                    Some(*entity),
+                    format!("Player {} - {:.0}/{:.0}", i + 1, health.current, health.max),
+                    *spectator_camera == SpectatorCamera::Follow(*entity),
+                );
+            }
+        });
+}
+
+fn spectator_camera_control(
+    time: Res<Time>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut spectator_camera: ResMut<SpectatorCamera>,
+    interaction_q: Query<(&Interaction, &SpectatorCameraButton), Changed<Interaction>>,
+    player_q: Query<(Entity, &Health), (With<Player>, With<Interpolated>)>,
+    mut camera_q: Query<(&mut Transform, &OrthographicProjection), With<PlayerCamera>>,
+) {
+    for (interaction, button) in interaction_q.iter() {
+        if *interaction == Interaction::Pressed {
+            *spectator_camera = match button.0 {
+                Some(entity) => SpectatorCamera::Follow(entity),
+                None => SpectatorCamera::Free,
+            };
+        }
+    }
+
+    let players = sorted_players(&player_q);
+
+    // The followed player may have been despawned
+    if let SpectatorCamera::Follow(entity) = *spectator_camera {
+        if !players.iter().any(|(player, _)| *player == entity) {
+            *spectator_camera = SpectatorCamera::Free;
+        }
+    }
+
+    if keyboard.just_pressed(KeyCode::KeyF) {
+        *spectator_camera = SpectatorCamera::Free;
+    }
+
+    if keyboard.just_pressed(KeyCode::Tab) && !players.is_empty() {
+        let next_index = match *spectator_camera {
+            SpectatorCamera::Follow(entity) => players
+                .iter()
+                .position(|(player, _)| *player == entity)
+                .map_or(0, |i| (i + 1) % players.len()),
+            SpectatorCamera::Free => 0,
+        };
+        *spectator_camera = SpectatorCamera::Follow(players[next_index].0);
+    }
+
+    let mut direction = Vec2::ZERO;
+    if keyboard.pressed(KeyCode::KeyW) || keyboard.pressed(KeyCode::ArrowUp) {
+        direction.y += 1.;
+    }
+    if keyboard.pressed(KeyCode::KeyS) || keyboard.pressed(KeyCode::ArrowDown) {
+        direction.y -= 1.;
+    }
+    if keyboard.pressed(KeyCode::KeyA) || keyboard.pressed(KeyCode::ArrowLeft) {
+        direction.x -= 1.;
+    }
+    if keyboard.pressed(KeyCode::KeyD) || keyboard.pressed(KeyCode::ArrowRight) {
+        direction.x += 1.;
+    }
+
+    if direction == Vec2::ZERO {
+        return;
+    }
+
+    // Moving the camera always stops following
+    *spectator_camera = SpectatorCamera::Free;
+    for (mut camera_transform, ortho_proj) in camera_q.iter_mut() {
+        let translation =
+            direction.normalize() * FREE_CAMERA_SPEED * ortho_proj.scale * time.delta_secs();
+        camera_transform.translation += translation.extend(0.);
+    }
+}
+
+fn spectator_camera_follow(
+    time: Res<Time>,
+    spectator_camera: Res<SpectatorCamera>,
+    player_q: Query<&Transform, (With<Player>, With<Interpolated>)>,
+    mut camera_q: Query<&mut Transform, (With<PlayerCamera>, Without<Player>)>,
+) {
+    let SpectatorCamera::Follow(entity) = *spectator_camera else {
+        return;
+    };
+    let Ok(player_transform) = player_q.get(entity) else {
+        return;
+    };
+
+    for mut camera_transform in camera_q.iter_mut() {
+        let Vec3 { x, y, .. } = player_transform.translation;
+        let direction = Vec3::new(x, y, camera_transform.translation.z);
+        camera_transform
+            .translation
+            .smooth_nudge(&direction, CAMERA_DECAY_RATE, time.delta_secs());
+    }
+}
+
+pub struct SpectatorPlugin;
+
+impl Plugin for SpectatorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SpectatorCamera>();
+        app.add_systems(
+            OnEnter(AppState::Play),
+            spectator_hud_setup.run_if(is_spectator),
+        );
+        app.add_systems(
+            Update,
+            (
+                spectator_camera_control,
+                update_spectator_player_list.run_if(on_timer(Duration::from_millis(500))),
+            )
+                .chain()
+                .run_if(in_state(AppState::Play).and(is_spectator)),
+        );
+        app.add_systems(
+            PostUpdate,
+            spectator_camera_follow
+                .before(TransformSystem::TransformPropagate)
+                .after(TransformEasingSet::UpdateEasingTick)
+                .run_if(in_state(AppState::Play).and(is_spectator)),
+        );
+    }
+}
@@ -14,18 +14,29 @@ pub fn ui_text_input_focus(
     for (interaction_entity, interaction) in &query {
         if *interaction == Interaction::Pressed {
             for (entity, mut inactive, mut border_color) in &mut text_input_query {
-                if entity == interaction_entity {
-                    inactive.0 = false;
-                    *border_color = BORDER_COLOR_ACTIVE.into();
-                } else {
-                    inactive.0 = true;
-                    *border_color = BORDER_COLOR_INACTIVE.into();
-                }
+                set_text_input_active(
+                    &mut inactive,
+                    &mut border_color,
+                    entity == interaction_entity,
+                );
             }
         }
     }
 }
 
+pub fn set_text_input_active(
+    inactive: &mut TextInputInactive,
+    border_color: &mut BorderColor,
+    active: bool,
+) {
+    inactive.0 = !active;
+    *border_color = if active {
+        BORDER_COLOR_ACTIVE.into()
+    } else {
+        BORDER_COLOR_INACTIVE.into()
+    };
+}
+
 pub fn create_text_input<C: Component>(
     commands: &mut Commands,
     marker: C,
@@ -0,0 +1,73 @@
+// Enemy avoidance benchmarks, run with `cargo bench -p lerp-common-game`.
+// A tick at 64 Hz leaves 15.6ms to the whole simulation.
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use lerp_common_game::prelude::*;
+
+const ENEMY_COUNTS: [usize; 3] = [1000, 2000, 4000];
+
+/// Enemies spread evenly on a disk (sunflower pattern), about one per square meter,
+/// so that each one has a full ring of neighbors to avoid
+fn enemies(count: usize) -> Vec<SpatialEntry> {
+    let golden_angle = PI * (3. - 5f32.sqrt());
+    (0..count)
+        .map(|i| SpatialEntry {
+            entity: Entity::from_raw(i as u32),
+            position: Vec2::from_angle(i as f32 * golden_angle)
+                * (i as f32 / PI).sqrt()
+                * PIXEL_METER,
+            velocity: Vec2::ZERO,
+            radius: ENEMY_SIZE / 2.,
+            team: Team::Enemy,
+        })
+        .collect()
+}
+
+fn bench_enemy_avoidance(c: &mut Criterion) {
+    let mut group = c.benchmark_group("enemy_avoidance");
+    for enemy_count in ENEMY_COUNTS {
+        let enemies = enemies(enemy_count);
+        let mut spatial_index = SpatialIndex::default();
+
+        group.bench_with_input(
+            BenchmarkId::new("rebuild", enemy_count),
+            &enemies,
+            |b, enemies| b.iter(|| spatial_index.rebuild(enemies.iter().copied())),
+        );
+        // What runs every tick, all the enemies chasing a player at the center of the pack
+        spatial_index.rebuild(enemies.iter().copied());
+        group.bench_with_input(
+            BenchmarkId::new("avoidance", enemy_count),
+            &enemies,
+            |b, enemies| {
+                b.iter(|| {
+                    enemies
+                        .iter()
+                        .map(|enemy| {
+                            let agent = AvoidanceAgent {
+                                entity: enemy.entity,
+                                position: enemy.position,
+                                velocity: enemy.velocity,
+                                radius: enemy.radius,
+                                preferred_velocity: -enemy.position.normalize_or_zero()
+                                    * ENEMY_BASE_MOVEMENT_SPEED,
+                                max_speed: ENEMY_BASE_MOVEMENT_SPEED,
+                            };
+                            avoidance_velocity(
+                                &spatial_index,
+                                &agent,
+                                1. / FIXED_TIMESTEP_HZ as f32,
+                            )
+                        })
+                        .sum::<Vec2>()
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_enemy_avoidance);
+criterion_main!(benches);
@@ -0,0 +1,78 @@
+// Flow field benchmarks on the giga map, run with `cargo bench -p lerp-common-game`.
+use bevy::ecs::world::CommandQueue;
+use bevy::prelude::*;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use lerp_common_game::prelude::*;
+
+const PLAYER_COUNTS: [usize; 3] = [1, 4, 16];
+
+fn load_giga_map() -> Map {
+    let world = World::new();
+    let mut queue = CommandQueue::default();
+    let mut commands = Commands::new(&mut queue, &world);
+    let mut map_grid = Map::default();
+    load_map(
+        &mut commands,
+        &mut map_grid,
+        &MapFile::builtin("giga").unwrap(),
+    );
+    map_grid
+}
+
+/// One aggro target per player, on walkable nav tiles spread over the whole map
+fn player_goals(map_grid: &Map, count: usize, distances: &[u32]) -> Vec<FlowFieldGoal> {
+    let mut walkable: Vec<NavTileCoord> = map_grid
+        .nav_map
+        .iter()
+        .filter(|(_, nav_tile)| nav_tile.walkable)
+        .map(|(coord, _)| *coord)
+        .collect();
+    walkable.sort_by_key(|coord| (coord.y, coord.x));
+    let step = walkable.len() / count;
+    walkable
+        .into_iter()
+        .step_by(step)
+        .take(count)
+        .enumerate()
+        .map(|(i, coord)| FlowFieldGoal {
+            target: Entity::from_raw(i as u32),
+            coord,
+            distances: distances.to_vec(),
+        })
+        .collect()
+}
+
+fn bench_flow_field(c: &mut Criterion) {
+    let map_grid = load_giga_map();
+    let ranged_distances = [PreferredDistance(8. * PIXEL_METER).nav_tiles()];
+
+    let mut group = c.benchmark_group("flow_field");
+    for player_count in PLAYER_COUNTS {
+        let goals = player_goals(&map_grid, player_count, &[]);
+        let ranged_goals = player_goals(&map_grid, player_count, &ranged_distances);
+        let mut flow_fields = FlowFields::default();
+
+        // Worst case, all the players moved to another nav tile
+        group.bench_with_input(
+            BenchmarkId::new("recompute", player_count),
+            &goals,
+            |b, goals| b.iter(|| flow_fields.update(&map_grid, goals, true)),
+        );
+        // Same with the fields of the ranged enemies
+        group.bench_with_input(
+            BenchmarkId::new("recompute_ranged", player_count),
+            &ranged_goals,
+            |b, goals| b.iter(|| flow_fields.update(&map_grid, goals, true)),
+        );
+        // Most ticks, the players stayed on their nav tile
+        group.bench_with_input(
+            BenchmarkId::new("unchanged", player_count),
+            &ranged_goals,
+            |b, goals| b.iter(|| flow_fields.update(&map_grid, goals, false)),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_flow_field);
+criterion_main!(benches);
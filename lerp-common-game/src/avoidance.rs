@@ -0,0 +1,392 @@
+// Local avoidance of the enemies (ORCA, optimal reciprocal collision avoidance), adapted from RVO2
+// https://gamma.cs.unc.edu/RVO2/ without the static obstacles, the walls being avoided by the flow fields.
+// Each enemy picks the velocity closest to its preferred one that does not collide with its neighbors
+// within the time horizon, enemies share the avoidance effort while the players do not take part in it.
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+/// Other characters further than this are ignored
+pub const AVOIDANCE_NEIGHBOR_DISTANCE: f32 = 3. * PIXEL_METER;
+/// Only the closest neighbors are avoided, a dense pack only needs the first ring around an enemy
+const AVOIDANCE_MAX_NEIGHBORS: usize = 10;
+/// In seconds, collisions further in time are ignored, a shorter horizon lets the enemies get closer to each other
+const AVOIDANCE_TIME_HORIZON: f32 = 0.5;
+const EPSILON: f32 = 1e-5;
+
+/// Half plane of the allowed velocities, on the left of the line
+#[derive(Clone, Copy)]
+struct Line {
+    point: Vec2,
+    direction: Vec2,
+}
+
+pub struct AvoidanceAgent {
+    pub entity: Entity,
+    pub position: Vec2,
+    pub velocity: Vec2,
+    pub radius: f32,
+    pub preferred_velocity: Vec2,
+    pub max_speed: f32,
+}
+
+/// Velocity of the agent avoiding its neighbors of the spatial index, for a tick of the given duration.
+/// Neighbors are taken in the order of the index, so that the result is the same on the clients and the server
+pub fn avoidance_velocity(
+    spatial_index: &SpatialIndex,
+    agent: &AvoidanceAgent,
+    delta_secs: f32,
+) -> Vec2 {
+    let mut neighbors: Vec<(f32, &SpatialEntry)> = spatial_index
+        .query(agent.position, AVOIDANCE_NEIGHBOR_DISTANCE)
+        // Characters on top of each other are separated by the character controller
+        .filter(|other| other.entity != agent.entity && other.position != agent.position)
+        .map(|other| (other.position.distance_squared(agent.position), other))
+        .collect();
+    // Stable, the ties keep the order of the index
+    neighbors.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+    neighbors.truncate(AVOIDANCE_MAX_NEIGHBORS);
+
+    let lines: Vec<Line> = neighbors
+        .iter()
+        .map(|(_, other)| {
+            // The players do not avoid the enemies, the enemies take the whole effort
+            let responsibility = if other.team == Team::Enemy { 0.5 } else { 1. };
+            orca_line(agent, other, responsibility, delta_secs)
+        })
+        .collect();
+
+    let mut result = Vec2::ZERO;
+    let line_fail = linear_program_2(
+        &lines,
+        agent.max_speed,
+        agent.preferred_velocity,
+        false,
+        &mut result,
+    );
+    if line_fail < lines.len() {
+        linear_program_3(&lines, line_fail, agent.max_speed, &mut result);
+    }
+    // Degenerate lines, e.g. a neighbor exactly where the agent would be after the tick
+    if result.is_finite() {
+        result
+    } else {
+        Vec2::ZERO
+    }
+}
+
+/// Half plane of the velocities avoiding the other character within the time horizon
+fn orca_line(
+    agent: &AvoidanceAgent,
+    other: &SpatialEntry,
+    responsibility: f32,
+    delta_secs: f32,
+) -> Line {
+    let relative_position = other.position - agent.position;
+    let relative_velocity = agent.velocity - other.velocity;
+    let dist_sq = relative_position.length_squared();
+    let combined_radius = agent.radius + other.radius;
+    let combined_radius_sq = combined_radius * combined_radius;
+
+    let direction;
+    let u;
+    if dist_sq > combined_radius_sq {
+        // No collision yet
+        let inv_time_horizon = 1. / AVOIDANCE_TIME_HORIZON;
+        // Vector from the cutoff center to the relative velocity
+        let w = relative_velocity - inv_time_horizon * relative_position;
+        let w_length_sq = w.length_squared();
+        let dot_product = w.dot(relative_position);
+
+        if dot_product < 0. && dot_product * dot_product > combined_radius_sq * w_length_sq {
+            // Project on the cutoff circle
+            let w_length = w_length_sq.sqrt();
+            let unit_w = w / w_length;
+            direction = Vec2::new(unit_w.y, -unit_w.x);
+            u = (combined_radius * inv_time_horizon - w_length) * unit_w;
+        } else {
+            // Project on the legs
+            let leg = (dist_sq - combined_radius_sq).sqrt();
+            direction = if relative_position.perp_dot(w) > 0. {
+                Vec2::new(
+                    relative_position.x * leg - relative_position.y * combined_radius,
+                    relative_position.x * combined_radius + relative_position.y * leg,
+                ) / dist_sq
+            } else {
+                -Vec2::new(
+                    relative_position.x * leg + relative_position.y * combined_radius,
+                    -relative_position.x * combined_radius + relative_position.y * leg,
+                ) / dist_sq
+            };
+            u = relative_velocity.dot(direction) * direction - relative_velocity;
+        }
+    } else {
+        // Already colliding, separate within the tick
+        let inv_delta_secs = 1. / delta_secs;
+        let w = relative_velocity - inv_delta_secs * relative_position;
+        let w_length = w.length();
+        let unit_w = w / w_length;
+        direction = Vec2::new(unit_w.y, -unit_w.x);
+        u = (combined_radius * inv_delta_secs - w_length) * unit_w;
+    }
+
+    Line {
+        point: agent.velocity + responsibility * u,
+        direction,
+    }
+}
+
+/// Optimal velocity on the given line, within the previous lines and the max speed circle
+fn linear_program_1(
+    lines: &[Line],
+    line_no: usize,
+    radius: f32,
+    opt_velocity: Vec2,
+    direction_opt: bool,
+    result: &mut Vec2,
+) -> bool {
+    let line = lines[line_no];
+    let dot_product = line.point.dot(line.direction);
+    let discriminant = dot_product * dot_product + radius * radius - line.point.length_squared();
+    if discriminant < 0. {
+        // The max speed circle fully invalidates the line
+        return false;
+    }
+
+    let sqrt_discriminant = discriminant.sqrt();
+    let mut t_left = -dot_product - sqrt_discriminant;
+    let mut t_right = -dot_product + sqrt_discriminant;
+
+    for other in &lines[..line_no] {
+        let denominator = line.direction.perp_dot(other.direction);
+        let numerator = other.direction.perp_dot(line.point - other.point);
+
+        if denominator.abs() <= EPSILON {
+            // Parallel lines
+            if numerator < 0. {
+                return false;
+            }
+            continue;
+        }
+
+        let t = numerator / denominator;
+        if denominator >= 0. {
+            t_right = t_right.min(t);
+        } else {
+            t_left = t_left.max(t);
+        }
+        if t_left > t_right {
+            return false;
+        }
+    }
+
+    let t = if direction_opt {
+        if opt_velocity.dot(line.direction) > 0. {
+            t_right
+        } else {
+            t_left
+        }
+    } else {
+        line.direction
+            .dot(opt_velocity - line.point)
+            .max(t_left)
+            .min(t_right)
+    };
+    *result = line.point + t * line.direction;
+    true
+}
+
+/// Velocity closest to the optimal one within all the lines and the max speed circle.
+/// Returns the number of lines when it succeeds, else the first line it failed on
+fn linear_program_2(
+    lines: &[Line],
+    radius: f32,
+    opt_velocity: Vec2,
+    direction_opt: bool,
+    result: &mut Vec2,
+) -> usize {
+    *result = if direction_opt {
+        // The optimal velocity is a unit direction
+        opt_velocity * radius
+    } else {
+        opt_velocity.clamp_length_max(radius)
+    };
+
+    for (line_no, line) in lines.iter().enumerate() {
+        if line.direction.perp_dot(line.point - *result) > 0. {
+            // The result does not satisfy the line
+            let previous = *result;
+            if !linear_program_1(lines, line_no, radius, opt_velocity, direction_opt, result) {
+                *result = previous;
+                return line_no;
+            }
+        }
+    }
+    lines.len()
+}
+
+/// Too crowded to satisfy all the lines, the velocity violating them the least
+fn linear_program_3(lines: &[Line], begin_line: usize, radius: f32, result: &mut Vec2) {
+    let mut distance = 0.;
+    for (line_no, line) in lines.iter().enumerate().skip(begin_line) {
+        if line.direction.perp_dot(line.point - *result) <= distance {
+            continue;
+        }
+
+        let mut projected_lines = vec![];
+        for other in &lines[..line_no] {
+            let determinant = line.direction.perp_dot(other.direction);
+            let point = if determinant.abs() <= EPSILON {
+                if line.direction.dot(other.direction) > 0. {
+                    // Same direction
+                    continue;
+                }
+                0.5 * (line.point + other.point)
+            } else {
+                line.point
+                    + (other.direction.perp_dot(line.point - other.point) / determinant)
+                        * line.direction
+            };
+            projected_lines.push(Line {
+                point,
+                direction: (other.direction - line.direction).normalize(),
+            });
+        }
+
+        let previous = *result;
+        if linear_program_2(
+            &projected_lines,
+            radius,
+            line.direction.perp(),
+            true,
+            result,
+        ) < projected_lines.len()
+        {
+            // Can only happen because of floating point errors, keep the previous result
+            *result = previous;
+        }
+        distance = line.direction.perp_dot(line.point - *result);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RADIUS: f32 = PIXEL_METER / 2.;
+    const SPEED: f32 = 3. * PIXEL_METER;
+    const DELTA_SECS: f32 = 1. / FIXED_TIMESTEP_HZ as f32;
+
+    struct Character {
+        entity: Entity,
+        position: Vec2,
+        velocity: Vec2,
+        team: Team,
+    }
+
+    fn characters(characters: &[(Vec2, Vec2, Team)]) -> Vec<Character> {
+        characters
+            .iter()
+            .enumerate()
+            .map(|(index, (position, velocity, team))| Character {
+                entity: Entity::from_raw(index as u32),
+                position: *position,
+                velocity: *velocity,
+                team: *team,
+            })
+            .collect()
+    }
+
+    fn spatial_index(characters: &[Character]) -> SpatialIndex {
+        let mut spatial_index = SpatialIndex::default();
+        spatial_index.rebuild(characters.iter().map(|character| SpatialEntry {
+            entity: character.entity,
+            position: character.position,
+            velocity: character.velocity,
+            radius: RADIUS,
+            team: character.team,
+        }));
+        spatial_index
+    }
+
+    /// Velocity of the character avoiding the others, its current velocity being the preferred one
+    fn avoid(spatial_index: &SpatialIndex, character: &Character) -> Vec2 {
+        let agent = AvoidanceAgent {
+            entity: character.entity,
+            position: character.position,
+            velocity: character.velocity,
+            radius: RADIUS,
+            preferred_velocity: character.velocity,
+            max_speed: SPEED,
+        };
+        avoidance_velocity(spatial_index, &agent, DELTA_SECS)
+    }
+
+    /// Closest distance between the two characters moving at the given velocities within the time horizon
+    fn closest_distance(a: &Character, a_velocity: Vec2, b: &Character, b_velocity: Vec2) -> f32 {
+        (0..=100)
+            .map(|step| step as f32 / 100. * AVOIDANCE_TIME_HORIZON)
+            .map(|t| (a.position + a_velocity * t).distance(b.position + b_velocity * t))
+            .fold(f32::INFINITY, f32::min)
+    }
+
+    /// Both characters avoid each other, sharing the effort
+    fn assert_reciprocal_avoidance(characters: &[Character]) {
+        let spatial_index = spatial_index(characters);
+        let [a, b] = characters else {
+            panic!("Expected two characters");
+        };
+        // They would collide without avoidance
+        assert!(closest_distance(a, a.velocity, b, b.velocity) < 2. * RADIUS);
+
+        let a_velocity = avoid(&spatial_index, a);
+        let b_velocity = avoid(&spatial_index, b);
+        assert!(a_velocity.length() <= SPEED + 1e-3, "{a_velocity}");
+        assert!(b_velocity.length() <= SPEED + 1e-3, "{b_velocity}");
+        let distance = closest_distance(a, a_velocity, b, b_velocity);
+        assert!(distance >= 2. * RADIUS - 0.5, "{distance}");
+    }
+
+    #[test]
+    fn agent_without_neighbors_keeps_its_preferred_velocity() {
+        let characters = characters(&[(Vec2::ZERO, Vec2::new(SPEED, 0.), Team::Enemy)]);
+        let spatial_index = spatial_index(&characters);
+        assert_eq!(avoid(&spatial_index, &characters[0]), Vec2::new(SPEED, 0.));
+    }
+
+    #[test]
+    fn head_on_agents_avoid_each_other() {
+        assert_reciprocal_avoidance(&characters(&[
+            (Vec2::new(-40., 0.), Vec2::new(SPEED, 0.), Team::Enemy),
+            (Vec2::new(40., 2.), Vec2::new(-SPEED, 0.), Team::Enemy),
+        ]));
+    }
+
+    #[test]
+    fn crossing_agents_avoid_each_other() {
+        assert_reciprocal_avoidance(&characters(&[
+            (Vec2::new(-50., 0.), Vec2::new(SPEED, 0.), Team::Enemy),
+            (Vec2::new(0., -50.), Vec2::new(0., SPEED), Team::Enemy),
+        ]));
+    }
+
+    #[test]
+    fn agent_takes_the_whole_effort_around_a_static_player() {
+        let characters = characters(&[
+            (Vec2::ZERO, Vec2::new(SPEED, 0.), Team::Enemy),
+            (Vec2::new(60., 2.), Vec2::ZERO, Team::Player),
+        ]);
+        let spatial_index = spatial_index(&characters);
+        let [enemy, player] = &characters[..] else {
+            unreachable!();
+        };
+        assert!(closest_distance(enemy, enemy.velocity, player, Vec2::ZERO) < 2. * RADIUS);
+
+        let velocity = avoid(&spatial_index, enemy);
+        let distance = closest_distance(enemy, velocity, player, Vec2::ZERO);
+        assert!(distance >= 2. * RADIUS - 0.5, "{distance}");
+        // Goes around the player rather than away from it
+        assert!(velocity.x > 0., "{velocity}");
+        assert!(velocity.y < 0., "{velocity}");
+    }
+}
@@ -103,6 +103,7 @@ impl CharacterAliveBundle {
             physics: PhysicsBundle {
                 rigid_body: RigidBody::Kinematic,
                 collider: Collider::circle(alive_data.collider_diameter / 2.),
+                collision_layers: GameLayer::character(alive_data.team),
             },
             movement_speed: MovementSpeed(alive_data.movement_speed),
         }
@@ -0,0 +1,48 @@
+// Chat messages sent by clients and relayed by the server.
+// Text starting with `/` is a command parsed by the server, see `/help`.
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// Longer messages are rejected by the server
+pub const CHAT_MESSAGE_MAX_LEN: usize = 200;
+/// Max messages a client can send per [`CHAT_RATE_LIMIT_PERIOD`]
+pub const CHAT_RATE_LIMIT_MESSAGES: usize = 5;
+pub const CHAT_RATE_LIMIT_PERIOD: Duration = Duration::from_secs(5);
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChatKind {
+    /// Everyone in the instance
+    Say,
+    /// Members of the sender's party
+    Party,
+    /// An action of the sender, `/me waves`
+    Emote,
+    /// Sent by the server
+    System,
+}
+
+/// Sent by a client
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SendChat {
+    pub text: String,
+}
+
+/// Relayed or sent by the server
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ChatMessage {
+    pub kind: ChatKind,
+    /// Username of the sender, None for system messages
+    pub sender: Option<String>,
+    pub text: String,
+}
+
+impl ChatMessage {
+    pub fn system(text: impl Into<String>) -> Self {
+        Self {
+            kind: ChatKind::System,
+            sender: None,
+            text: text.into(),
+        }
+    }
+}
@@ -1,12 +1,30 @@
 use avian2d::prelude::*;
+use bevy::ecs::entity::MapEntities;
 use bevy::prelude::*;
 use lightyear::prelude::{client::Predicted, server::ReplicationTarget, PreSpawnedPlayerObject};
+use serde::{Deserialize, Serialize};
 
 use crate::prelude::*;
 
 #[derive(Component)]
 pub struct Enemy;
 
+/// Decided by the server from the threat of the players, enemies without it stay idle
+#[derive(Component, Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum Aggro {
+    /// Chasing the player
+    Target(Entity),
+    /// Walking back to its spawn position, ignoring the players
+    Leash(Vec2),
+}
+impl MapEntities for Aggro {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        if let Aggro::Target(target) = self {
+            *target = entity_mapper.map_entity(*target);
+        }
+    }
+}
+
 #[derive(Bundle)]
 pub struct EnemyBundle {
     character: CharacterBundle,
@@ -22,10 +40,14 @@ impl EnemyBundle {
 #[derive(Bundle)]
 pub struct EnemyLocalBundle {
     marker: Enemy,
+    path: EnemyPath,
 }
 impl EnemyLocalBundle {
     pub fn init() -> Self {
-        Self { marker: Enemy }
+        Self {
+            marker: Enemy,
+            path: EnemyPath::default(),
+        }
     }
 }
 
@@ -35,13 +57,25 @@ pub fn enemy_init_local(entity: Entity, commands: &mut Commands) {
 }
 
 pub fn enemy_movement_behavior(
+    time: Res<Time>,
     map_grid: Res<Map>,
-    flow_field: Res<FlowField>,
+    flow_fields: Res<FlowFields>,
+    spatial_index: Res<SpatialIndex>,
+    target_q: Query<&Position>,
     mut query_enemies: Query<
-        (&Position, &mut LinearVelocity, &MovementSpeed),
+        (
+            Entity,
+            &Position,
+            &mut LinearVelocity,
+            &MovementSpeed,
+            &mut EnemyPath,
+            Option<&Aggro>,
+            Option<&PreferredDistance>,
+        ),
         (
             With<Enemy>,
             With<Alive>,
+            Without<ForcedMovement>,
             Or<(
                 With<Predicted>,
                 With<PreSpawnedPlayerObject>,
@@ -50,61 +84,57 @@ pub fn enemy_movement_behavior(
         ),
     >,
 ) {
-    // Collect and sort enemies deterministically
-    let mut enemies: Vec<_> = query_enemies.iter_mut().collect();
-    enemies.sort_by(|(pos_a, _, _), (pos_b, _, _)| {
-        pos_a
-            .x
-            .partial_cmp(&pos_b.x)
-            .unwrap_or(std::cmp::Ordering::Equal)
-            .then_with(|| {
-                pos_a
-                    .y
-                    .partial_cmp(&pos_b.y)
-                    .unwrap_or(std::cmp::Ordering::Equal)
-            })
-    });
-
-    // Store enemy positions for separation
-    let enemies_position: Vec<_> = enemies.iter().map(|(pos, _, _)| *pos).collect();
-
-    let mut i: i32 = 0;
-    #[allow(clippy::explicit_counter_loop)]
-    for (enemy_position, mut enemy_velocity, movement_speed) in enemies {
-        // Retrieve the flow field direction
-        let flow_direction = flow_field.get_direction_from_position(&map_grid, enemy_position);
+    // Each enemy only reads the spatial index built before this system, the order of the query does not matter
+    for (
+        entity,
+        enemy_position,
+        mut enemy_velocity,
+        movement_speed,
+        mut enemy_path,
+        aggro,
+        preferred_distance,
+    ) in query_enemies.iter_mut()
+    {
+        let movement_speed = terrain_movement_speed(&map_grid, enemy_position, movement_speed.0);
 
-        // Scale flow field force to movement speed
-        let flow_field_force = flow_direction.map_or(Vec2::ZERO, |d| {
-            d.to_normalized_velocity() * movement_speed.0
-        });
+        // Retrieve the direction to the aggro target, ranged enemies follow the field of their preferred distance
+        let flow_direction = match aggro {
+            Some(Aggro::Target(target)) => target_q.get(*target).ok().and_then(|target_position| {
+                enemy_direction(
+                    &map_grid,
+                    &flow_fields,
+                    &mut enemy_path,
+                    enemy_position,
+                    Some(*target),
+                    target_position,
+                    preferred_distance,
+                )
+            }),
+            Some(Aggro::Leash(spawn_position)) => enemy_direction(
+                &map_grid,
+                &flow_fields,
+                &mut enemy_path,
+                enemy_position,
+                None,
+                &Position(*spawn_position),
+                None,
+            ),
+            None => None,
+        };
 
-        // Separation behavior
-        let mut separation_force = Vec2::ZERO;
-        let separation_distance = 1.0 * PIXEL_METER;
-        for other_position in &enemies_position {
-            if other_position != &enemy_position {
-                let diff = enemy_position.0 - other_position.0;
-                let dist_sq = diff.length_squared();
-                if dist_sq < separation_distance.powi(2) && dist_sq > 0.0 {
-                    let force = diff / dist_sq.sqrt();
-                    if !force.is_nan() {
-                        separation_force += force;
-                    }
-                }
-            }
+        // Scale flow field direction to movement speed, then avoid the other characters around
+        let preferred_velocity = flow_direction.map_or(Vec2::ZERO, |d| d * movement_speed);
+        let agent = AvoidanceAgent {
+            entity,
+            position: enemy_position.0,
+            velocity: enemy_velocity.0,
+            radius: ENEMY_SIZE / 2.,
+            preferred_velocity,
+            max_speed: movement_speed,
+        };
+        let new_velocity = avoidance_velocity(&spatial_index, &agent, time.delta_secs());
+        if new_velocity != enemy_velocity.0 {
+            enemy_velocity.0 = new_velocity;
         }
-
-        // Scale separation force to avoid overpowering flow field
-        let separation_force_scale = if i % 3 == 0 { 0.5 } else { 0.25 };
-        separation_force =
-            separation_force.normalize_or_zero() * movement_speed.0 * separation_force_scale;
-
-        // Combine forces
-        let combined_force = flow_field_force + separation_force;
-
-        // Update velocity
-        enemy_velocity.0 = combined_force.clamp_length_max(movement_speed.0);
-        i += 1;
     }
 }
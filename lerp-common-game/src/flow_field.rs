@@ -1,141 +1,715 @@
-use std::collections::VecDeque;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, VecDeque};
+use std::f32::consts::SQRT_2;
 
 use avian2d::prelude::Position;
 use bevy::{math::UVec2, prelude::*, utils::HashMap};
 use lightyear::prelude::{client::Predicted, server::ReplicationTarget};
+use serde::{Deserialize, Serialize};
 
 use crate::prelude::*;
 
-#[derive(Clone, Copy)]
-pub enum FlowFieldDirection {
-    North,
-    South,
-    West,
-    East,
-    NorthWest,
-    SouthWest,
-    SouthEast,
-    NorthEast,
+// Neighbors of a nav tile, diagonals last
+const NEIGHBORS: [IVec2; 8] = [
+    IVec2::new(0, 1),
+    IVec2::new(0, -1),
+    IVec2::new(-1, 0),
+    IVec2::new(1, 0),
+    IVec2::new(-1, 1),
+    IVec2::new(1, 1),
+    IVec2::new(-1, -1),
+    IVec2::new(1, -1),
+];
+/// Path cost at which the search stops, a ground nav tile costs 1
+const MAX_SEACH_DISTANCE: f32 = 40.;
+/// Path cost at which the A* fallback gives up
+const MAX_PATH_COST: f32 = MAX_SEACH_DISTANCE * 4.;
+/// In nav tiles, distance the goal of a path moves before it is computed again
+const REPATH_DISTANCE: i32 = 4;
+/// Half width kept free around the smoothed player paths, a pixel short of the collider radius so that one tile wide corridors stay in line of sight
+const PLAYER_PATH_CLEARANCE: f32 = PLAYER_SIZE / 2. - 1.;
+
+/// Path cost of the nav tiles of a map, None when the characters never path through it
+#[derive(Default)]
+struct TileCosts {
+    costs: Vec<Option<f32>>,
+    size: UVec2,
 }
-impl FlowFieldDirection {
-    pub fn to_normalized_velocity(&self) -> Vec2 {
-        match self {
-            FlowFieldDirection::North => Vec2::new(0.0, 1.0),
-            FlowFieldDirection::South => Vec2::new(0.0, -1.0),
-            FlowFieldDirection::West => Vec2::new(-1.0, 0.0),
-            FlowFieldDirection::East => Vec2::new(1.0, 0.0),
-            FlowFieldDirection::NorthWest => Vec2::new(-1.0, 1.0).normalize(),
-            FlowFieldDirection::SouthWest => Vec2::new(-1.0, -1.0).normalize(),
-            FlowFieldDirection::SouthEast => Vec2::new(1.0, -1.0).normalize(),
-            FlowFieldDirection::NorthEast => Vec2::new(1.0, 1.0).normalize(),
+impl TileCosts {
+    fn load(&mut self, map_grid: &Map, blocked: &[NavTileCoord]) {
+        self.size = map_grid.nav_map_size;
+        self.costs.clear();
+        self.costs
+            .resize((self.size.x * self.size.y) as usize, None);
+        for (coord, nav_tile) in map_grid.nav_map.iter() {
+            if let Some(index) = tile_index(self.size, coord.0.as_ivec2()) {
+                self.costs[index] = nav_tile
+                    .walkable
+                    .then(|| nav_tile.terrain.path_cost())
+                    .flatten();
+            }
+        }
+        for coord in blocked {
+            if let Some(index) = tile_index(self.size, coord.0.as_ivec2()) {
+                self.costs[index] = None;
+            }
+        }
+    }
+
+    fn get(&self, coord: IVec2) -> Option<f32> {
+        tile_index(self.size, coord).and_then(|index| self.costs[index])
+    }
+
+    /// Cost of the step from the given tile to its neighbor.
+    /// Diagonal steps are only allowed when both adjacent tiles are passable, so that enemies do not cut the wall corners
+    fn step_cost(&self, from: IVec2, offset: IVec2) -> Option<f32> {
+        let cost = self.get(from + offset)?;
+        if offset.x != 0 && offset.y != 0 {
+            self.get(from + IVec2::new(offset.x, 0))?;
+            self.get(from + IVec2::new(0, offset.y))?;
+            return Some(cost * SQRT_2);
         }
+        Some(cost)
     }
 }
 
-pub struct FlowFieldNode {
-    pub direction: FlowFieldDirection,
-    pub distance: u32,
+fn tile_index(size: UVec2, coord: IVec2) -> Option<usize> {
+    (coord.x >= 0 && coord.y >= 0 && (coord.x as u32) < size.x && (coord.y as u32) < size.y)
+        .then(|| (coord.y as u32 * size.x + coord.x as u32) as usize)
 }
 
-#[derive(Resource, Default)]
+/// Dijkstra queue entry, ordered by lowest cost first
+#[derive(PartialEq)]
+struct Step {
+    cost: f32,
+    index: usize,
+}
+impl Eq for Step {}
+impl Ord for Step {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .cost
+            .total_cmp(&self.cost)
+            .then_with(|| other.index.cmp(&self.index))
+    }
+}
+impl PartialOrd for Step {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Default)]
 pub struct FlowField {
-    pub map: HashMap<NavTileCoord, FlowFieldDirection>,
+    /// Integration field by nav tile index, path cost to the nearest goal of the tiles the search reached.
+    /// Sparse since the search stops at [`MAX_SEACH_DISTANCE`], a dense field would cover the whole map
+    costs: HashMap<usize, f32>,
+    /// Normalized direction to the cheapest neighbor of the reached tiles, none for the goals
+    directions: HashMap<usize, Vec2>,
     pub size: UVec2,
 }
 impl FlowField {
-    pub fn get_direction_from_position(
+    pub fn get(&self, coord: NavTileCoord) -> Option<Vec2> {
+        tile_index(self.size, coord.0.as_ivec2())
+            .and_then(|index| self.directions.get(&index).copied())
+    }
+
+    /// Direction interpolated between the 4 nav tiles around the position, so that enemies do not move in octants
+    pub fn get_direction_from_position(&self, map_grid: &Map, position: &Position) -> Option<Vec2> {
+        // In nav tiles, relative to the center of the tile on the bottom left of the position
+        let tile_position = (position.0 + map_grid.map_px_half_size) / NAV_TILE_SIZE - 0.5;
+        let origin = tile_position.floor();
+        let fraction = tile_position - origin;
+
+        let mut direction = Vec2::ZERO;
+        for (offset, weight) in [
+            (IVec2::new(0, 0), (1. - fraction.x) * (1. - fraction.y)),
+            (IVec2::new(1, 0), fraction.x * (1. - fraction.y)),
+            (IVec2::new(0, 1), (1. - fraction.x) * fraction.y),
+            (IVec2::new(1, 1), fraction.x * fraction.y),
+        ] {
+            if let Some(direction_at) = tile_index(self.size, origin.as_ivec2() + offset)
+                .and_then(|index| self.directions.get(&index))
+            {
+                direction += *direction_at * weight;
+            }
+        }
+
+        // Opposite directions can cancel out, fallback on the tile of the position
+        let direction = direction.normalize_or_zero();
+        if direction != Vec2::ZERO {
+            return Some(direction);
+        }
+        self.get(map_grid.position_to_nav_map_tile_coord(position))
+    }
+
+    /// Dijkstra from the given seeds and their initial cost
+    fn compute(
+        &mut self,
+        tile_costs: &TileCosts,
+        seeds: impl Iterator<Item = (usize, f32)>,
+        heap: &mut BinaryHeap<Step>,
+    ) {
+        self.size = tile_costs.size;
+        self.costs.clear();
+        self.directions.clear();
+
+        heap.clear();
+        for (index, cost) in seeds {
+            if cost < self.cost(index) {
+                self.costs.insert(index, cost);
+                heap.push(Step { cost, index });
+            }
+        }
+
+        while let Some(Step { cost, index }) = heap.pop() {
+            // Already reached with a lower cost
+            if cost > self.cost(index) {
+                continue;
+            }
+
+            let coord = IVec2::new(
+                (index as u32 % self.size.x) as i32,
+                (index as u32 / self.size.x) as i32,
+            );
+            for offset in NEIGHBORS {
+                let Some(step_cost) = tile_costs.step_cost(coord, offset) else {
+                    continue;
+                };
+                let neighbor_cost = cost + step_cost;
+                if neighbor_cost > MAX_SEACH_DISTANCE {
+                    continue;
+                }
+                let neighbor_index = tile_index(self.size, coord + offset).unwrap();
+                if neighbor_cost < self.cost(neighbor_index) {
+                    self.costs.insert(neighbor_index, neighbor_cost);
+                    heap.push(Step {
+                        cost: neighbor_cost,
+                        index: neighbor_index,
+                    });
+                }
+            }
+        }
+
+        // Point each reached tile to its cheapest neighbor, the goals have none
+        for (&index, &cost) in self.costs.iter() {
+            let coord = IVec2::new(
+                (index as u32 % self.size.x) as i32,
+                (index as u32 / self.size.x) as i32,
+            );
+            let mut best = (cost, IVec2::ZERO);
+            for offset in NEIGHBORS {
+                if tile_costs.step_cost(coord, offset).is_none() {
+                    continue;
+                }
+                let neighbor_cost = self.cost(tile_index(self.size, coord + offset).unwrap());
+                if neighbor_cost < best.0 {
+                    best = (neighbor_cost, offset);
+                }
+            }
+            if best.1 != IVec2::ZERO {
+                self.directions
+                    .insert(index, best.1.as_vec2().normalize_or_zero());
+            }
+        }
+    }
+
+    /// Path cost of the nav tile to the nearest goal, infinite out of reach
+    fn cost(&self, index: usize) -> f32 {
+        self.costs.get(&index).copied().unwrap_or(f32::INFINITY)
+    }
+}
+
+/// Ranged enemies stop at this distance from the players, in pixels
+#[derive(Component, Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct PreferredDistance(pub f32);
+impl PreferredDistance {
+    /// Path cost of the distance, key of its flow field
+    pub fn nav_tiles(&self) -> u32 {
+        ((self.0 / NAV_TILE_SIZE).round() as u32).min(MAX_SEACH_DISTANCE as u32 - 1)
+    }
+}
+
+/// Aggro target of the fields and the distances kept by the ranged enemies chasing it
+pub struct FlowFieldGoal {
+    pub target: Entity,
+    pub coord: NavTileCoord,
+    /// See [`PreferredDistance::nav_tiles`]
+    pub distances: Vec<u32>,
+}
+
+#[derive(Default)]
+struct TargetFlowFields {
+    /// Nav tile of the target the fields were computed for
+    coord: Option<NavTileCoord>,
+    /// Towards the target
+    chase: FlowField,
+    /// Towards the tiles at the given path cost from the target, see [`PreferredDistance`]
+    keep_distance: HashMap<u32, FlowField>,
+}
+
+#[derive(Resource, Default)]
+pub struct FlowFields {
+    /// One set of fields per aggro target, see [`Aggro`]
+    targets: HashMap<Entity, TargetFlowFields>,
+    // Kept between the updates to reuse their allocations
+    tile_costs: TileCosts,
+    heap: BinaryHeap<Step>,
+    /// Nav tiles covered by the obstacles, sorted
+    blocked: Vec<NavTileCoord>,
+}
+impl FlowFields {
+    pub fn get(
         &self,
-        map_grid: &Map,
-        position: &Position,
-    ) -> Option<&FlowFieldDirection> {
-        let map_node_pos = map_grid.position_to_nav_map_tile_coord(position);
-        self.map.get(&map_node_pos)
-    }
-}
-
-// Directions for neighbor traversal
-const DIRECTIONS: [(i32, i32, FlowFieldDirection); 8] = [
-    (0, 1, FlowFieldDirection::South),
-    (0, -1, FlowFieldDirection::North),
-    (-1, 0, FlowFieldDirection::East),
-    (1, 0, FlowFieldDirection::West),
-    (-1, 1, FlowFieldDirection::SouthEast),
-    (1, 1, FlowFieldDirection::SouthWest),
-    (-1, -1, FlowFieldDirection::NorthEast),
-    (1, -1, FlowFieldDirection::NorthWest),
-];
-const MAX_SEACH_DISTANCE: u32 = 40;
+        target: Entity,
+        preferred_distance: Option<&PreferredDistance>,
+    ) -> Option<&FlowField> {
+        let fields = self.targets.get(&target)?;
+        Some(
+            preferred_distance
+                .and_then(|distance| fields.keep_distance.get(&distance.nav_tiles()))
+                .unwrap_or(&fields.chase),
+        )
+    }
 
-pub fn update_flow_field(
-    map_grid: Res<Map>,
-    mut flow_field: ResMut<FlowField>,
-    player_q: Query<&Position, (With<Player>, Or<(With<Predicted>, With<ReplicationTarget>)>)>,
-) {
-    // Get all goal positions
-    let goals: Vec<Position> = player_q.iter().copied().collect();
+    pub fn size(&self) -> UVec2 {
+        self.tile_costs.size
+    }
+
+    /// Direction towards the nearest target at the nav tile, for the debug render
+    pub fn get_nearest(&self, coord: NavTileCoord) -> Option<Vec2> {
+        let index = tile_index(self.tile_costs.size, coord.0.as_ivec2())?;
+        self.targets
+            .values()
+            .filter_map(|fields| {
+                Some((
+                    fields.chase.cost(index),
+                    *fields.chase.directions.get(&index)?,
+                ))
+            })
+            .min_by(|(cost_a, _), (cost_b, _)| cost_a.total_cmp(cost_b))
+            .map(|(_, direction)| direction)
+    }
 
-    flow_field.map.clear();
-    flow_field.size = map_grid.nav_map_size;
+    /// Replace the nav tiles covered by the obstacles, returns whether they changed.
+    /// The fields are then recomputed by the next update, as for a map change
+    pub fn set_blocked(&mut self, mut blocked: Vec<NavTileCoord>) -> bool {
+        blocked.sort_unstable_by_key(|coord| (coord.y, coord.x));
+        blocked.dedup();
+        if blocked == self.blocked {
+            return false;
+        }
+        self.blocked = blocked;
+        true
+    }
 
-    // Initialize the visited map
-    let mut visited = HashMap::new();
+    /// Recompute the fields of the targets that moved to another nav tile, or all of them when the map changed.
+    /// Returns whether any field was recomputed
+    pub fn update(&mut self, map_grid: &Map, goals: &[FlowFieldGoal], map_changed: bool) -> bool {
+        let mut updated = false;
+        if map_changed || self.tile_costs.size != map_grid.nav_map_size {
+            self.tile_costs.load(map_grid, &self.blocked);
+            self.targets.clear();
+        }
 
-    // Create a separate queue for each goal
-    let mut queues: Vec<VecDeque<(NavTileCoord, u32)>> =
-        goals.iter().map(|_| VecDeque::new()).collect();
+        let target_count = self.targets.len();
+        self.targets
+            .retain(|target, _| goals.iter().any(|goal| goal.target == *target));
+        updated |= self.targets.len() != target_count;
 
-    // Initialize each goal's BFS queue with distance 0
-    for (i, goal_position) in goals.iter().enumerate() {
-        let goal_map_node_pos = map_grid.position_to_nav_map_tile_coord(goal_position);
-        queues[i].push_back((goal_map_node_pos, 0));
-        visited.insert(goal_map_node_pos, None); // None indicates this is a goal
+        let size = self.tile_costs.size;
+        for goal in goals {
+            let fields = self.targets.entry(goal.target).or_default();
+
+            let goal_moved = fields.coord != Some(goal.coord);
+            if goal_moved {
+                fields.coord = Some(goal.coord);
+                let seeds = tile_index(size, goal.coord.0.as_ivec2()).map(|index| (index, 0.));
+                fields
+                    .chase
+                    .compute(&self.tile_costs, seeds.into_iter(), &mut self.heap);
+                updated = true;
+            }
+
+            fields
+                .keep_distance
+                .retain(|distance, _| goal.distances.contains(distance));
+            for distance in &goal.distances {
+                if !goal_moved && fields.keep_distance.contains_key(distance) {
+                    continue;
+                }
+                // The seeds are the ring of tiles at the distance, enemies inside of it flow outward
+                let ring = (*distance as f32)..(*distance as f32 + SQRT_2);
+                let seeds = fields
+                    .chase
+                    .costs
+                    .iter()
+                    .filter(|(_, cost)| ring.contains(*cost))
+                    .map(|(index, _)| (*index, 0.));
+                fields.keep_distance.entry(*distance).or_default().compute(
+                    &self.tile_costs,
+                    seeds,
+                    &mut self.heap,
+                );
+                updated = true;
+            }
+        }
+        updated
     }
 
-    // Process the BFS in a round-robin manner (pop once per goal)
-    while queues.iter().any(|q| !q.is_empty()) {
-        // Iterate over each goal's queue
-        for queue in &mut queues {
-            // Skip if the queue for this goal is empty
-            if queue.is_empty() {
-                continue;
+    /// A* on the nav tiles the characters path through, for the goals out of reach of the flow fields and the players movement targets.
+    /// Returns the nav tiles from the start, excluded, to the goal
+    pub fn find_path(&self, from: NavTileCoord, to: NavTileCoord) -> Option<Vec<NavTileCoord>> {
+        let size = self.tile_costs.size;
+        let from_index = tile_index(size, from.0.as_ivec2())?;
+        let to_index = tile_index(size, to.0.as_ivec2())?;
+        let to = to.0.as_ivec2();
+        // Do not search the whole map for a goal in a wall
+        self.tile_costs.get(to)?;
+        // Octile distance, a ground nav tile being the cheapest
+        let heuristic = |coord: IVec2| {
+            let delta = (to - coord).abs();
+            let (min, max) = (delta.min_element() as f32, delta.max_element() as f32);
+            max - min + min * SQRT_2
+        };
+
+        // Nav tile index to its path cost and previous tile
+        let mut reached: HashMap<usize, (f32, usize)> = HashMap::default();
+        let mut heap = BinaryHeap::new();
+        reached.insert(from_index, (0., from_index));
+        heap.push(Step {
+            cost: heuristic(from.0.as_ivec2()),
+            index: from_index,
+        });
+
+        while let Some(Step { index, .. }) = heap.pop() {
+            if index == to_index {
+                let mut path = vec![];
+                let mut current = index;
+                while current != from_index {
+                    path.push(NavTileCoord(UVec2::new(
+                        current as u32 % size.x,
+                        current as u32 / size.x,
+                    )));
+                    current = reached[&current].1;
+                }
+                path.reverse();
+                return Some(path);
             }
 
-            if let Some((current, distance)) = queue.pop_front() {
-                // If we've exceeded the maximum search distance, stop exploring further
-                if distance >= MAX_SEACH_DISTANCE {
+            let cost = reached[&index].0;
+            let coord = IVec2::new(
+                (index as u32 % size.x) as i32,
+                (index as u32 / size.x) as i32,
+            );
+            for offset in NEIGHBORS {
+                let Some(step_cost) = self.tile_costs.step_cost(coord, offset) else {
+                    continue;
+                };
+                let neighbor_cost = cost + step_cost;
+                if neighbor_cost > MAX_PATH_COST {
+                    continue;
+                }
+                let neighbor_index = tile_index(size, coord + offset).unwrap();
+                if reached
+                    .get(&neighbor_index)
+                    .is_some_and(|(reached_cost, _)| *reached_cost <= neighbor_cost)
+                {
                     continue;
                 }
+                reached.insert(neighbor_index, (neighbor_cost, index));
+                heap.push(Step {
+                    cost: neighbor_cost + heuristic(coord + offset),
+                    index: neighbor_index,
+                });
+            }
+        }
+        None
+    }
 
-                let current_pos = current.0;
-
-                for (dx, dy, direction) in DIRECTIONS.iter() {
-                    let neighbor_pos = NavTileCoord(UVec2::new(
-                        (current_pos.x as i32 + dx) as u32,
-                        (current_pos.y as i32 + dy) as u32,
-                    ));
-
-                    // Skip out-of-bounds or already visited nodes
-                    if visited.contains_key(&neighbor_pos) {
-                        continue;
-                    }
-
-                    // Check if the neighbor is walkable
-                    if let Some(neighbor_node) = map_grid.nav_map.get(&neighbor_pos) {
-                        if neighbor_node.walkable {
-                            // Mark the neighbor as visited and record its direction
-                            visited.insert(neighbor_pos, Some(*direction));
-                            queue.push_back((neighbor_pos, distance + 1));
-                        }
-                    }
+    /// String pulling of a path from [`Self::find_path`], only keeps the nav tiles where the path turns around an obstacle.
+    /// Returns the positions to walk through, the last one being the goal
+    pub fn smooth_path(
+        &self,
+        map_grid: &Map,
+        from: Vec2,
+        path: &[NavTileCoord],
+        goal: Vec2,
+        clearance: f32,
+    ) -> Vec<Vec2> {
+        let mut points: Vec<Vec2> = path
+            .iter()
+            .map(|coord| map_grid.nav_map_tile_coord_to_position(*coord))
+            .collect();
+        match points.last_mut() {
+            Some(last) => *last = goal,
+            None => points.push(goal),
+        }
+
+        let mut waypoints = vec![];
+        let mut anchor = from;
+        for (previous, point) in points.iter().zip(points.iter().skip(1)) {
+            if !self.line_of_sight(map_grid, anchor, *point, clearance) {
+                waypoints.push(*previous);
+                anchor = *previous;
+            }
+        }
+        waypoints.extend(points.last());
+        waypoints
+    }
+
+    /// Whether a character of the given radius walks in a straight line between the positions,
+    /// without going through a nav tile more expensive than the ones at both ends
+    fn line_of_sight(&self, map_grid: &Map, from: Vec2, to: Vec2, clearance: f32) -> bool {
+        let cost_at = |position: Vec2| {
+            self.tile_costs.get(
+                map_grid
+                    .position_to_nav_map_tile_coord(&Position(position))
+                    .as_ivec2(),
+            )
+        };
+        let (Some(from_cost), Some(to_cost)) = (cost_at(from), cost_at(to)) else {
+            return false;
+        };
+        let max_cost = from_cost.max(to_cost);
+
+        let side = (to - from).perp().normalize_or_zero() * clearance;
+        [Vec2::ZERO, side, -side]
+            .iter()
+            .all(|offset| self.segment_passable(map_grid, from + *offset, to + *offset, max_cost))
+    }
+
+    /// Walk the nav tiles crossed by the segment (Amanatides-Woo), both tiles around a crossed corner included
+    fn segment_passable(&self, map_grid: &Map, from: Vec2, to: Vec2, max_cost: f32) -> bool {
+        let passable = |coord: IVec2| {
+            self.tile_costs
+                .get(coord)
+                .is_some_and(|cost| cost <= max_cost)
+        };
+
+        // In nav tiles
+        let start = (from + map_grid.map_px_half_size) / NAV_TILE_SIZE;
+        let end = (to + map_grid.map_px_half_size) / NAV_TILE_SIZE;
+        let delta = end - start;
+        // The step on an axis without movement is never taken, its border being infinitely far
+        let step = delta.signum().as_ivec2();
+        let mut coord = start.floor().as_ivec2();
+        // Fraction of the segment to the next tile border and between two tile borders, on each axis
+        let border_at = |start: f32, coord: i32, delta: f32| {
+            if delta > 0. {
+                (coord as f32 + 1. - start) / delta
+            } else if delta < 0. {
+                (start - coord as f32) / -delta
+            } else {
+                f32::INFINITY
+            }
+        };
+        let mut next_border = Vec2::new(
+            border_at(start.x, coord.x, delta.x),
+            border_at(start.y, coord.y, delta.y),
+        );
+        let border_step = Vec2::new(1. / delta.x.abs(), 1. / delta.y.abs());
+
+        if !passable(coord) {
+            return false;
+        }
+        while next_border.min_element() <= 1. {
+            if next_border.x < next_border.y {
+                coord.x += step.x;
+                next_border.x += border_step.x;
+            } else if next_border.y < next_border.x {
+                coord.y += step.y;
+                next_border.y += border_step.y;
+            } else {
+                if !passable(coord + IVec2::new(step.x, 0))
+                    || !passable(coord + IVec2::new(0, step.y))
+                {
+                    return false;
                 }
+                coord += step;
+                next_border += border_step;
             }
+            if !passable(coord) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Enemies chasing a target follow the field of the target, or a path when they are out of its reach.
+/// Predicted by the clients along with the enemy movement, so it is rolled back with it
+#[derive(Component, Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct EnemyPath {
+    /// Nav tile the path leads to
+    goal: Option<NavTileCoord>,
+    /// Next nav tiles to walk through
+    waypoints: VecDeque<NavTileCoord>,
+}
+
+/// Direction of the enemy towards the given goal
+pub fn enemy_direction(
+    map_grid: &Map,
+    flow_fields: &FlowFields,
+    enemy_path: &mut EnemyPath,
+    position: &Position,
+    target: Option<Entity>,
+    goal: &Position,
+    preferred_distance: Option<&PreferredDistance>,
+) -> Option<Vec2> {
+    if let Some(direction) = target
+        .and_then(|target| flow_fields.get(target, preferred_distance))
+        .and_then(|flow_field| flow_field.get_direction_from_position(map_grid, position))
+    {
+        enemy_path.goal = None;
+        return Some(direction);
+    }
+
+    // Out of reach of the fields, fallback on a path
+    let goal = map_grid.position_to_nav_map_tile_coord(goal);
+    let goal_moved = enemy_path.goal.is_none_or(|path_goal| {
+        path_goal.as_ivec2().distance_squared(goal.as_ivec2()) > REPATH_DISTANCE.pow(2)
+    });
+    if goal_moved {
+        let from = map_grid.position_to_nav_map_tile_coord(position);
+        enemy_path.goal = Some(goal);
+        enemy_path.waypoints = flow_fields.find_path(from, goal).unwrap_or_default().into();
+    }
+
+    while let Some(waypoint) = enemy_path.waypoints.front() {
+        let to_waypoint = map_grid.nav_map_tile_coord_to_position(*waypoint) - position.0;
+        if to_waypoint.length() > NAV_TILE_SIZE / 2. {
+            return Some(to_waypoint.normalize());
+        }
+        enemy_path.waypoints.pop_front();
+    }
+    None
+}
+
+/// Players walking to a [`MovementTarget`] follow a smoothed path to it.
+/// Consumed by the player movement, so it is rolled back with it
+#[derive(Component, Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct PlayerPath {
+    /// Nav tile the path leads to
+    goal: Option<NavTileCoord>,
+    /// Next positions to walk through, the last one being the movement target
+    waypoints: VecDeque<Vec2>,
+}
+impl PlayerPath {
+    pub fn clear(&mut self) {
+        if self.goal.is_some() {
+            self.goal = None;
+            self.waypoints.clear();
+        }
+    }
+}
+
+/// Direction of the player towards the given target, scaled down on the last step so that it stops on the target.
+/// Returns None once the target is reached, or when it is out of reach
+pub fn player_direction(
+    map_grid: &Map,
+    flow_fields: &FlowFields,
+    player_path: &mut PlayerPath,
+    position: &Position,
+    target: Vec2,
+    step: f32,
+) -> Option<Vec2> {
+    let goal = map_grid.position_to_nav_map_tile_coord(&Position(target));
+    if player_path.goal != Some(goal) {
+        let from = map_grid.position_to_nav_map_tile_coord(position);
+        player_path.goal = Some(goal);
+        player_path.waypoints = flow_fields
+            .find_path(from, goal)
+            .map(|path| {
+                flow_fields.smooth_path(map_grid, position.0, &path, target, PLAYER_PATH_CLEARANCE)
+            })
+            .unwrap_or_default()
+            .into();
+    } else if let Some(last) = player_path.waypoints.back_mut() {
+        // The target moved inside of the same nav tile
+        *last = target;
+    }
+
+    while let Some(waypoint) = player_path.waypoints.front() {
+        let to_waypoint = *waypoint - position.0;
+        if player_path.waypoints.len() == 1 {
+            if to_waypoint.length() > 1e-4 {
+                return Some((to_waypoint / step.max(1e-4)).clamp_length_max(1.));
+            }
+        } else if to_waypoint.length() > NAV_TILE_SIZE / 2. {
+            return Some(to_waypoint.normalize());
         }
+        player_path.waypoints.pop_front();
     }
+    player_path.clear();
+    None
+}
+
+/// Nav tiles under the collider of an obstacle
+fn obstacle_tiles(map_grid: &Map, position: Vec2, kind: ObstacleKind) -> Vec<NavTileCoord> {
+    // Tiles only touched by the border stay open
+    let half_size = Vec2::splat(kind.size() / 2. - 1.);
+    let min = map_grid.position_to_nav_map_tile_coord(&Position(position - half_size));
+    let max = map_grid.position_to_nav_map_tile_coord(&Position(position + half_size));
+    (min.y..=max.y)
+        .flat_map(|y| (min.x..=max.x).map(move |x| NavTileCoord(UVec2::new(x, y))))
+        .collect()
+}
 
-    // Populate the flow field based on the visited map from all goals
-    for (&node_pos, &direction) in visited.iter() {
-        if let Some(dir) = direction {
-            flow_field.map.insert(node_pos, dir);
+pub fn update_flow_field(
+    map_grid: Res<Map>,
+    mut flow_fields: ResMut<FlowFields>,
+    target_q: Query<&Position>,
+    obstacle_q: Query<(&Position, &Obstacle), Or<(With<Predicted>, With<ReplicationTarget>)>>,
+    enemy_q: Query<
+        (&Aggro, Option<&PreferredDistance>),
+        (
+            With<Enemy>,
+            With<Alive>,
+            Or<(With<Predicted>, With<ReplicationTarget>)>,
+        ),
+    >,
+) {
+    // One goal per aggro target, with the distances of the ranged enemies chasing it
+    let mut goals: Vec<FlowFieldGoal> = vec![];
+    for (aggro, preferred_distance) in enemy_q.iter() {
+        let Aggro::Target(target) = aggro else {
+            continue;
+        };
+        let Ok(target_position) = target_q.get(*target) else {
+            continue;
+        };
+        let goal = match goals.iter_mut().find(|goal| goal.target == *target) {
+            Some(goal) => goal,
+            None => {
+                goals.push(FlowFieldGoal {
+                    target: *target,
+                    coord: map_grid.position_to_nav_map_tile_coord(target_position),
+                    distances: vec![],
+                });
+                goals.last_mut().unwrap()
+            }
+        };
+        if let Some(distance) = preferred_distance.map(PreferredDistance::nav_tiles) {
+            if !goal.distances.contains(&distance) {
+                goal.distances.push(distance);
+            }
         }
     }
+
+    // Destroyed obstacles open their tiles again
+    let blocked = obstacle_q
+        .iter()
+        .flat_map(|(position, obstacle)| obstacle_tiles(&map_grid, position.0, obstacle.kind))
+        .collect();
+    let blocked_changed = flow_fields.bypass_change_detection().set_blocked(blocked);
+
+    // Only take the fields mutably when they are recomputed, so that they are not flagged as changed every tick
+    let map_changed = map_grid.is_changed() || blocked_changed;
+    if flow_fields
+        .bypass_change_detection()
+        .update(&map_grid, &goals, map_changed)
+    {
+        flow_fields.set_changed();
+    }
 }
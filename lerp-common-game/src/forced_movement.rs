@@ -0,0 +1,168 @@
+// Movements overriding the steering of a character for a few ticks: knockback on hit, player dash, enemy leap.
+// The velocity is set again every tick, so that the character controller still slides it along the walls.
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use lightyear::prelude::{client::Predicted, server::ReplicationTarget, PreSpawnedPlayerObject};
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+pub const KNOCKBACK_SPEED: f32 = 20. * PIXEL_METER;
+pub const DASH_SPEED: f32 = 24. * PIXEL_METER;
+pub const LEAP_SPEED: f32 = 15. * PIXEL_METER;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ForcedMovementKind {
+    /// Pushed away by a hit
+    Knockback,
+    /// Player dodge, the hits are ignored for its duration
+    Dash,
+    /// Enemy jumping towards its target
+    Leap,
+}
+
+/// Replaces the velocity given by the inputs or the enemy behavior.
+/// Counted in ticks, so that a rollback replays it identically
+#[derive(Component, Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ForcedMovement {
+    pub kind: ForcedMovementKind,
+    pub velocity: Vec2,
+    pub ticks_left: u16,
+}
+
+impl ForcedMovement {
+    /// Straight line of the given length in pixels, at the given speed
+    pub fn new(kind: ForcedMovementKind, direction: Vec2, distance: f32, speed: f32) -> Self {
+        let ticks = (distance / speed * FIXED_TIMESTEP_HZ as f32).ceil().max(1.);
+        Self {
+            kind,
+            velocity: direction.normalize_or_zero() * speed,
+            ticks_left: ticks as u16,
+        }
+    }
+
+    pub fn is_invulnerable(&self) -> bool {
+        self.kind == ForcedMovementKind::Dash
+    }
+}
+
+pub fn apply_forced_movement(
+    mut commands: Commands,
+    mut forced_q: Query<
+        (Entity, &mut ForcedMovement, &mut LinearVelocity),
+        Or<(
+            With<Predicted>,
+            With<PreSpawnedPlayerObject>,
+            With<ReplicationTarget>,
+        )>,
+    >,
+) {
+    for (entity, mut forced_movement, mut linear_velocity) in forced_q.iter_mut() {
+        linear_velocity.0 = forced_movement.velocity;
+        forced_movement.ticks_left = forced_movement.ticks_left.saturating_sub(1);
+        // The steering takes over on the next tick
+        if forced_movement.ticks_left == 0 {
+            commands.entity(entity).remove::<ForcedMovement>();
+        }
+    }
+}
+
+pub fn on_execute_skill_dash_event(
+    mut commands: Commands,
+    mut excecute_skill_ev: EventReader<ExcecuteSkillEvent>,
+    skill_dash_q: Query<&SkillDash, With<Skill>>,
+    initiator_q: Query<&Position, Without<Skill>>,
+) {
+    for event in excecute_skill_ev.read() {
+        // Skills without SkillDash are handled by the other executors
+        let Ok(skill_dash) = skill_dash_q.get(event.skill) else {
+            continue;
+        };
+        let Ok(initiator_position) = initiator_q.get(event.initiator) else {
+            println!("[on_execute_skill_dash_event] Cannot find initiator entity");
+            continue;
+        };
+
+        commands.entity(event.initiator).insert(ForcedMovement::new(
+            ForcedMovementKind::Dash,
+            event.target - initiator_position.0,
+            skill_dash.distance,
+            DASH_SPEED,
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    /// Distance covered in the given number of ticks
+    fn ticks_distance(ticks: f32, speed: f32) -> f32 {
+        ticks * speed / FIXED_TIMESTEP_HZ as f32
+    }
+
+    #[test]
+    fn forced_movement_lasts_the_ticks_to_cover_its_distance() {
+        let dash = ForcedMovement::new(
+            ForcedMovementKind::Dash,
+            Vec2::new(0., -3.),
+            ticks_distance(10., DASH_SPEED),
+            DASH_SPEED,
+        );
+        assert_eq!(dash.ticks_left, 10);
+        assert_eq!(dash.velocity, Vec2::new(0., -DASH_SPEED));
+
+        // A partial tick is a full one
+        let leap = ForcedMovement::new(
+            ForcedMovementKind::Leap,
+            Vec2::X,
+            ticks_distance(4.5, LEAP_SPEED),
+            LEAP_SPEED,
+        );
+        assert_eq!(leap.ticks_left, 5);
+
+        let knockback = ForcedMovement::new(ForcedMovementKind::Knockback, Vec2::ZERO, 0., 1.);
+        assert_eq!(knockback.ticks_left, 1);
+        assert_eq!(knockback.velocity, Vec2::ZERO);
+    }
+
+    #[test]
+    fn forced_movement_is_removed_after_its_ticks() {
+        let mut world = World::new();
+        let forced_movement = ForcedMovement::new(
+            ForcedMovementKind::Knockback,
+            Vec2::X,
+            ticks_distance(3., KNOCKBACK_SPEED),
+            KNOCKBACK_SPEED,
+        );
+        let entity = world
+            .spawn((
+                forced_movement,
+                LinearVelocity::ZERO,
+                PreSpawnedPlayerObject::default(),
+            ))
+            .id();
+
+        for ticks_left in (0..3).rev() {
+            world.run_system_once(apply_forced_movement).unwrap();
+            assert_eq!(
+                world.get::<LinearVelocity>(entity).unwrap().0,
+                Vec2::X * KNOCKBACK_SPEED
+            );
+            assert_eq!(
+                world.get::<ForcedMovement>(entity).is_some(),
+                ticks_left > 0
+            );
+        }
+    }
+
+    #[test]
+    fn only_the_dash_ignores_hits() {
+        let forced_movement = |kind| ForcedMovement::new(kind, Vec2::X, 1., 1.);
+        assert!(forced_movement(ForcedMovementKind::Dash).is_invulnerable());
+        assert!(!forced_movement(ForcedMovementKind::Knockback).is_invulnerable());
+        assert!(!forced_movement(ForcedMovementKind::Leap).is_invulnerable());
+    }
+}
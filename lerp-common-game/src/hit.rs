@@ -1,13 +1,16 @@
+use avian2d::prelude::{LinearVelocity, Position};
 use bevy::{prelude::*, utils::hashbrown::HashSet};
 use lightyear::prelude::{
     client::{Predicted, PredictionDespawnCommandsExt},
     server::ReplicationTarget,
     NetworkIdentity,
 };
+use serde::{Deserialize, Serialize};
 
 use crate::prelude::*;
 
-#[derive(Component, Default)]
+/// Team of the source, replicated so that the clients build the collision layers of the projectiles they did not shoot
+#[derive(Component, Serialize, Deserialize, Clone, Copy, Default, Debug, PartialEq)]
 pub struct HitSource(pub Team);
 
 #[derive(Component, Default)]
@@ -16,6 +19,10 @@ pub struct Hittable {
     pub hit_track_map: HashSet<u64>,
 }
 
+/// Hits are still registered but deal no damage, toggled by an admin command
+#[derive(Component, Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct GodMode;
+
 pub struct HitEventData {
     pub source: Entity,
     pub skill: Entity,
@@ -29,18 +36,32 @@ pub fn on_hit_event(
     identity: NetworkIdentity,
     mut commands: Commands,
     mut hit_events: EventReader<HitEvent>,
+    spatial_index: Res<SpatialIndex>,
     mut source_q: Query<
         (
             &HitSource,
             &SkillInstanceHash,
             Option<&DamageOnHit>,
+            Option<&KnockbackOnHit>,
+            Option<&Position>,
+            Option<&mut LinearVelocity>,
             Option<&mut Pierce>,
+            Option<&mut ProjectileData>,
+            Option<&mut ProjectileBehaviors>,
+            Option<&LagCompensation>,
         ),
         (With<HitSource>, Without<Skill>, Without<Hittable>),
     >,
     _skill_q: Query<&SkillDamageOnHit, (With<Skill>, Without<HitSource>, Without<Hittable>)>,
     mut target: Query<
-        (&Team, Option<&mut Health>, &mut Hittable),
+        (
+            &Team,
+            Option<&mut Health>,
+            &mut Hittable,
+            Has<GodMode>,
+            Has<CharacterController>,
+            Option<&ForcedMovement>,
+        ),
         (
             With<Hittable>,
             Without<HitSource>,
@@ -53,17 +74,36 @@ pub fn on_hit_event(
 
     for event in hit_events.read() {
         for event_data in &event.0 {
-            let Ok((hit_source, skill_instance_hash, damage_on_hit, pierce)) =
-                source_q.get_mut(event_data.source)
+            // A projectile sweeping several targets in a tick stops at the first one it is consumed on
+            if despawned_entities.contains(&event_data.source) {
+                continue;
+            }
+
+            let Ok((
+                hit_source,
+                skill_instance_hash,
+                damage_on_hit,
+                knockback_on_hit,
+                source_position,
+                mut source_velocity,
+                pierce,
+                mut projectile_data,
+                mut projectile_behaviors,
+                lag_compensation,
+            )) = source_q.get_mut(event_data.source)
             else {
-                if !despawned_entities.contains(&event_data.source) {
-                    error!("[on_hit_event] Hit source does not exist in world");
-                }
+                error!("[on_hit_event] Hit source does not exist in world");
                 continue;
             };
 
-            let Ok((target_team, target_health, mut target_hittable)) =
-                target.get_mut(event_data.target)
+            let Ok((
+                target_team,
+                target_health,
+                mut target_hittable,
+                god_mode,
+                is_character,
+                target_forced_movement,
+            )) = target.get_mut(event_data.target)
             else {
                 if !despawned_entities.contains(&event_data.target) {
                     error!("[on_hit_event] Hit target does not exist in world");
@@ -76,20 +116,44 @@ pub fn on_hit_event(
                 continue;
             }
 
+            // Dashing characters are not hit, the source goes through them
+            if target_forced_movement.is_some_and(ForcedMovement::is_invulnerable) {
+                continue;
+            }
+
             // Insert the skill instance hash in the hit track map.
             // If it was already in, then we stop and do not apply any on hit effect.
             // This prevent shotguning.
             if !target_hittable.hit_track_map.insert(**skill_instance_hash) {
                 continue;
             }
+            if let Some(projectile_data) = projectile_data.as_mut() {
+                projectile_data.hit_targets.push(event_data.target);
+            }
 
             // If the source apply DamageOnHit and the target has Health, then apply damages.
-            if let (Some(damage_on_hit), Some(mut target_health)) = (damage_on_hit, target_health) {
+            if let (Some(damage_on_hit), Some(mut target_health), false) =
+                (damage_on_hit, target_health, god_mode)
+            {
                 target_health.current = (target_health.current - damage_on_hit.value)
                     .min(target_health.max)
                     .max(0.);
             }
 
+            // Push the characters away in the direction the source was moving
+            if let (Some(knockback_on_hit), Some(source_velocity), true) =
+                (knockback_on_hit, source_velocity.as_deref(), is_character)
+            {
+                commands
+                    .entity(event_data.target)
+                    .try_insert(ForcedMovement::new(
+                        ForcedMovementKind::Knockback,
+                        source_velocity.0,
+                        knockback_on_hit.distance,
+                        KNOCKBACK_SPEED,
+                    ));
+            }
+
             // Try to apply pierce, decrement count and continue if pierced applied.
             if let Some(mut pierce) = pierce {
                 if pierce.count >= 1 {
@@ -98,6 +162,36 @@ pub fn on_hit_event(
                 }
             }
 
+            // Projectile behaviors may keep it alive, e.g. chaining to the next target
+            if let (
+                Some(source_position),
+                Some(source_velocity),
+                Some(projectile_data),
+                Some(projectile_behaviors),
+            ) = (
+                source_position,
+                source_velocity.as_deref_mut(),
+                projectile_data.as_deref_mut(),
+                projectile_behaviors.as_deref_mut(),
+            ) {
+                if projectile_on_hit(
+                    &mut commands,
+                    &identity,
+                    &spatial_index,
+                    hit_source.0,
+                    **skill_instance_hash,
+                    source_position.0,
+                    source_velocity,
+                    projectile_data,
+                    projectile_behaviors,
+                    damage_on_hit,
+                    knockback_on_hit,
+                    lag_compensation,
+                ) {
+                    continue;
+                }
+            }
+
             if despawned_entities.insert(event_data.source) {
                 if identity.is_server() {
                     commands.entity(event_data.source).despawn();
@@ -5,6 +5,8 @@ use uuid::Uuid;
 pub struct HttpStartServerResponse {
     pub instance_port: u16,
     pub instance_uuid: Uuid,
+    /// Map of the instance, it can differ from the requested one when joining a party instance
+    pub map: String,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -17,3 +19,64 @@ pub struct HttpStopServerInput {
 pub struct HttpStopServerResponse {
     pub succcess: bool,
 }
+
+#[derive(Serialize, Deserialize)]
+pub struct HttpStartServerInput {
+    pub username: String,
+    /// Builtin map name, the default map when not given
+    #[serde(default)]
+    pub map: Option<String>,
+}
+
+/// Create, leave or get the party of a user
+#[derive(Serialize, Deserialize)]
+pub struct HttpPartyInput {
+    pub username: String,
+}
+
+/// Sent by the party leader, also used to give the lead to a member
+#[derive(Serialize, Deserialize)]
+pub struct HttpPartyMemberInput {
+    pub username: String,
+    pub member: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct HttpPartyAcceptInput {
+    pub username: String,
+    pub party_id: Uuid,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct HttpParty {
+    pub party_id: Uuid,
+    pub leader: String,
+    pub members: Vec<String>,
+    pub invited: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct HttpPartyResponse {
+    /// Party of the user
+    pub party: Option<HttpParty>,
+    /// Parties that invited the user
+    pub invites: Vec<HttpParty>,
+    pub error: Option<String>,
+}
+
+/// Run an admin command on a running instance, like `spawn 10 brute` or `killall`
+#[derive(Serialize, Deserialize)]
+pub struct HttpAdminCommandInput {
+    pub token: String,
+    pub instance_port: u16,
+    pub instance_uuid: Uuid,
+    /// Netcode client id of the player targeted by the player commands
+    pub client_id: Option<u64>,
+    pub command: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct HttpAdminCommandResponse {
+    pub success: bool,
+    pub message: String,
+}
@@ -5,8 +5,8 @@ use lightyear::{
     inputs::leafwing::input_buffer::InputBuffer,
     prelude::{
         client::{Predicted, Rollback},
-        server::{Replicate, ReplicationTarget, SyncTarget},
-        NetworkIdentity, NetworkTarget, PreSpawnedPlayerObject, TickManager,
+        server::ReplicationTarget,
+        NetworkIdentity, Tick, TickManager,
     },
 };
 use serde::{Deserialize, Serialize};
@@ -23,6 +23,8 @@ pub struct InputVec2 {
 #[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Debug, Clone, Copy, Reflect, Actionlike)]
 pub enum PlayerActions {
     Move,
+    /// Hold or click to walk along a path to the cursor
+    MoveToCursor,
     MoveUp,
     MoveDown,
     MoveLeft,
@@ -30,9 +32,16 @@ pub enum PlayerActions {
     SkillSlot1,
     SkillSlot2,
     SkillSlot3,
+    SkillSlot4,
+    SkillSlot5,
+    SkillSlot6,
+    SkillSlot7,
+    SkillSlot8,
     #[actionlike(DualAxis)]
     Cursor,
-    SpawnEnemies,
+    /// Tick of the characters the client tests its hits against when the input is applied, see lag_compensation
+    #[actionlike(Axis)]
+    TargetTick,
     // TODO: Dirty to dup them for local/remote but don't know how to it in better way yet
     // Also dirty to use TripleAxis to store entity bits but need to PR Leafwing or use another lib...
     #[actionlike(TripleAxis)]
@@ -45,7 +54,12 @@ impl PlayerActions {
     /// You could use the `strum` crate to derive this automatically!
     pub fn variants() -> impl Iterator<Item = PlayerActions> {
         use PlayerActions::*;
-        [SkillSlot1, SkillSlot2, SkillSlot3].iter().copied()
+        [
+            SkillSlot1, SkillSlot2, SkillSlot3, SkillSlot4, SkillSlot5, SkillSlot6, SkillSlot7,
+            SkillSlot8,
+        ]
+        .iter()
+        .copied()
     }
 }
 
@@ -74,6 +88,9 @@ pub fn handle_input_move_wasd(
     mut commands: Commands,
     tick_manager: Res<TickManager>,
     rollback: Option<Res<Rollback>>,
+    time: Res<Time>,
+    map_grid: Res<Map>,
+    flow_fields: Res<FlowFields>,
     mut player_cancel_action_ev: EventWriter<PlayerCancelAction>,
     mut player_query: Query<
         (
@@ -83,10 +100,16 @@ pub fn handle_input_move_wasd(
             &mut LinearVelocity,
             &Position,
             &MovementSpeed,
+            &mut PlayerPath,
             Has<SkillInProgress>,
+            Has<PendingItemDroppedPickup>,
             Option<&MovementTarget>,
         ),
-        (With<Player>, Or<(With<Predicted>, With<ReplicationTarget>)>),
+        (
+            With<Player>,
+            Without<ForcedMovement>,
+            Or<(With<Predicted>, With<ReplicationTarget>)>,
+        ),
     >,
 ) {
     let tick = rollback
@@ -101,7 +124,9 @@ pub fn handle_input_move_wasd(
         mut linear_velocity,
         position,
         movement_speed,
+        mut player_path,
         has_skill_in_progress,
+        has_pending_pickup,
         movement_target,
     ) in player_query.iter_mut()
     {
@@ -134,26 +159,55 @@ pub fn handle_input_move_wasd(
 
         direction = direction.clamp_length_max(1.0);
 
+        let modifier = if has_skill_in_progress { 0.6 } else { 1. };
+        let speed = terrain_movement_speed(&map_grid, position, movement_speed.0) * modifier;
+
+        // The server only accepts targets inside of the map, the path to them is validated by player_direction
+        let mut movement_target = movement_target.map(|target| target.0);
+        if direction == Vec2::ZERO && action.pressed(&PlayerActions::MoveToCursor) {
+            let cursor = action
+                .axis_pair(&PlayerActions::Cursor)
+                .clamp(-map_grid.map_px_half_size, map_grid.map_px_half_size);
+            if movement_target != Some(cursor) {
+                movement_target = Some(cursor);
+                commands
+                    .entity(entity)
+                    .insert(MovementTarget(cursor))
+                    .remove::<PendingItemDroppedPickup>();
+            }
+        }
+
         if direction != Vec2::ZERO {
             player_cancel_action_ev.send(PlayerCancelAction(entity));
             // TODO: Do we need this here ?
             if movement_target.is_some() {
                 commands.entity(entity).remove::<MovementTarget>();
             }
+            player_path.clear();
         } else if let Some(movement_target) = movement_target {
-            let to_target: Vec2 = movement_target.0 - position.0;
-            let distance_to_target = to_target.length();
-            // Target reached stopping
-            if distance_to_target <= 1e-4 {
-                direction = Vec2::ZERO;
-                commands.entity(entity).remove::<MovementTarget>();
-            } else {
-                direction = to_target.normalize();
+            match player_direction(
+                &map_grid,
+                &flow_fields,
+                &mut player_path,
+                position,
+                movement_target,
+                speed * time.delta_secs(),
+            ) {
+                Some(path_direction) => direction = path_direction,
+                // Target reached or out of reach, stopping
+                None => {
+                    commands.entity(entity).remove::<MovementTarget>();
+                    if has_pending_pickup {
+                        commands.entity(entity).remove::<PendingItemDroppedPickup>();
+                    }
+                }
             }
+        } else {
+            // The target was removed elsewhere, e.g. once the item to pickup is in range
+            player_path.clear();
         }
 
-        let modifier = if has_skill_in_progress { 0.6 } else { 1. };
-        let new_velocity = direction * movement_speed.0 * modifier;
+        let new_velocity = direction * speed;
         if new_velocity != linear_velocity.0 {
             linear_velocity.0 = new_velocity
         }
@@ -176,6 +230,9 @@ fn handle_input_skill_slot(
         let Some(cursor_position) = action.dual_axis_data(&PlayerActions::Cursor) else {
             continue;
         };
+        let target_tick = action
+            .axis_data(&PlayerActions::TargetTick)
+            .map(|target_tick| Tick(target_tick.value as u16));
 
         for player_action in PlayerActions::variants() {
             if !action.pressed(&player_action) {
@@ -197,54 +254,13 @@ fn handle_input_skill_slot(
                 initiator: entity,
                 skill: *skill_entity,
                 target: cursor_position.pair,
+                target_tick,
             });
             break;
         }
     }
 }
 
-fn handle_input_spawn_enemies(
-    tick_manager: Res<TickManager>,
-    identity: NetworkIdentity,
-    mut commands: Commands,
-    player_query: Query<
-        &ActionState<PlayerActions>,
-        (Or<(With<Predicted>, With<ReplicationTarget>)>,),
-    >,
-) {
-    for action in player_query.iter() {
-        if !action.just_pressed(&PlayerActions::SpawnEnemies) {
-            continue;
-        }
-
-        let mut count = 0;
-        for x in 0..5 {
-            for y in 0..5 {
-                count += 1;
-                let enemy = (
-                    EnemyBundle::new(&Vec2::new(x as f32 * ENEMY_SIZE, y as f32 * ENEMY_SIZE)),
-                    PreSpawnedPlayerObject::new(xor_u64s(&[tick_manager.tick().0 as u64, count])),
-                );
-                let enemy_entity = commands.spawn(enemy).id();
-
-                if identity.is_server() {
-                    commands.entity(enemy_entity).insert((Replicate {
-                        sync: SyncTarget {
-                            prediction: NetworkTarget::All,
-                            interpolation: NetworkTarget::None,
-                        },
-                        target: ReplicationTarget {
-                            target: NetworkTarget::All,
-                        },
-                        group: REPLICATION_GROUP,
-                        ..default()
-                    },));
-                }
-            }
-        }
-    }
-}
-
 fn handle_input_click(
     identity: NetworkIdentity,
     mut commands: Commands,
@@ -284,7 +300,6 @@ impl Plugin for InputPlugin {
             (
                 handle_input_move_wasd,
                 handle_input_skill_slot,
-                handle_input_spawn_enemies,
                 handle_input_click,
             )
                 .chain()
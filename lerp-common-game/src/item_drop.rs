@@ -36,6 +36,8 @@ pub struct PendingItemDroppedPickup(pub Entity);
 pub struct ItemDropped {
     pub position: Vec2,
     pub ratity: ItemRarity,
+    /// Only the members of this party can pick up the item
+    pub reserved_for: Option<PartyMember>,
 }
 impl ItemDropped {
     pub fn sound_effect(&self) -> Option<ItemDroppedSound> {
@@ -61,15 +63,30 @@ fn pickup_item_dropped(
             &Position,
             &PendingItemDroppedPickup,
             Option<&MovementTarget>,
+            Option<&PartyMember>,
         ),
         (Or<(With<Predicted>, With<ReplicationTarget>)>,),
     >,
     dropped_item_q: Query<&ItemDropped>,
 ) {
-    for (player_entity, player_position, pending_item_dropped_pickup, player_movement_target) in
-        player_q.iter()
+    for (
+        player_entity,
+        player_position,
+        pending_item_dropped_pickup,
+        player_movement_target,
+        party_member,
+    ) in player_q.iter()
     {
-        if let Ok(item_dropped) = dropped_item_q.get(pending_item_dropped_pickup.0) {
+        let item_dropped = dropped_item_q
+            .get(pending_item_dropped_pickup.0)
+            .ok()
+            // Loot reserved to another party is ignored
+            .filter(|item_dropped| {
+                item_dropped
+                    .reserved_for
+                    .is_none_or(|party| party_member == Some(&party))
+            });
+        if let Some(item_dropped) = item_dropped {
             let distance_to = player_position.0.distance(item_dropped.position);
 
             // Pickup item if in radius
@@ -81,7 +98,7 @@ fn pickup_item_dropped(
                 if identity.is_server() {
                     commands.entity(pending_item_dropped_pickup.0).despawn();
                 }
-            // Set MovementTarget to item location if not already set
+            // Set MovementTarget to item location if not already set, the player walks a path to it
             } else if player_movement_target.is_none()
                 || player_movement_target.is_some_and(|t| t.0 != item_dropped.position)
             {
@@ -90,7 +107,7 @@ fn pickup_item_dropped(
                     .insert(MovementTarget(item_dropped.position));
             }
         } else {
-            // Could not find the item, maybe already picked up, we remove the PendingItemDroppedPickup and potential MovementTarget
+            // Could not find the item, maybe already picked up or reserved, we remove the PendingItemDroppedPickup and potential MovementTarget
             commands
                 .entity(player_entity)
                 .remove::<(PendingItemDroppedPickup, MovementTarget)>();
@@ -0,0 +1,179 @@
+// Server side lag compensation of the projectiles shot by the players.
+// A client tests the hits of its projectiles against the characters of its own simulation, and sends with its
+// inputs the tick of these characters (its prediction tick, the enemies being predicted by every client).
+// The server triggers the skill when it applies the input, which is later than on the client if the input arrived
+// late, and tests the projectile against the characters as they were that many ticks before, walls being static.
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use lightyear::prelude::*;
+
+use crate::prelude::*;
+
+/// Half a second at 64 Hz, players with a higher latency are only partially compensated
+pub const LAG_COMPENSATION_MAX_TICKS: u16 = 32;
+
+/// Snapshots of the spatial index, the most recent first. Only inserted on the server
+#[derive(Resource, Default)]
+pub struct SpatialIndexHistory {
+    snapshots: VecDeque<SpatialIndex>,
+}
+impl SpatialIndexHistory {
+    pub fn record(&mut self, spatial_index: &SpatialIndex) {
+        // Reuse the allocations of the oldest snapshot
+        let mut snapshot = if self.snapshots.len() > LAG_COMPENSATION_MAX_TICKS as usize {
+            self.snapshots.pop_back().unwrap_or_default()
+        } else {
+            SpatialIndex::default()
+        };
+        snapshot.clone_from(spatial_index);
+        self.snapshots.push_front(snapshot);
+    }
+
+    /// Characters as they were the given number of ticks ago, or as far back as recorded
+    pub fn rewind(&self, ticks: u16) -> Option<&SpatialIndex> {
+        self.snapshots
+            .get(ticks as usize)
+            .or_else(|| self.snapshots.back())
+    }
+}
+
+/// Number of ticks the characters are rewound by when testing the hits of a projectile
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct LagCompensation {
+    pub rewind_ticks: u16,
+}
+impl LagCompensation {
+    /// Projectiles of a skill triggered at the given server tick, aimed at the characters of the target tick
+    pub fn new(trigger_tick: Tick, target_tick: Tick) -> Self {
+        let ticks = trigger_tick.0.wrapping_sub(target_tick.0) as i16;
+        Self {
+            rewind_ticks: (ticks.max(0) as u16).min(LAG_COMPENSATION_MAX_TICKS),
+        }
+    }
+}
+
+pub fn record_spatial_index_history(
+    spatial_index: Res<SpatialIndex>,
+    mut history: ResMut<SpatialIndexHistory>,
+) {
+    history.record(&spatial_index);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spatial_index_at(x: f32) -> SpatialIndex {
+        let mut spatial_index = SpatialIndex::default();
+        spatial_index.rebuild(std::iter::once(SpatialEntry {
+            entity: Entity::PLACEHOLDER,
+            position: Vec2::new(x, 0.),
+            velocity: Vec2::ZERO,
+            radius: 1.,
+            team: Team::Enemy,
+        }));
+        spatial_index
+    }
+
+    fn recorded_x(spatial_index: Option<&SpatialIndex>) -> f32 {
+        spatial_index
+            .and_then(|spatial_index| spatial_index.query(Vec2::ZERO, 64.).next())
+            .map(|entry| entry.position.x)
+            .unwrap()
+    }
+
+    #[test]
+    fn rewind_returns_the_snapshot_of_the_given_tick() {
+        let mut history = SpatialIndexHistory::default();
+        assert!(history.rewind(0).is_none());
+
+        for tick in 0..10 {
+            history.record(&spatial_index_at(tick as f32));
+        }
+        assert_eq!(recorded_x(history.rewind(0)), 9.);
+        assert_eq!(recorded_x(history.rewind(3)), 6.);
+        assert_eq!(recorded_x(history.rewind(9)), 0.);
+        // Older than recorded
+        assert_eq!(recorded_x(history.rewind(20)), 0.);
+    }
+
+    #[test]
+    fn record_keeps_the_last_ticks_only() {
+        let mut history = SpatialIndexHistory::default();
+        let recorded = LAG_COMPENSATION_MAX_TICKS as usize + 10;
+        for tick in 0..recorded {
+            history.record(&spatial_index_at(tick as f32));
+        }
+        assert_eq!(
+            history.snapshots.len(),
+            LAG_COMPENSATION_MAX_TICKS as usize + 1
+        );
+        let oldest = (recorded - 1 - LAG_COMPENSATION_MAX_TICKS as usize) as f32;
+        assert_eq!(
+            recorded_x(history.rewind(LAG_COMPENSATION_MAX_TICKS)),
+            oldest
+        );
+        assert_eq!(recorded_x(history.rewind(u16::MAX)), oldest);
+    }
+
+    #[test]
+    fn rewind_is_the_delay_of_the_trigger() {
+        let rewind = |trigger_tick, target_tick| {
+            LagCompensation::new(Tick(trigger_tick), Tick(target_tick)).rewind_ticks
+        };
+        // Input applied at the tick it is stamped with
+        assert_eq!(rewind(100, 100), 0);
+        assert_eq!(rewind(100, 90), 10);
+        assert_eq!(rewind(100, 0), LAG_COMPENSATION_MAX_TICKS);
+        // Across the wrap around of the ticks
+        assert_eq!(rewind(3, u16::MAX - 2), 6);
+        // A target ahead of the server is not rewound
+        assert_eq!(rewind(90, 100), 0);
+    }
+
+    /// Enemy walking along x by one unit per tick
+    fn enemy_x(tick: u16) -> f32 {
+        tick as f32
+    }
+
+    /// Ticks of flight before the projectile shot from `x = 200` towards the enemy, at 3 units per tick, hits it
+    fn flight_ticks_until_hit(
+        mut enemy_at: impl FnMut(u16) -> Option<SpatialIndex>,
+    ) -> Option<u16> {
+        (0..LAG_COMPENSATION_MAX_TICKS).find(|&flight| {
+            let projectile = Vec2::new(200. - 3. * flight as f32, 0.);
+            enemy_at(flight)
+                .is_some_and(|spatial_index| spatial_index.query(projectile, 2.).next().is_some())
+        })
+    }
+
+    #[test]
+    fn client_and_server_agree_on_a_late_input_hit() {
+        // The client triggers at its prediction tick 100, the input reaches the server 4 ticks late
+        let client_tick = 100;
+        let server_tick = 104;
+
+        let client_hit =
+            flight_ticks_until_hit(|flight| Some(spatial_index_at(enemy_x(client_tick + flight))));
+
+        let lag_compensation = LagCompensation::new(Tick(server_tick), Tick(client_tick));
+        let mut history = SpatialIndexHistory::default();
+        for tick in 0..server_tick {
+            history.record(&spatial_index_at(enemy_x(tick)));
+        }
+        let server_hit = flight_ticks_until_hit(|flight| {
+            let tick = server_tick + flight;
+            history.record(&spatial_index_at(enemy_x(tick)));
+            history.rewind(lag_compensation.rewind_ticks).cloned()
+        });
+
+        assert!(client_hit.is_some());
+        assert_eq!(client_hit, server_hit);
+
+        // Without rewinding the server sees the enemy 4 units further
+        let unrewound_hit =
+            flight_ticks_until_hit(|flight| Some(spatial_index_at(enemy_x(server_tick + flight))));
+        assert_ne!(client_hit, unrewound_hit);
+    }
+}
@@ -1,14 +1,20 @@
+pub mod avoidance;
 pub mod character;
+pub mod chat;
 pub mod enemy;
 pub mod flow_field;
+pub mod forced_movement;
 pub mod health;
 pub mod hit;
 pub mod http_api;
 pub mod input;
 pub mod item_drop;
+pub mod lag_compensation;
 pub mod mana;
 pub mod map;
 pub mod network;
+pub mod obstacle;
+pub mod party;
 pub mod physics;
 pub mod player;
 pub mod projectile;
@@ -16,23 +22,31 @@ pub mod protocol;
 pub mod settings;
 pub mod shared;
 pub mod skill;
+pub mod spatial_index;
 pub mod team;
+pub mod terrain;
 pub mod utils;
 pub mod wall;
 
 pub mod prelude {
+    pub use crate::avoidance::*;
     pub use crate::character::prelude::*;
+    pub use crate::chat::*;
     pub use crate::enemy::*;
     pub use crate::flow_field::*;
+    pub use crate::forced_movement::*;
     pub use crate::health::*;
     pub use crate::hit::*;
     pub use crate::http_api::*;
     pub use crate::input::*;
     pub use crate::item_drop::*;
+    pub use crate::lag_compensation::*;
     pub use crate::mana::*;
     pub use crate::map::prelude::*;
     pub use crate::map::*;
     pub use crate::network::prelude::*;
+    pub use crate::obstacle::*;
+    pub use crate::party::*;
     pub use crate::physics::*;
     pub use crate::player::*;
     pub use crate::projectile::*;
@@ -40,7 +54,9 @@ pub mod prelude {
     pub use crate::settings::*;
     pub use crate::shared::*;
     pub use crate::skill::*;
+    pub use crate::spatial_index::*;
     pub use crate::team::*;
+    pub use crate::terrain::*;
     pub use crate::utils::*;
     pub use crate::wall::*;
 }
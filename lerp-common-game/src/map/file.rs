@@ -0,0 +1,692 @@
+// Text map format: `key: value` metadata lines, a `---` separator, then the grid with its top row first.
+// Grid cells are `W` wall, `F` floor, `D` door, `S` player spawn, `E` enemy spawner and `.` empty.
+// Terrain cells are `~` shallow water, `L` lava and `P` pit, obstacle cells are `C` crate and `B` barrel.
+// `spawner: <column> <row> <preset>` metadata lines pick the settings preset of the `E` cell, rows counted from the top.
+// `spawn: <column> <row>` metadata lines add player spawn points on `F` cells, after the `S` cell.
+use std::fmt;
+use std::path::Path;
+
+use bevy::{prelude::*, utils::HashMap};
+use serde::{Deserialize, Serialize};
+
+use super::generator::{generate_dungeon, parse_generated_map_name, DungeonSettings};
+use super::input::MapInput;
+use crate::utils::StableHasher;
+
+/// Map loaded when none is selected
+pub const DEFAULT_MAP_NAME: &str = "large";
+
+/// Maps shipped with the game, see the `maps` directory
+const BUILTIN_MAPS: &[(&str, &str)] = &[
+    ("extra_small", include_str!("../../maps/extra_small.map")),
+    ("small", include_str!("../../maps/small.map")),
+    ("large", include_str!("../../maps/large.map")),
+    ("giga", include_str!("../../maps/giga.map")),
+    ("showcase", include_str!("../../maps/showcase.map")),
+];
+
+const GRID_SEPARATOR: &str = "---";
+/// Render map sizes must be a multiple of it, see [`super::map::Map::reset`]
+pub(super) const MAP_SIZE_MULTIPLE: usize = 10;
+/// Largest width and height of a map in grid cells, the replicated positions are quantized over it
+pub const MAX_MAP_SIZE: usize = 400;
+const GRID_CELLS: [char; 11] = ['W', 'F', 'D', 'S', 'E', '.', '~', 'L', 'P', 'C', 'B'];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MapFileError {
+    UnknownMap(String),
+    Io(String),
+    InvalidMetadata {
+        line: usize,
+        content: String,
+    },
+    UnknownKey {
+        line: usize,
+        key: String,
+    },
+    InvalidLighting(String),
+    InvalidSpawner {
+        line: usize,
+        content: String,
+    },
+    InvalidSpawnPoint {
+        line: usize,
+        content: String,
+    },
+    /// The cell of a `spawn` line is not an `F` cell
+    SpawnPointNotOnFloor {
+        line: usize,
+        column: usize,
+        row: usize,
+    },
+    /// The cell of a `spawner` line is not an `E` cell
+    SpawnerNotOnEnemyCell {
+        line: usize,
+        column: usize,
+        row: usize,
+    },
+    MissingName,
+    MissingGrid,
+    RaggedRow {
+        row: usize,
+        width: usize,
+        expected: usize,
+    },
+    InvalidSize {
+        width: usize,
+        height: usize,
+    },
+    TooLarge {
+        width: usize,
+        height: usize,
+    },
+    UnknownCell {
+        row: usize,
+        column: usize,
+        cell: char,
+    },
+    TooManySpawnPoints(usize),
+    ChecksumMismatch,
+    /// No layout generated from the seed has its rooms connected
+    UnconnectedDungeon(u64),
+}
+
+impl fmt::Display for MapFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownMap(name) => write!(f, "unknown map {}", name),
+            Self::Io(err) => write!(f, "cannot read map: {}", err),
+            Self::InvalidMetadata { line, content } => {
+                write!(f, "line {}: expected `key: value`, got `{}`", line, content)
+            }
+            Self::UnknownKey { line, key } => write!(f, "line {}: unknown key {}", line, key),
+            Self::InvalidLighting(value) => {
+                write!(f, "lighting must be a number in [0, 1], got {}", value)
+            }
+            Self::InvalidSpawner { line, content } => write!(
+                f,
+                "line {}: expected `spawner: <column> <row> <preset>`, got `{}`",
+                line, content
+            ),
+            Self::InvalidSpawnPoint { line, content } => write!(
+                f,
+                "line {}: expected `spawn: <column> <row>`, got `{}`",
+                line, content
+            ),
+            Self::SpawnPointNotOnFloor { line, column, row } => write!(
+                f,
+                "line {}: grid row {} column {} is not a floor cell",
+                line, row, column
+            ),
+            Self::SpawnerNotOnEnemyCell { line, column, row } => write!(
+                f,
+                "line {}: grid row {} column {} is not an enemy spawner",
+                line, row, column
+            ),
+            Self::MissingName => write!(f, "missing name"),
+            Self::MissingGrid => write!(f, "missing grid after `{}`", GRID_SEPARATOR),
+            Self::RaggedRow {
+                row,
+                width,
+                expected,
+            } => write!(
+                f,
+                "grid row {} has {} cells, expected {}",
+                row, width, expected
+            ),
+            Self::InvalidSize { width, height } => write!(
+                f,
+                "grid size {}x{} is not a multiple of {}",
+                width, height, MAP_SIZE_MULTIPLE
+            ),
+            Self::TooLarge { width, height } => write!(
+                f,
+                "grid size {}x{} is larger than {}x{}",
+                width, height, MAX_MAP_SIZE, MAX_MAP_SIZE
+            ),
+            Self::UnknownCell { row, column, cell } => {
+                write!(
+                    f,
+                    "grid row {} column {}: unknown cell {:?}",
+                    row, column, cell
+                )
+            }
+            Self::TooManySpawnPoints(count) => {
+                write!(f, "{} player spawn points, expected at most one", count)
+            }
+            Self::ChecksumMismatch => write!(f, "checksum mismatch"),
+            Self::UnconnectedDungeon(seed) => {
+                write!(f, "dungeon {} has no connected layout", seed)
+            }
+        }
+    }
+}
+
+impl std::error::Error for MapFileError {}
+
+pub struct MapFile {
+    pub name: String,
+    /// Ambient light, from 0 (dark) to 1
+    pub lighting: f32,
+    pub input: MapInput,
+    /// Settings preset of the enemy spawners by grid column and row, server only
+    pub spawner_presets: HashMap<UVec2, String>,
+    /// Player spawn points after the `S` cell, by grid column and row, server only
+    pub spawn_points: Vec<UVec2>,
+}
+
+impl MapFile {
+    /// Parse and validate a map, so that [`super::loader::load_map`] cannot fail on it
+    pub fn parse(content: &str) -> Result<Self, MapFileError> {
+        let mut lines = content.lines().enumerate();
+
+        let mut name = None;
+        let mut lighting = 1.;
+        // With their line, checked once the grid is read
+        let mut spawner_presets = vec![];
+        let mut spawn_points = vec![];
+        for (index, line) in lines.by_ref() {
+            let line = line.trim();
+            if line == GRID_SEPARATOR {
+                break;
+            }
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once(':') else {
+                return Err(MapFileError::InvalidMetadata {
+                    line: index + 1,
+                    content: line.to_string(),
+                });
+            };
+            let value = value.trim();
+            match key.trim() {
+                "name" => name = Some(value.to_string()),
+                "lighting" => {
+                    lighting = value
+                        .parse::<f32>()
+                        .ok()
+                        .filter(|lighting| (0. ..=1.).contains(lighting))
+                        .ok_or_else(|| MapFileError::InvalidLighting(value.to_string()))?;
+                }
+                "spawner" => {
+                    let invalid = || MapFileError::InvalidSpawner {
+                        line: index + 1,
+                        content: line.to_string(),
+                    };
+                    let [column, row, preset] = value
+                        .split_whitespace()
+                        .collect::<Vec<_>>()
+                        .try_into()
+                        .map_err(|_| invalid())?;
+                    let column = column.parse::<usize>().map_err(|_| invalid())?;
+                    let row = row.parse::<usize>().map_err(|_| invalid())?;
+                    spawner_presets.push((index + 1, column, row, preset.to_string()));
+                }
+                "spawn" => {
+                    let invalid = || MapFileError::InvalidSpawnPoint {
+                        line: index + 1,
+                        content: line.to_string(),
+                    };
+                    let [column, row] = value
+                        .split_whitespace()
+                        .collect::<Vec<_>>()
+                        .try_into()
+                        .map_err(|_| invalid())?;
+                    let column = column.parse::<usize>().map_err(|_| invalid())?;
+                    let row = row.parse::<usize>().map_err(|_| invalid())?;
+                    spawn_points.push((index + 1, column, row));
+                }
+                key => {
+                    return Err(MapFileError::UnknownKey {
+                        line: index + 1,
+                        key: key.to_string(),
+                    })
+                }
+            }
+        }
+        let name = name
+            .filter(|name| !name.is_empty())
+            .ok_or(MapFileError::MissingName)?;
+
+        let rows: Vec<&str> = lines
+            .map(|(_, line)| line.trim_end())
+            .filter(|line| !line.is_empty())
+            .collect();
+        let Some(expected) = rows.first().map(|row| row.chars().count()) else {
+            return Err(MapFileError::MissingGrid);
+        };
+
+        let mut map: Vec<Vec<char>> = Vec::with_capacity(rows.len());
+        let mut spawn_cells = 0;
+        for (row, line) in rows.iter().enumerate() {
+            let width = line.chars().count();
+            if width != expected {
+                return Err(MapFileError::RaggedRow {
+                    row,
+                    width,
+                    expected,
+                });
+            }
+            if let Some((column, cell)) = line
+                .chars()
+                .enumerate()
+                .find(|(_, cell)| !GRID_CELLS.contains(cell))
+            {
+                return Err(MapFileError::UnknownCell { row, column, cell });
+            }
+            spawn_cells += line.chars().filter(|cell| *cell == 'S').count();
+
+            // The loader reads empty cells as spaces
+            map.push(
+                line.chars()
+                    .map(|cell| if cell == '.' { ' ' } else { cell })
+                    .collect(),
+            );
+        }
+
+        if expected % MAP_SIZE_MULTIPLE != 0 || map.len() % MAP_SIZE_MULTIPLE != 0 {
+            return Err(MapFileError::InvalidSize {
+                width: expected,
+                height: map.len(),
+            });
+        }
+        if expected > MAX_MAP_SIZE || map.len() > MAX_MAP_SIZE {
+            return Err(MapFileError::TooLarge {
+                width: expected,
+                height: map.len(),
+            });
+        }
+        // Without spawn point, players spawn at the center of the map
+        if spawn_cells > 1 {
+            return Err(MapFileError::TooManySpawnPoints(spawn_cells));
+        }
+        for (line, column, row) in spawn_points.iter() {
+            if map.get(*row).and_then(|cells| cells.get(*column)) != Some(&'F') {
+                return Err(MapFileError::SpawnPointNotOnFloor {
+                    line: *line,
+                    column: *column,
+                    row: *row,
+                });
+            }
+        }
+        for (line, column, row, _) in spawner_presets.iter() {
+            if map.get(*row).and_then(|cells| cells.get(*column)) != Some(&'E') {
+                return Err(MapFileError::SpawnerNotOnEnemyCell {
+                    line: *line,
+                    column: *column,
+                    row: *row,
+                });
+            }
+        }
+
+        Ok(Self {
+            name,
+            lighting,
+            input: MapInput { map },
+            spawner_presets: spawner_presets
+                .into_iter()
+                .map(|(_, column, row, preset)| (UVec2::new(column as u32, row as u32), preset))
+                .collect(),
+            spawn_points: spawn_points
+                .into_iter()
+                .map(|(_, column, row)| UVec2::new(column as u32, row as u32))
+                .collect(),
+        })
+    }
+
+    pub fn load(path: &Path) -> Result<Self, MapFileError> {
+        let content =
+            std::fs::read_to_string(path).map_err(|err| MapFileError::Io(err.to_string()))?;
+        Self::parse(&content)
+    }
+
+    pub fn builtin(name: &str) -> Result<Self, MapFileError> {
+        let (_, content) = BUILTIN_MAPS
+            .iter()
+            .find(|(builtin_name, _)| *builtin_name == name)
+            .ok_or_else(|| MapFileError::UnknownMap(name.to_string()))?;
+        Self::parse(content)
+    }
+
+    /// Hash of the whole map, to check that it was transmitted unchanged
+    pub fn checksum(&self) -> u64 {
+        let mut hasher = StableHasher::new();
+        hasher.write_str(&self.name);
+        hasher.write_f32(self.lighting);
+        hasher.write_u64(self.input.map.len() as u64);
+        for row in &self.input.map {
+            hasher.write_u64(row.len() as u64);
+            for cell in row {
+                hasher.write_u32(*cell as u32);
+            }
+        }
+        hasher.finish()
+    }
+
+    /// Run-length encode the grid rows, `12.3W` for 12 empty cells then 3 walls
+    pub fn compress(&self) -> CompressedMapFile {
+        let rows = self
+            .input
+            .map
+            .iter()
+            .map(|row| {
+                let mut encoded = String::new();
+                let mut cells = row
+                    .iter()
+                    .map(|cell| if *cell == ' ' { '.' } else { *cell });
+                let mut current = cells.next();
+                while let Some(cell) = current {
+                    let mut count = 1;
+                    current = cells.next();
+                    while current == Some(cell) {
+                        count += 1;
+                        current = cells.next();
+                    }
+                    if count > 1 {
+                        encoded.push_str(&count.to_string());
+                    }
+                    encoded.push(cell);
+                }
+                encoded
+            })
+            .collect();
+
+        CompressedMapFile {
+            name: self.name.clone(),
+            lighting: self.lighting,
+            rows,
+            checksum: self.checksum(),
+        }
+    }
+
+    /// Builtin map, or dungeon generated from the seed in the name, see [`super::generator`]
+    pub fn from_name(name: &str) -> Result<Self, MapFileError> {
+        match parse_generated_map_name(name) {
+            Some(seed) => generate_dungeon(seed, &DungeonSettings::default()),
+            None => Self::builtin(name),
+        }
+    }
+}
+
+/// Map file as sent over the network, see [`MapFile::compress`]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct CompressedMapFile {
+    pub name: String,
+    pub lighting: f32,
+    pub rows: Vec<String>,
+    pub checksum: u64,
+}
+
+impl CompressedMapFile {
+    /// Decode and validate the map like a map file read from the disk
+    pub fn decompress(&self) -> Result<MapFile, MapFileError> {
+        let mut content = format!(
+            "name: {}\nlighting: {}\n{}\n",
+            self.name, self.lighting, GRID_SEPARATOR
+        );
+        for row in self.rows.iter() {
+            let mut count = 0;
+            for c in row.chars() {
+                match c.to_digit(10) {
+                    Some(digit) => count = count * 10 + digit as usize,
+                    None => {
+                        content.extend(std::iter::repeat_n(c, count.max(1)));
+                        count = 0;
+                    }
+                }
+            }
+            content.push('\n');
+        }
+
+        let map_file = MapFile::parse(&content)?;
+        if map_file.checksum() != self.checksum {
+            return Err(MapFileError::ChecksumMismatch);
+        }
+        Ok(map_file)
+    }
+}
+
+/// Map of the instance, sent by the server to each client on connect so that they build the same map.
+/// It is inserted as a resource by [`super::generate_map`]
+#[derive(Resource, Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum MapData {
+    /// Generated maps are rebuilt from their seed
+    Generated {
+        seed: u64,
+    },
+    File(CompressedMapFile),
+}
+
+impl MapData {
+    pub fn new(map_file: &MapFile) -> Self {
+        match parse_generated_map_name(&map_file.name) {
+            Some(seed) => Self::Generated { seed },
+            None => Self::File(map_file.compress()),
+        }
+    }
+
+    pub fn load(&self) -> Result<MapFile, MapFileError> {
+        match self {
+            Self::Generated { seed } => generate_dungeon(*seed, &DungeonSettings::default()),
+            Self::File(compressed) => compressed.decompress(),
+        }
+    }
+}
+
+pub fn builtin_map_names() -> impl Iterator<Item = &'static str> {
+    BUILTIN_MAPS.iter().map(|(name, _)| *name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Map of the given rows, with a name and the default lighting
+    fn map_content(rows: &[&str]) -> String {
+        format!("name: test\n{}\n{}\n", GRID_SEPARATOR, rows.join("\n"))
+    }
+
+    /// Square grid of walls of the given size, with the given cell at its center
+    fn grid(width: usize, height: usize, center: char) -> Vec<String> {
+        (0..height)
+            .map(|y| {
+                (0..width)
+                    .map(|x| {
+                        if (x, y) == (width / 2, height / 2) {
+                            center
+                        } else {
+                            'W'
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn parse_grid(rows: &[String]) -> Result<MapFile, MapFileError> {
+        let rows: Vec<&str> = rows.iter().map(String::as_str).collect();
+        MapFile::parse(&map_content(&rows))
+    }
+
+    #[test]
+    fn builtin_maps_parse() {
+        for name in builtin_map_names() {
+            let map = MapFile::builtin(name).unwrap();
+            assert_eq!(map.name, name);
+        }
+    }
+
+    #[test]
+    fn parse_reads_the_metadata_and_the_grid() {
+        let content = "# comment\nname: test\nlighting: 0.5\n---\n".to_string()
+            + &grid(10, 10, 'S').join("\n");
+        let map = MapFile::parse(&content).unwrap();
+        assert_eq!(map.name, "test");
+        assert_eq!(map.lighting, 0.5);
+        assert_eq!(map.input.map.len(), 10);
+        assert_eq!(map.input.map[5][5], 'S');
+    }
+
+    #[test]
+    fn parse_rejects_sizes_not_multiple_of_ten() {
+        assert_eq!(
+            parse_grid(&grid(12, 10, 'F')).err(),
+            Some(MapFileError::InvalidSize {
+                width: 12,
+                height: 10
+            })
+        );
+        assert_eq!(
+            parse_grid(&grid(10, 9, 'F')).err(),
+            Some(MapFileError::InvalidSize {
+                width: 10,
+                height: 9
+            })
+        );
+    }
+
+    #[test]
+    fn parse_rejects_maps_larger_than_the_quantization_bounds() {
+        assert!(parse_grid(&grid(MAX_MAP_SIZE, 10, 'F')).is_ok());
+        assert_eq!(
+            parse_grid(&grid(MAX_MAP_SIZE + 10, 10, 'F')).err(),
+            Some(MapFileError::TooLarge {
+                width: MAX_MAP_SIZE + 10,
+                height: 10
+            })
+        );
+    }
+
+    #[test]
+    fn parse_rejects_ragged_rows() {
+        let mut rows = grid(10, 10, 'F');
+        rows[3].push('W');
+        assert_eq!(
+            parse_grid(&rows).err(),
+            Some(MapFileError::RaggedRow {
+                row: 3,
+                width: 11,
+                expected: 10
+            })
+        );
+    }
+
+    #[test]
+    fn parse_rejects_unknown_cells() {
+        assert_eq!(
+            parse_grid(&grid(10, 10, 'X')).err(),
+            Some(MapFileError::UnknownCell {
+                row: 5,
+                column: 5,
+                cell: 'X'
+            })
+        );
+    }
+
+    #[test]
+    fn parse_checks_the_spawn_points() {
+        // Players spawn at the center of a map without spawn point
+        assert!(parse_grid(&grid(10, 10, 'F')).is_ok());
+
+        let mut rows = grid(10, 10, 'S');
+        rows[1].replace_range(1..2, "S");
+        assert_eq!(
+            parse_grid(&rows).err(),
+            Some(MapFileError::TooManySpawnPoints(2))
+        );
+    }
+
+    #[test]
+    fn parse_rejects_invalid_metadata() {
+        let rows = grid(10, 10, 'F').join("\n");
+        let parse = |metadata: &str| MapFile::parse(&format!("{metadata}\n---\n{rows}")).err();
+
+        assert_eq!(parse("lighting: 1"), Some(MapFileError::MissingName));
+        assert_eq!(parse("name:"), Some(MapFileError::MissingName));
+        assert_eq!(
+            parse("name: test\nlighting: 2"),
+            Some(MapFileError::InvalidLighting("2".to_string()))
+        );
+        assert_eq!(
+            parse("name: test\nsize: 10"),
+            Some(MapFileError::UnknownKey {
+                line: 2,
+                key: "size".to_string()
+            })
+        );
+        assert_eq!(
+            parse("name test"),
+            Some(MapFileError::InvalidMetadata {
+                line: 1,
+                content: "name test".to_string()
+            })
+        );
+        assert_eq!(
+            MapFile::parse("name: test\n---\n").err(),
+            Some(MapFileError::MissingGrid)
+        );
+    }
+
+    #[test]
+    fn parse_reads_the_spawner_presets() {
+        let rows = grid(10, 10, 'E').join("\n");
+        let parse =
+            |metadata: &str| MapFile::parse(&format!("name: test\n{metadata}\n---\n{rows}"));
+
+        let map = parse("spawner: 5 5 runners").unwrap();
+        assert_eq!(
+            map.spawner_presets.get(&UVec2::new(5, 5)),
+            Some(&"runners".to_string())
+        );
+        assert_eq!(
+            parse("spawner: 4 5 runners").err(),
+            Some(MapFileError::SpawnerNotOnEnemyCell {
+                line: 2,
+                column: 4,
+                row: 5
+            })
+        );
+        assert_eq!(
+            parse("spawner: 5 runners").err(),
+            Some(MapFileError::InvalidSpawner {
+                line: 2,
+                content: "spawner: 5 runners".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn parse_reads_the_spawn_points() {
+        let rows = grid(10, 10, 'F').join("\n");
+        let parse =
+            |metadata: &str| MapFile::parse(&format!("name: test\n{metadata}\n---\n{rows}"));
+
+        let map = parse("spawn: 5 5\nspawn: 5 5").unwrap();
+        assert_eq!(map.spawn_points, vec![UVec2::new(5, 5), UVec2::new(5, 5)]);
+        assert_eq!(
+            parse("spawn: 4 5").err(),
+            Some(MapFileError::SpawnPointNotOnFloor {
+                line: 2,
+                column: 4,
+                row: 5
+            })
+        );
+        assert_eq!(
+            parse("spawn: 5 5 5").err(),
+            Some(MapFileError::InvalidSpawnPoint {
+                line: 2,
+                content: "spawn: 5 5 5".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn compressed_map_round_trips() {
+        let map = MapFile::builtin(DEFAULT_MAP_NAME).unwrap();
+        let decompressed = map.compress().decompress().unwrap();
+        assert_eq!(decompressed.input.map, map.input.map);
+        assert_eq!(decompressed.checksum(), map.checksum());
+    }
+}
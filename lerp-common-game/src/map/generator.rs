@@ -0,0 +1,504 @@
+// Seeded dungeon generator, the server and the clients generate the same map from the same seed.
+// Rooms are connected by a spanning tree of 2 cells wide corridors, so every room can be reached from the spawn.
+// A layout failing that check is discarded and the next one is drawn from the same rng.
+use bevy::utils::HashMap;
+
+use super::file::{MapFile, MapFileError, MAP_SIZE_MULTIPLE, MAX_MAP_SIZE};
+use super::input::MapInput;
+
+/// Prefix of the generated map names, `dungeon-<seed>`
+pub const GENERATED_MAP_PREFIX: &str = "dungeon";
+
+const EMPTY: char = ' ';
+const FLOOR: char = 'F';
+const WALL: char = 'W';
+const DOOR: char = 'D';
+const PLAYER_SPAWN: char = 'S';
+const ENEMY_SPAWNER: char = 'E';
+
+/// Layouts drawn from a seed before giving up on it
+const MAX_LAYOUT_ATTEMPTS: usize = 8;
+
+#[derive(Clone, Debug)]
+pub struct DungeonSettings {
+    /// In cells, rounded up to a multiple of 10 and at most [`MAX_MAP_SIZE`]
+    pub width: usize,
+    pub height: usize,
+    /// Rooms tried to be placed, less can fit
+    pub room_count: usize,
+    /// Size of the room floors, walls excluded
+    pub min_room_size: usize,
+    pub max_room_size: usize,
+    /// Corridors added on top of the spanning tree, they make loops
+    pub extra_corridors: usize,
+    /// One enemy spawner per this many floor cells of a room
+    pub floor_cells_per_spawner: usize,
+    pub max_spawners_per_room: usize,
+    /// No spawner closer than this to the player spawn, in cells
+    pub min_spawner_distance: usize,
+    /// Between two spawners, in cells
+    pub min_spawner_spacing: usize,
+    pub lighting: f32,
+}
+
+impl Default for DungeonSettings {
+    fn default() -> Self {
+        Self {
+            width: 60,
+            height: 60,
+            room_count: 10,
+            min_room_size: 5,
+            max_room_size: 12,
+            extra_corridors: 2,
+            floor_cells_per_spawner: 30,
+            max_spawners_per_room: 3,
+            min_spawner_distance: 12,
+            min_spawner_spacing: 4,
+            lighting: 0.8,
+        }
+    }
+}
+
+/// SplitMix64, the map must not depend on the version of an external rng crate
+struct DungeonRng(u64);
+
+impl DungeonRng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// In `min..=max`
+    fn range(&mut self, min: usize, max: usize) -> usize {
+        min + (self.next_u64() % (max - min + 1) as u64) as usize
+    }
+}
+
+/// Floor rectangle of a room, bounds included
+#[derive(Clone, Copy, Debug)]
+struct Room {
+    x0: usize,
+    y0: usize,
+    x1: usize,
+    y1: usize,
+}
+
+impl Room {
+    fn center(&self) -> (usize, usize) {
+        ((self.x0 + self.x1) / 2, (self.y0 + self.y1) / 2)
+    }
+
+    /// Rooms keep their walls apart, so that their walls are never shared
+    fn overlaps(&self, other: &Room) -> bool {
+        self.x0 <= other.x1 + 3
+            && other.x0 <= self.x1 + 3
+            && self.y0 <= other.y1 + 3
+            && other.y0 <= self.y1 + 3
+    }
+}
+
+/// Pair of cells crossed by a corridor in the wall of a room
+#[derive(Clone, Copy, Debug)]
+struct DoorCandidate {
+    x: usize,
+    y: usize,
+    /// The pair is stacked vertically, in a left or right wall
+    vertical: bool,
+}
+
+fn is_walkable(cell: char) -> bool {
+    matches!(cell, FLOOR | DOOR | PLAYER_SPAWN | ENEMY_SPAWNER)
+}
+
+struct DungeonBuilder<'a> {
+    settings: &'a DungeonSettings,
+    rng: DungeonRng,
+    width: usize,
+    height: usize,
+    /// Indexed by `[y][x]`
+    grid: Vec<Vec<char>>,
+    rooms: Vec<Room>,
+    doors: Vec<DoorCandidate>,
+}
+
+impl DungeonBuilder<'_> {
+    fn place_rooms(&mut self) {
+        // Spawners are placed away from the room walls, rooms need at least 3 inner cells
+        let size_limit = self.width.min(self.height) - 6;
+        let min_size = self.settings.min_room_size.clamp(4, size_limit);
+        let max_size = self.settings.max_room_size.clamp(min_size, size_limit);
+
+        for _ in 0..self.settings.room_count * 20 {
+            if self.rooms.len() >= self.settings.room_count {
+                break;
+            }
+            let width = self.rng.range(min_size, max_size);
+            let height = self.rng.range(min_size, max_size);
+            // Keep the walls and one empty cell inside the map
+            let x0 = self.rng.range(2, self.width - width - 3);
+            let y0 = self.rng.range(2, self.height - height - 3);
+            let room = Room {
+                x0,
+                y0,
+                x1: x0 + width - 1,
+                y1: y0 + height - 1,
+            };
+            if self.rooms.iter().any(|other| other.overlaps(&room)) {
+                continue;
+            }
+
+            for y in room.y0..=room.y1 {
+                for x in room.x0..=room.x1 {
+                    self.grid[y][x] = FLOOR;
+                }
+            }
+            self.rooms.push(room);
+        }
+    }
+
+    /// Minimum spanning tree of the rooms, plus some random extra corridors
+    fn connect_rooms(&mut self) {
+        let distance = |a: &Room, b: &Room| {
+            let (ax, ay) = a.center();
+            let (bx, by) = b.center();
+            ax.abs_diff(bx) + ay.abs_diff(by)
+        };
+
+        if self.rooms.is_empty() {
+            return;
+        }
+        let mut connected = vec![false; self.rooms.len()];
+        connected[0] = true;
+        for _ in 1..self.rooms.len() {
+            let mut closest = None;
+            for (from, _) in connected.iter().enumerate().filter(|(_, c)| **c) {
+                for (to, _) in connected.iter().enumerate().filter(|(_, c)| !**c) {
+                    let dist = distance(&self.rooms[from], &self.rooms[to]);
+                    if closest.is_none_or(|(_, _, closest_dist)| dist < closest_dist) {
+                        closest = Some((from, to, dist));
+                    }
+                }
+            }
+            let (from, to, _) = closest.unwrap();
+            connected[to] = true;
+            self.carve_corridor(from, to);
+        }
+
+        if self.rooms.len() > 2 {
+            for _ in 0..self.settings.extra_corridors {
+                let from = self.rng.range(0, self.rooms.len() - 1);
+                let to = self.rng.range(0, self.rooms.len() - 1);
+                if from != to {
+                    self.carve_corridor(from, to);
+                }
+            }
+        }
+    }
+
+    /// L shaped corridor between the room centers, the rooms are at least 4 cells wide so it fits in them
+    fn carve_corridor(&mut self, from: usize, to: usize) {
+        let (fx, fy) = self.rooms[from].center();
+        let (tx, ty) = self.rooms[to].center();
+
+        if self.rng.next_u64() % 2 == 0 {
+            self.carve_horizontal(fx, tx, fy);
+            self.carve_vertical(fy, ty, tx);
+        } else {
+            self.carve_vertical(fy, ty, fx);
+            self.carve_horizontal(fx, tx, ty);
+        }
+    }
+
+    fn carve_horizontal(&mut self, from_x: usize, to_x: usize, y: usize) {
+        for x in from_x.min(to_x)..=from_x.max(to_x) + 1 {
+            for y in y..=y + 1 {
+                self.carve(x, y);
+            }
+            let crossed_wall = self
+                .rooms
+                .iter()
+                .any(|room| (x + 1 == room.x0 || x == room.x1 + 1) && y >= room.y0 && y < room.y1);
+            if crossed_wall {
+                self.doors.push(DoorCandidate {
+                    x,
+                    y,
+                    vertical: true,
+                });
+            }
+        }
+    }
+
+    fn carve_vertical(&mut self, from_y: usize, to_y: usize, x: usize) {
+        for y in from_y.min(to_y)..=from_y.max(to_y) + 1 {
+            for x in x..=x + 1 {
+                self.carve(x, y);
+            }
+            let crossed_wall = self
+                .rooms
+                .iter()
+                .any(|room| (y + 1 == room.y0 || y == room.y1 + 1) && x >= room.x0 && x < room.x1);
+            if crossed_wall {
+                self.doors.push(DoorCandidate {
+                    x,
+                    y,
+                    vertical: false,
+                });
+            }
+        }
+    }
+
+    fn carve(&mut self, x: usize, y: usize) {
+        if self.grid[y][x] == EMPTY {
+            self.grid[y][x] = FLOOR;
+        }
+    }
+
+    /// Surround every walkable cell with walls, diagonals included
+    fn build_walls(&mut self) {
+        for y in 1..self.height - 1 {
+            for x in 1..self.width - 1 {
+                if self.grid[y][x] != EMPTY {
+                    continue;
+                }
+                let next_to_floor = (y - 1..=y + 1)
+                    .any(|ny| (x - 1..=x + 1).any(|nx| is_walkable(self.grid[ny][nx])));
+                if next_to_floor {
+                    self.grid[y][x] = WALL;
+                }
+            }
+        }
+    }
+
+    /// Doors are only kept where the corridor goes straight through a wall, with walls on both sides
+    fn place_doors(&mut self) {
+        for door in std::mem::take(&mut self.doors) {
+            let (x, y) = (door.x, door.y);
+            let (cells, wall_ends, sides) = if door.vertical {
+                (
+                    [(x, y), (x, y + 1)],
+                    [(x, y - 1), (x, y + 2)],
+                    [(x - 1, y), (x - 1, y + 1), (x + 1, y), (x + 1, y + 1)],
+                )
+            } else {
+                (
+                    [(x, y), (x + 1, y)],
+                    [(x - 1, y), (x + 2, y)],
+                    [(x, y - 1), (x + 1, y - 1), (x, y + 1), (x + 1, y + 1)],
+                )
+            };
+
+            let is_valid = cells.iter().all(|(x, y)| self.grid[*y][*x] == FLOOR)
+                && wall_ends.iter().all(|(x, y)| self.grid[*y][*x] == WALL)
+                && sides.iter().all(|(x, y)| self.grid[*y][*x] == FLOOR);
+            if is_valid {
+                for (x, y) in cells {
+                    self.grid[y][x] = DOOR;
+                }
+            }
+        }
+    }
+
+    /// Player spawn in the first room, enemy spawners in the rooms far enough from it
+    fn place_spawns(&mut self) {
+        let (spawn_x, spawn_y) = self.rooms[0].center();
+        self.grid[spawn_y][spawn_x] = PLAYER_SPAWN;
+
+        let mut spawners: Vec<(usize, usize)> = Vec::new();
+        for room in self.rooms.clone().iter().skip(1) {
+            let floor_cells = (room.x1 - room.x0 + 1) * (room.y1 - room.y0 + 1);
+            let count = (floor_cells / self.settings.floor_cells_per_spawner.max(1))
+                .clamp(1, self.settings.max_spawners_per_room);
+
+            let mut placed = 0;
+            for _ in 0..count * 10 {
+                if placed == count {
+                    break;
+                }
+                // Away from the room walls, so that doors stay clear
+                let x = self.rng.range(room.x0 + 1, room.x1 - 1);
+                let y = self.rng.range(room.y0 + 1, room.y1 - 1);
+                let far_from_spawn = x.abs_diff(spawn_x).max(y.abs_diff(spawn_y))
+                    >= self.settings.min_spawner_distance;
+                let far_from_spawners = spawners.iter().all(|(sx, sy)| {
+                    x.abs_diff(*sx).max(y.abs_diff(*sy)) >= self.settings.min_spawner_spacing
+                });
+                if self.grid[y][x] == FLOOR && far_from_spawn && far_from_spawners {
+                    self.grid[y][x] = ENEMY_SPAWNER;
+                    spawners.push((x, y));
+                    placed += 1;
+                }
+            }
+        }
+    }
+
+    /// Draw a new layout, false when it has no room or a room cannot be walked to
+    fn build_layout(&mut self) -> bool {
+        self.grid = vec![vec![EMPTY; self.width]; self.height];
+        self.rooms.clear();
+        self.doors.clear();
+
+        self.place_rooms();
+        if self.rooms.is_empty() {
+            return false;
+        }
+        self.connect_rooms();
+        self.build_walls();
+        self.place_doors();
+        self.place_spawns();
+        self.is_connected()
+    }
+
+    /// Every room can be walked to from the player spawn
+    fn is_connected(&self) -> bool {
+        let (spawn_x, spawn_y) = self.rooms[0].center();
+        let mut visited = vec![vec![false; self.width]; self.height];
+        let mut stack = vec![(spawn_x, spawn_y)];
+        visited[spawn_y][spawn_x] = true;
+        while let Some((x, y)) = stack.pop() {
+            for (nx, ny) in [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)] {
+                if !visited[ny][nx] && is_walkable(self.grid[ny][nx]) {
+                    visited[ny][nx] = true;
+                    stack.push((nx, ny));
+                }
+            }
+        }
+        self.rooms.iter().all(|room| {
+            let (x, y) = room.center();
+            visited[y][x]
+        })
+    }
+}
+
+/// Generate a dungeon, the same seed and settings always give the same map
+pub fn generate_dungeon(seed: u64, settings: &DungeonSettings) -> Result<MapFile, MapFileError> {
+    let round_up = |size: usize| {
+        size.clamp(2 * MAP_SIZE_MULTIPLE, MAX_MAP_SIZE)
+            .div_ceil(MAP_SIZE_MULTIPLE)
+            * MAP_SIZE_MULTIPLE
+    };
+    let width = round_up(settings.width);
+    let height = round_up(settings.height);
+
+    let mut builder = DungeonBuilder {
+        settings,
+        rng: DungeonRng(seed),
+        width,
+        height,
+        grid: Vec::new(),
+        rooms: Vec::new(),
+        doors: Vec::new(),
+    };
+    if !(0..MAX_LAYOUT_ATTEMPTS).any(|_| builder.build_layout()) {
+        return Err(MapFileError::UnconnectedDungeon(seed));
+    }
+
+    Ok(MapFile {
+        name: generated_map_name(seed),
+        lighting: settings.lighting,
+        input: MapInput { map: builder.grid },
+        spawner_presets: HashMap::default(),
+        spawn_points: Vec::new(),
+    })
+}
+
+pub fn generated_map_name(seed: u64) -> String {
+    format!("{}-{}", GENERATED_MAP_PREFIX, seed)
+}
+
+/// Seed of a generated map name
+pub fn parse_generated_map_name(name: &str) -> Option<u64> {
+    name.strip_prefix(GENERATED_MAP_PREFIX)?
+        .strip_prefix('-')?
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Floor cells reached from the player spawn, and the walkable cells of the map
+    fn walkable_cells(map: &MapFile) -> (usize, usize) {
+        let grid = &map.input.map;
+        let walkable = |x: usize, y: usize| is_walkable(grid[y][x]);
+        let (spawn_y, spawn_x) = grid
+            .iter()
+            .enumerate()
+            .find_map(|(y, row)| {
+                row.iter()
+                    .position(|cell| *cell == PLAYER_SPAWN)
+                    .map(|x| (y, x))
+            })
+            .expect("No player spawn");
+
+        let mut visited = vec![vec![false; grid[0].len()]; grid.len()];
+        let mut stack = vec![(spawn_x, spawn_y)];
+        visited[spawn_y][spawn_x] = true;
+        let mut reached = 0;
+        while let Some((x, y)) = stack.pop() {
+            reached += 1;
+            for (nx, ny) in [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)] {
+                if !visited[ny][nx] && walkable(nx, ny) {
+                    visited[ny][nx] = true;
+                    stack.push((nx, ny));
+                }
+            }
+        }
+        let total = grid
+            .iter()
+            .flatten()
+            .filter(|cell| is_walkable(**cell))
+            .count();
+        (reached, total)
+    }
+
+    #[test]
+    fn dungeons_are_connected_and_deterministic() {
+        let settings = DungeonSettings::default();
+        for seed in 0..200 {
+            let map = generate_dungeon(seed, &settings).unwrap();
+            let (reached, total) = walkable_cells(&map);
+            assert_eq!(reached, total, "dungeon {} is not connected", seed);
+            assert_eq!(
+                map.input.map,
+                generate_dungeon(seed, &settings).unwrap().input.map,
+                "dungeon {} is not deterministic",
+                seed
+            );
+            assert_eq!(
+                MapFile::from_name(&map.name).unwrap().input.map,
+                map.input.map
+            );
+        }
+    }
+
+    #[test]
+    fn small_dungeons_are_connected() {
+        let settings = DungeonSettings {
+            width: 20,
+            height: 20,
+            room_count: 3,
+            ..Default::default()
+        };
+        for seed in 0..200 {
+            let map = generate_dungeon(seed, &settings).unwrap();
+            let (reached, total) = walkable_cells(&map);
+            assert_eq!(reached, total, "dungeon {} is not connected", seed);
+        }
+    }
+
+    #[test]
+    fn dungeon_without_rooms_is_an_error() {
+        let settings = DungeonSettings {
+            room_count: 0,
+            ..Default::default()
+        };
+        assert!(matches!(
+            generate_dungeon(7, &settings),
+            Err(MapFileError::UnconnectedDungeon(7))
+        ));
+    }
+}
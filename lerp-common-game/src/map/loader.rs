@@ -1,9 +1,5 @@
 use avian2d::prelude::{Collider, Position, RigidBody};
 use bevy::{prelude::*, utils::HashMap};
-use lightyear::prelude::{
-    server::{Replicate, ReplicationTarget, SyncTarget},
-    NetworkIdentity, NetworkTarget,
-};
 
 use crate::prelude::*;
 
@@ -19,24 +15,37 @@ struct WallKey {
     end_x: u32,
 }
 
-/// Reset the given Map and load the given InputMap in it
-pub fn load_map(
-    identity: NetworkIdentity,
-    commands: &mut Commands,
-    map_grid: &mut Map,
-    input: MapInput,
-) {
+/// Floor cells, terrains and obstacles included
+fn is_ground_cell(cell: char) -> bool {
+    matches!(cell, 'F' | '~' | 'L' | 'P') || ObstacleKind::from_cell(cell).is_some()
+}
+
+/// Reset the given Map and load the given MapFile in it
+pub fn load_map(commands: &mut Commands, map_grid: &mut Map, map_file: &MapFile) {
+    let input = &map_file.input;
+
     // Reset the map with the input size
     map_grid.reset(UVec2::new(
         input.map.first().unwrap().len() as u32,
         input.map.len() as u32,
     ));
+    map_grid.name = map_file.name.clone();
+    map_grid.lighting = map_file.lighting;
+
+    // The map file counts the rows from the top, the `S` cell is inserted first by the loop below
+    for spawn_point in map_file.spawn_points.iter() {
+        let cell = UVec2::new(spawn_point.x, input.map.len() as u32 - 1 - spawn_point.y);
+        map_grid
+            .player_spawn_positions
+            .push(cell.as_vec2() * RENDER_TILE_SIZE - map_grid.map_px_half_size);
+    }
 
     for x_render in 0..map_grid.render_map_size.x {
         for y_render in 0..map_grid.render_map_size.y {
             let Some(tile_char) = input.get(x_render, y_render) else {
                 continue;
             };
+            let terrain = TerrainKind::from_cell(*tile_char);
 
             // Insert and mark all nav tiles as walkable by default
             for x_nav in x_render * RENDER_TO_NAV_TILE_MULTI
@@ -47,7 +56,10 @@ pub fn load_map(
                 {
                     map_grid.nav_map.insert(
                         NavTileCoord(UVec2::new(x_nav, y_nav)),
-                        NavTile { walkable: true },
+                        NavTile {
+                            walkable: !terrain.blocks_walking(),
+                            terrain,
+                        },
                     );
                 }
             }
@@ -78,36 +90,41 @@ pub fn load_map(
                 .get_right(x_render, y_render)
                 .is_some_and(|t| *t == 'D');
 
-            let _is_top_floor = input.get_top(x_render, y_render).is_some_and(|t| *t == 'F');
+            let _is_top_floor = input
+                .get_top(x_render, y_render)
+                .is_some_and(|t| is_ground_cell(*t));
             let is_bottom_floor = input
                 .get_bottom(x_render, y_render)
-                .is_some_and(|t| *t == 'F');
+                .is_some_and(|t| is_ground_cell(*t));
             let _is_left_floor = input
                 .get_left(x_render, y_render)
-                .is_some_and(|t| *t == 'F');
+                .is_some_and(|t| is_ground_cell(*t));
             let is_right_floor = input
                 .get_right(x_render, y_render)
-                .is_some_and(|t| *t == 'F');
+                .is_some_and(|t| is_ground_cell(*t));
             let is_bottom_right_floor = input
                 .get_bottom_right(x_render, y_render)
-                .is_some_and(|t| *t == 'F');
+                .is_some_and(|t| is_ground_cell(*t));
 
             if *tile_char == 'S' {
-                map_grid.player_spawn_position = Vec2::new(
-                    x_render as f32 * RENDER_TILE_SIZE,
-                    y_render as f32 * RENDER_TILE_SIZE,
-                ) - map_grid.map_px_half_size;
+                map_grid.player_spawn_positions.insert(
+                    0,
+                    Vec2::new(
+                        x_render as f32 * RENDER_TILE_SIZE,
+                        y_render as f32 * RENDER_TILE_SIZE,
+                    ) - map_grid.map_px_half_size,
+                );
             }
 
             if *tile_char == 'S'
                 || *tile_char == 'E'
-                || *tile_char == 'F'
+                || is_ground_cell(*tile_char)
                 || *tile_char == 'D'
                 || (*tile_char == 'W' && (is_bottom_floor || is_right_floor))
                 || (*tile_char == 'W' && is_bottom_right_floor)
             {
                 map_grid.add_tile_floor(
-                    RenderTileFloorKind::Standard,
+                    RenderTileFloorKind::from_terrain(terrain),
                     UVec2::new(x_render, y_render),
                 );
             }
@@ -172,34 +189,54 @@ pub fn load_map(
                 }
             }
 
-            if identity.is_server() && *tile_char == 'E' {
-                for x in 0..5 {
-                    for y in 0..5 {
-                        let position = Vec2::new(
-                            x_render as f32 * RENDER_TILE_SIZE + x as f32 * ENEMY_SIZE,
-                            y_render as f32 * RENDER_TILE_SIZE + y as f32 * ENEMY_SIZE,
-                        ) - map_grid.map_px_half_size;
-
-                        let enemy = (EnemyBundle::new(&position),);
-                        let enemy_entity = commands.spawn(enemy).id();
-
-                        commands.entity(enemy_entity).insert((Replicate {
-                            sync: SyncTarget {
-                                prediction: NetworkTarget::All,
-                                interpolation: NetworkTarget::None,
-                            },
-                            target: ReplicationTarget {
-                                target: NetworkTarget::All,
-                            },
-                            group: REPLICATION_GROUP,
-                            ..default()
-                        },));
-                    }
-                }
+            if let Some(kind) = ObstacleKind::from_cell(*tile_char) {
+                // Centered in the cell
+                map_grid.obstacle_spawn_positions.push((
+                    kind,
+                    (Vec2::new(x_render as f32, y_render as f32) + 0.5) * RENDER_TILE_SIZE
+                        - map_grid.map_px_half_size,
+                ));
+            }
+
+            // Enemies are spawned by the server spawners placed on these cells
+            if *tile_char == 'E' {
+                map_grid.enemy_spawn_positions.push((
+                    Vec2::new(
+                        x_render as f32 * RENDER_TILE_SIZE,
+                        y_render as f32 * RENDER_TILE_SIZE,
+                    ) - map_grid.map_px_half_size,
+                    // The map file counts the rows from the top
+                    map_file
+                        .spawner_presets
+                        .get(&UVec2::new(x_render, input.map.len() as u32 - 1 - y_render))
+                        .cloned(),
+                ));
             }
         }
     }
 
+    // One set of colliders per layer: walls stop the projectiles, pits only the characters
+    spawn_merged_colliders(
+        commands,
+        map_grid,
+        |nav_tile| !nav_tile.walkable && !nav_tile.terrain.blocks_walking(),
+        (Wall, GameLayer::wall()),
+    );
+    spawn_merged_colliders(
+        commands,
+        map_grid,
+        |nav_tile| nav_tile.terrain.blocks_walking(),
+        (Pit, GameLayer::low_wall()),
+    );
+}
+
+/// Spawn static colliders covering the nav tiles matching `is_solid`, with the given marker and layers
+fn spawn_merged_colliders(
+    commands: &mut Commands,
+    map_grid: &Map,
+    is_solid: impl Fn(&NavTile) -> bool,
+    marker: impl Bundle + Clone,
+) {
     // Add coliders based on the nav grid state of the map.
     // Nav grid is updated automatically when adding tiles with add_tile_wall for example
     //
@@ -215,7 +252,7 @@ pub fn load_map(
         for x in 0..map_grid.nav_map_size.x {
             let is_wall = map_grid
                 .get_nav_tile(UVec2::new(x, y))
-                .map_or(false, |t| !t.walkable);
+                .map_or(false, &is_solid);
 
             if !is_wall {
                 continue;
@@ -228,7 +265,7 @@ pub fn load_map(
             let next_tile_x = x + 1;
             let next_tile_is_wall = map_grid
                 .get_nav_tile(UVec2::new(next_tile_x, y))
-                .map_or(true, |t| !t.walkable);
+                .map_or(true, &is_solid);
 
             // We found the end of a segment, we store it
             if !next_tile_is_wall {
@@ -291,7 +328,7 @@ pub fn load_map(
 
             commands.spawn((
                 CommonPlaySceneTag,
-                Wall,
+                marker.clone(),
                 position,
                 RigidBody::Static,
                 collider,
@@ -1,15 +1,18 @@
 use avian2d::prelude::Position;
 use bevy::{prelude::*, utils::HashMap};
+use serde::{Deserialize, Serialize};
 
 use crate::{
+    obstacle::ObstacleKind,
     shared::{NAV_TILE_SIZE, RENDER_TILE_SIZE, RENDER_TO_NAV_TILE_MULTI},
+    terrain::TerrainKind,
     utils::cartesian_to_isometric,
 };
 
 use super::tile_kind::{RenderTileFloorKind, RenderTileWallKind};
 
 /// Coordonates of a tile in the nav map
-#[derive(Clone, Copy, Deref, DerefMut, PartialEq, Eq, Hash)]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Deref, DerefMut, PartialEq, Eq, Hash)]
 pub struct NavTileCoord(pub UVec2);
 
 /// Coordonates of a tile in the render map
@@ -18,6 +21,7 @@ pub struct RenderTileCoord(pub UVec2);
 
 pub struct NavTile {
     pub walkable: bool,
+    pub terrain: TerrainKind,
 }
 
 pub struct RenderTileFloor {
@@ -79,6 +83,10 @@ impl RenderTileWall {
 
 #[derive(Resource, Default)]
 pub struct Map {
+    pub name: String,
+    /// Ambient light, from 0 (dark) to 1
+    pub lighting: f32,
+
     pub nav_map: HashMap<NavTileCoord, NavTile>,
     pub nav_map_size: UVec2,
 
@@ -91,7 +99,12 @@ pub struct Map {
 
     nav_tile_px_offset: Vec2,
 
-    pub player_spawn_position: Vec2,
+    /// The `S` cell first, then the `spawn` lines of the map file
+    pub player_spawn_positions: Vec<Vec2>,
+    /// Positions of the `E` cells and the spawner preset the map names for them
+    pub enemy_spawn_positions: Vec<(Vec2, Option<String>)>,
+    /// Destructible obstacles, spawned by the server
+    pub obstacle_spawn_positions: Vec<(ObstacleKind, Vec2)>,
 }
 impl Map {
     pub fn reset(&mut self, render_map_size: UVec2) {
@@ -108,6 +121,10 @@ impl Map {
             );
         }
         self.render_map_wall.clear();
+        self.player_spawn_positions.clear();
+        self.enemy_spawn_positions.clear();
+        self.obstacle_spawn_positions.clear();
+        self.render_map_floor.clear();
         self.render_map_size = render_map_size;
 
         self.nav_map.clear();
@@ -145,7 +162,10 @@ impl Map {
                     ((render_tile_pos.y * RENDER_TO_NAV_TILE_MULTI) as i32
                         + none_walkable_nav_tile.y) as u32,
                 )),
-                NavTile { walkable: false },
+                NavTile {
+                    walkable: false,
+                    terrain: TerrainKind::Ground,
+                },
             );
         }
 
@@ -157,6 +177,14 @@ impl Map {
         }
     }
 
+    /// Spawn points are used in turn, players spawn at the center of a map without any
+    pub fn player_spawn_position(&self, index: usize) -> Vec2 {
+        if self.player_spawn_positions.is_empty() {
+            return Vec2::ZERO;
+        }
+        self.player_spawn_positions[index % self.player_spawn_positions.len()]
+    }
+
     pub fn get_nav_tile(&self, uvec2: UVec2) -> Option<&NavTile> {
         self.nav_map.get(&NavTileCoord(uvec2))
     }
@@ -180,6 +208,11 @@ impl Map {
         ))
     }
 
+    /// Center of the nav tile
+    pub fn nav_map_tile_coord_to_position(&self, coord: NavTileCoord) -> Vec2 {
+        (coord.as_vec2() + 0.5) * NAV_TILE_SIZE - self.map_px_half_size
+    }
+
     pub fn position_to_render_map_tile_coord(&self, position: &Position) -> RenderTileCoord {
         // Add half of the map width/height to the position to get its absolute (positive) position
         let position_abs = position.0 + self.map_px_half_size;
@@ -196,6 +229,11 @@ impl Map {
             .get(&self.position_to_nav_map_tile_coord(position))
     }
 
+    pub fn get_terrain_from_position(&self, position: &Position) -> TerrainKind {
+        self.get_nav_tile_from_position(position)
+            .map_or(TerrainKind::Ground, |nav_tile| nav_tile.terrain)
+    }
+
     pub fn get_render_tiles_from_position(
         &self,
         position: &Position,
@@ -1,25 +1,74 @@
 use bevy::prelude::*;
-use input::*;
-use lightyear::prelude::NetworkIdentity;
+use file::*;
+use lightyear::prelude::client::ClientReceiveMessage;
 use loader::load_map;
 use map::Map;
 
+use crate::terrain::Pit;
+use crate::wall::Wall;
+
+pub mod file;
+pub mod generator;
 pub mod input;
 pub mod loader;
 #[allow(clippy::module_inception)]
 pub mod map;
 pub mod tile_kind;
 
-pub fn generate_map(identity: NetworkIdentity, mut commands: Commands, mut map_grid: ResMut<Map>) {
-    load_map(
-        identity,
-        &mut commands,
-        &mut map_grid,
-        create_large_map_input(),
-    );
+/// Name of the map loaded by [`generate_map`], the server picks it per instance.
+/// Generated maps are named after their seed, so the seed is all that is exchanged
+#[derive(Resource, Clone, Debug, PartialEq, Eq)]
+pub struct SelectedMap(pub String);
+
+impl Default for SelectedMap {
+    fn default() -> Self {
+        Self(DEFAULT_MAP_NAME.to_string())
+    }
+}
+
+pub fn generate_map(
+    mut commands: Commands,
+    mut map_grid: ResMut<Map>,
+    selected_map: Res<SelectedMap>,
+) {
+    let map_file = MapFile::from_name(&selected_map.0).unwrap_or_else(|err| {
+        error!(
+            "[generate_map] Cannot load map {}: {}, loading {} instead",
+            selected_map.0, err, DEFAULT_MAP_NAME
+        );
+        MapFile::builtin(DEFAULT_MAP_NAME).unwrap()
+    });
+    load_map(&mut commands, &mut map_grid, &map_file);
+    commands.insert_resource(MapData::new(&map_file));
+}
+
+/// Client side, build the map sent by the server on connect
+pub fn receive_map_data(
+    mut commands: Commands,
+    mut map_grid: ResMut<Map>,
+    mut map_data_ev: EventReader<ClientReceiveMessage<MapData>>,
+    map_collider_q: Query<Entity, Or<(With<Wall>, With<Pit>)>>,
+) {
+    for ev in map_data_ev.read() {
+        let map_file = match ev.message.load() {
+            Ok(map_file) => map_file,
+            Err(err) => {
+                error!("[receive_map_data] Invalid map data: {}", err);
+                continue;
+            }
+        };
+
+        for map_collider in map_collider_q.iter() {
+            commands.entity(map_collider).despawn();
+        }
+        load_map(&mut commands, &mut map_grid, &map_file);
+        info!("[receive_map_data] Loaded map {}", map_file.name);
+    }
 }
 
 pub mod prelude {
+    pub use crate::map::file::*;
+    pub use crate::map::generator::*;
     pub use crate::map::input::*;
     pub use crate::map::loader::*;
     pub use crate::map::map::*;
@@ -1,5 +1,7 @@
 use bevy::prelude::*;
 
+use crate::terrain::TerrainKind;
+
 // https://d2mods.info/forum/viewtopic.php?t=65163
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub enum RenderTileWallKind {
@@ -127,4 +129,17 @@ impl RenderTileWallKind {
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub enum RenderTileFloorKind {
     Standard,
+    ShallowWater,
+    Lava,
+    Pit,
+}
+impl RenderTileFloorKind {
+    pub fn from_terrain(terrain: TerrainKind) -> Self {
+        match terrain {
+            TerrainKind::Ground => Self::Standard,
+            TerrainKind::ShallowWater => Self::ShallowWater,
+            TerrainKind::Lava => Self::Lava,
+            TerrainKind::Pit => Self::Pit,
+        }
+    }
 }
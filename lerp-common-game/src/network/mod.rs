@@ -1,5 +1,7 @@
+pub mod quantize;
 mod tokio_task;
 
 pub mod prelude {
+    pub use crate::network::quantize::*;
     pub use crate::network::tokio_task::*;
 }
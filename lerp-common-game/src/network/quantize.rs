@@ -0,0 +1,103 @@
+// Fixed-point serialization of the physics components replicated at every replication interval.
+// A full precision Vec2 costs 8 bytes, the quantized versions cost 4.
+// The components are not delta-compressed against the last acked value: lightyear only delta-compresses the
+// components implementing its `Diffable` trait, which cannot be implemented for the avian components, and
+// the serialize functions have no state to diff against.
+use std::io::{Read, Write};
+
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use lightyear::protocol::serialize::SerializeFns;
+use lightyear::serialize::reader::Reader;
+use lightyear::serialize::writer::Writer;
+use lightyear::serialize::SerializationError;
+
+use crate::prelude::{MAX_MAP_SIZE, RENDER_TILE_SIZE};
+
+/// Half size in pixels of the area in which positions can be replicated, centered on the map origin.
+/// Maps are centered on the origin too, and cannot be larger than MAX_MAP_SIZE.
+pub const POSITION_BOUNDS_PX: f32 = MAX_MAP_SIZE as f32 * RENDER_TILE_SIZE / 2.;
+
+/// Size in pixels of one quantization step of a replicated position
+pub const POSITION_PRECISION_PX: f32 = 2. * POSITION_BOUNDS_PX / u16::MAX as f32;
+
+/// Size in pixels/s of one quantization step of a replicated velocity, for a max of 4096 px/s
+pub const VELOCITY_PRECISION_PX: f32 = 1. / 8.;
+
+/// Predicted positions are only rolled back when they drift further than the quantization error
+pub const POSITION_ROLLBACK_THRESHOLD_PX: f32 = POSITION_PRECISION_PX;
+
+pub fn quantize_position(value: f32) -> u16 {
+    let normalized = (value.clamp(-POSITION_BOUNDS_PX, POSITION_BOUNDS_PX) + POSITION_BOUNDS_PX)
+        / (2. * POSITION_BOUNDS_PX);
+    (normalized * u16::MAX as f32).round() as u16
+}
+
+pub fn dequantize_position(value: u16) -> f32 {
+    (value as f32 / u16::MAX as f32) * 2. * POSITION_BOUNDS_PX - POSITION_BOUNDS_PX
+}
+
+pub fn quantize_velocity(value: f32) -> i16 {
+    (value / VELOCITY_PRECISION_PX)
+        .round()
+        .clamp(i16::MIN as f32, i16::MAX as f32) as i16
+}
+
+pub fn dequantize_velocity(value: i16) -> f32 {
+    value as f32 * VELOCITY_PRECISION_PX
+}
+
+fn serialize_position(position: &Position, writer: &mut Writer) -> Result<(), SerializationError> {
+    writer.write_all(&quantize_position(position.x).to_le_bytes())?;
+    writer.write_all(&quantize_position(position.y).to_le_bytes())?;
+    Ok(())
+}
+
+fn deserialize_position(reader: &mut Reader) -> Result<Position, SerializationError> {
+    let mut bytes = [0; 2];
+    reader.read_exact(&mut bytes)?;
+    let x = dequantize_position(u16::from_le_bytes(bytes));
+    reader.read_exact(&mut bytes)?;
+    let y = dequantize_position(u16::from_le_bytes(bytes));
+    Ok(Position::from_xy(x, y))
+}
+
+fn serialize_linear_velocity(
+    linear_velocity: &LinearVelocity,
+    writer: &mut Writer,
+) -> Result<(), SerializationError> {
+    writer.write_all(&quantize_velocity(linear_velocity.x).to_le_bytes())?;
+    writer.write_all(&quantize_velocity(linear_velocity.y).to_le_bytes())?;
+    Ok(())
+}
+
+fn deserialize_linear_velocity(reader: &mut Reader) -> Result<LinearVelocity, SerializationError> {
+    let mut bytes = [0; 2];
+    reader.read_exact(&mut bytes)?;
+    let x = dequantize_velocity(i16::from_le_bytes(bytes));
+    reader.read_exact(&mut bytes)?;
+    let y = dequantize_velocity(i16::from_le_bytes(bytes));
+    Ok(LinearVelocity(Vec2::new(x, y)))
+}
+
+pub fn position_serialize_fns() -> SerializeFns<Position> {
+    SerializeFns {
+        serialize: serialize_position,
+        deserialize: deserialize_position,
+    }
+}
+
+pub fn linear_velocity_serialize_fns() -> SerializeFns<LinearVelocity> {
+    SerializeFns {
+        serialize: serialize_linear_velocity,
+        deserialize: deserialize_linear_velocity,
+    }
+}
+
+pub fn position_should_rollback(this: &Position, that: &Position) -> bool {
+    this.distance(that.0) > POSITION_ROLLBACK_THRESHOLD_PX
+}
+
+pub fn linear_velocity_should_rollback(this: &LinearVelocity, that: &LinearVelocity) -> bool {
+    this.distance(that.0) > VELOCITY_PRECISION_PX
+}
@@ -0,0 +1,84 @@
+// Destructible obstacles placed on the `C` and `B` map cells, spawned and replicated by the server.
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use lightyear::prelude::{client::Predicted, server::ReplicationTarget};
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ObstacleKind {
+    Crate,
+    Barrel,
+}
+
+impl ObstacleKind {
+    pub fn from_cell(cell: char) -> Option<Self> {
+        match cell {
+            'C' => Some(Self::Crate),
+            'B' => Some(Self::Barrel),
+            _ => None,
+        }
+    }
+
+    pub fn health(&self) -> f32 {
+        match self {
+            Self::Crate => 30.,
+            Self::Barrel => 15.,
+        }
+    }
+
+    /// Side of the square collider
+    pub fn size(&self) -> f32 {
+        match self {
+            Self::Crate => PIXEL_METER,
+            Self::Barrel => PIXEL_METER * 0.75,
+        }
+    }
+}
+
+#[derive(Component, Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Obstacle {
+    pub kind: ObstacleKind,
+}
+
+#[derive(Bundle)]
+pub struct ObstacleBundle {
+    obstacle: Obstacle,
+    position: Position,
+    health: Health,
+}
+
+impl ObstacleBundle {
+    pub fn new(kind: ObstacleKind, position: &Vec2) -> Self {
+        Self {
+            obstacle: Obstacle { kind },
+            position: Position(*position),
+            health: Health::new(kind.health()),
+        }
+    }
+}
+
+/// Obstacles block characters and projectiles, and are hit by both teams
+pub fn set_obstacle_local(
+    mut commands: Commands,
+    obstacle_q: Query<
+        (Entity, &Obstacle),
+        (
+            Without<Hittable>,
+            Or<(With<Predicted>, With<ReplicationTarget>)>,
+        ),
+    >,
+) {
+    for (entity, obstacle) in obstacle_q.iter() {
+        commands.entity(entity).insert((
+            Team::Neutral,
+            Hittable::default(),
+            PhysicsBundle {
+                rigid_body: RigidBody::Static,
+                collider: Collider::rectangle(obstacle.kind.size(), obstacle.kind.size()),
+                collision_layers: GameLayer::obstacle(),
+            },
+        ));
+    }
+}
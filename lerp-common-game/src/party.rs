@@ -0,0 +1,22 @@
+// Players grouped in a party in the lobby play in the same instance.
+// In game, party members share experience and loot, and always see each other.
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::prelude::*;
+
+pub const PARTY_MAX_MEMBERS: usize = 4;
+
+/// Enemy experience is shared by the players closer than this to the enemy when it dies
+pub const EXPERIENCE_RADIUS: f32 = 20. * PIXEL_METER;
+
+/// Experience earned by killing an enemy, split between the members of a party
+pub const ENEMY_EXPERIENCE: u32 = 10;
+
+/// Party of a player, set by the server when the player joins an instance started by its party
+#[derive(Component, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PartyMember(pub Uuid);
+
+#[derive(Component, Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Experience(pub u32);
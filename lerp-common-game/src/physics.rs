@@ -1,8 +1,79 @@
 use avian2d::prelude::*;
 use bevy::prelude::*;
 
+use crate::team::Team;
+
 #[derive(Bundle, Clone)]
 pub struct PhysicsBundle {
     pub rigid_body: RigidBody,
     pub collider: Collider,
+    pub collision_layers: CollisionLayers,
+}
+
+/// Two colliders only collide when each one is in the filters of the other
+#[derive(PhysicsLayer, Clone, Copy, Debug, Default)]
+pub enum GameLayer {
+    #[default]
+    Default,
+    /// Block the characters and the projectiles
+    Wall,
+    /// Block the characters only, the projectiles fly over them (pits)
+    LowWall,
+    /// Destructible obstacles, block and are hit by everything
+    Obstacle,
+    PlayerCharacter,
+    EnemyCharacter,
+    Projectile,
+}
+
+impl GameLayer {
+    pub fn wall() -> CollisionLayers {
+        CollisionLayers::new(GameLayer::Wall, LayerMask::ALL)
+    }
+
+    pub fn low_wall() -> CollisionLayers {
+        CollisionLayers::new(
+            GameLayer::LowWall,
+            [GameLayer::PlayerCharacter, GameLayer::EnemyCharacter],
+        )
+    }
+
+    pub fn obstacle() -> CollisionLayers {
+        CollisionLayers::new(GameLayer::Obstacle, LayerMask::ALL)
+    }
+
+    pub fn character(team: Team) -> CollisionLayers {
+        CollisionLayers::new(
+            Self::characters_of(team),
+            [
+                GameLayer::Wall,
+                GameLayer::LowWall,
+                GameLayer::Obstacle,
+                GameLayer::PlayerCharacter,
+                GameLayer::EnemyCharacter,
+                GameLayer::Projectile,
+            ],
+        )
+    }
+
+    /// Projectiles pass through the characters of their own team
+    pub fn projectile(team: Team) -> CollisionLayers {
+        let targets = match team {
+            Team::Player => Self::characters_of(Team::Enemy),
+            Team::Enemy => Self::characters_of(Team::Player),
+            Team::Neutral => Self::characters_of(Team::Neutral),
+        };
+        CollisionLayers::new(
+            GameLayer::Projectile,
+            targets | [GameLayer::Wall, GameLayer::Obstacle].into(),
+        )
+    }
+
+    fn characters_of(team: Team) -> LayerMask {
+        match team {
+            Team::Player => GameLayer::PlayerCharacter.into(),
+            Team::Enemy => GameLayer::EnemyCharacter.into(),
+            Team::Neutral => [GameLayer::PlayerCharacter, GameLayer::EnemyCharacter].into(),
+        }
+    }
 }
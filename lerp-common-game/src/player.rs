@@ -12,6 +12,7 @@ pub struct PlayerBundle {
     character: CharacterBundle,
     mana: Mana,
     skill_slot_map: SkillSlotMap,
+    experience: Experience,
 }
 
 impl PlayerBundle {
@@ -20,11 +21,17 @@ impl PlayerBundle {
         skill_slot_map.insert(PlayerActions::SkillSlot1, SkillName::BowAttack);
         skill_slot_map.insert(PlayerActions::SkillSlot2, SkillName::SplitArrow);
         skill_slot_map.insert(PlayerActions::SkillSlot3, SkillName::FlowerArrow);
+        skill_slot_map.insert(PlayerActions::SkillSlot4, SkillName::Dash);
+        skill_slot_map.insert(PlayerActions::SkillSlot5, SkillName::ChainArrow);
+        skill_slot_map.insert(PlayerActions::SkillSlot6, SkillName::Boomerang);
+        skill_slot_map.insert(PlayerActions::SkillSlot7, SkillName::RicochetArrow);
+        skill_slot_map.insert(PlayerActions::SkillSlot8, SkillName::HomingArrow);
 
         Self {
             character: CharacterBundle::new(CharacterId::Player, position),
             mana: Mana::new(PLAYER_BASE_MANA),
             skill_slot_map,
+            experience: Experience::default(),
         }
     }
 }
@@ -34,6 +41,7 @@ pub struct PlayerLocalBundle {
     marker: Player,
     pub skills_available: SkillsAvailable,
     skill_speed: SkillSpeed,
+    path: PlayerPath,
 }
 impl PlayerLocalBundle {
     pub fn init() -> Self {
@@ -43,6 +51,7 @@ impl PlayerLocalBundle {
             skill_speed: SkillSpeed {
                 value: Duration::from_millis(200),
             },
+            path: PlayerPath::default(),
         }
     }
 }
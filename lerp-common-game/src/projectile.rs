@@ -1,4 +1,5 @@
 use avian2d::prelude::*;
+use bevy::ecs::entity::MapEntities;
 use bevy::{prelude::*, utils::HashSet};
 use client::{Predicted, PredictionDespawnCommandsExt};
 use lightyear::prelude::server::*;
@@ -10,11 +11,92 @@ use crate::prelude::*;
 #[derive(Component, Default, Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct Projectile;
 
-#[derive(Component)]
+/// In pixels, targets further than this are ignored by the homing and chaining projectiles
+const PROJECTILE_SEEK_RADIUS: f32 = 6. * PIXEL_METER;
+/// Spread of the projectiles split on hit, in degrees
+const PROJECTILE_SPLIT_ANGLE: f32 = 30.;
+/// Changes the skill instance hash of a returning projectile, so that it hits the targets again
+const PROJECTILE_RETURN_HASH: u64 = 0x5245_5455_524e;
+
+/// Replicated once, the clients then simulate the projectile like the server
+#[derive(Component, Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct ProjectileData {
     pub skill_source: Entity,
     pub max_distance: f32,
     pub distance_traveled: f32,
+    /// Initiator of the skill, the boomerangs return to it
+    pub caster: Entity,
+    /// Hash of the PreSpawnedPlayerObject, the projectiles spawned on hit derive theirs from it
+    pub spawn_hash: u64,
+    /// Targets already hit, skipped by the homing and chaining
+    pub hit_targets: Vec<Entity>,
+    /// Boomerang on its way back
+    pub returning: bool,
+}
+
+impl MapEntities for ProjectileData {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.caster = entity_mapper.map_entity(self.caster);
+        for hit_target in self.hit_targets.iter_mut() {
+            *hit_target = entity_mapper.map_entity(*hit_target);
+        }
+    }
+}
+
+impl ProjectileData {
+    /// At its max distance or back to its caster, a boomerang turns back once.
+    /// Returns whether the projectile keeps flying
+    fn end_of_flight(
+        &mut self,
+        behaviors: &ProjectileBehaviors,
+        skill_instance_hash: &mut SkillInstanceHash,
+    ) -> bool {
+        if !behaviors.boomerang || self.returning {
+            return false;
+        }
+        // Hit the targets again on the way back
+        self.returning = true;
+        self.distance_traveled = 0.;
+        self.hit_targets.clear();
+        skill_instance_hash.0 = xor_u64s(&[skill_instance_hash.0, PROJECTILE_RETURN_HASH]);
+        true
+    }
+}
+
+/// Behaviors of the projectiles of a skill, they compose with each other and with [`Pierce`].
+/// On hit, the pierce applies first, then the split, then the chain
+#[derive(Component, Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct ProjectileBehaviors {
+    /// In radians per second, turn rate towards the nearest target
+    pub homing: Option<f32>,
+    /// Bounces left on the walls
+    pub ricochet: u32,
+    /// Hits left that jump to the nearest other target
+    pub chain: u32,
+    /// Number of projectiles it splits into on hit, 2 being a fork. The split projectiles do not split again
+    pub split: u32,
+    /// Flies back to the caster once at its max distance
+    pub boomerang: bool,
+}
+
+/// Everything a projectile is spawned with, by a skill or by a projectile splitting on hit
+pub struct ProjectileSpawn {
+    pub position: Vec2,
+    pub velocity: Vec2,
+    pub skill_source: Entity,
+    pub caster: Entity,
+    pub skill_instance_hash: u64,
+    /// Hash of the PreSpawnedPlayerObject, computed the same way on the clients and the server
+    pub spawn_hash: u64,
+    pub team: Team,
+    pub damage: Option<f32>,
+    pub knockback: Option<f32>,
+    pub pierce: u32,
+    pub behaviors: ProjectileBehaviors,
+    /// Targets the projectile does not seek, e.g. the one it split on
+    pub hit_targets: Vec<Entity>,
+    /// Projectiles of the players on the server, inherited by the split projectiles
+    pub lag_compensation: Option<LagCompensation>,
 }
 
 #[derive(Component, Default)]
@@ -30,6 +112,7 @@ pub struct ProjectileBundle {
     previous_position: PreviousPosition,
     linear_velocity: LinearVelocity,
     skill_instance_hash: SkillInstanceHash,
+    behaviors: ProjectileBehaviors,
 }
 impl Default for ProjectileBundle {
     fn default() -> Self {
@@ -39,46 +122,55 @@ impl Default for ProjectileBundle {
                 skill_source: Entity::PLACEHOLDER,
                 max_distance: 0.,
                 distance_traveled: 0.,
+                caster: Entity::PLACEHOLDER,
+                spawn_hash: 0,
+                hit_targets: vec![],
+                returning: false,
             },
             hit_source: HitSource::default(),
-            physics: Self::physics(),
+            physics: Self::physics(Team::default()),
             position: Position::default(),
             previous_position: PreviousPosition::default(),
             linear_velocity: LinearVelocity::default(),
             skill_instance_hash: SkillInstanceHash::default(),
+            behaviors: ProjectileBehaviors::default(),
         }
     }
 }
 impl ProjectileBundle {
-    pub fn new(
-        position: &Vec2,
-        linear_velocity: &Vec2,
-        skill_source: Entity,
-        skill_instance_hash: u64,
-        from_team: Team,
-    ) -> Self {
+    pub fn new(spawn: &ProjectileSpawn) -> Self {
         Self {
             data: ProjectileData {
-                skill_source,
+                skill_source: spawn.skill_source,
                 max_distance: 10. * PIXEL_METER,
                 distance_traveled: 0.,
+                caster: spawn.caster,
+                spawn_hash: spawn.spawn_hash,
+                hit_targets: spawn.hit_targets.clone(),
+                returning: false,
             },
-            physics: Self::physics(),
-            position: Position::from_xy(position.x, position.y),
-            previous_position: PreviousPosition(*position),
-            linear_velocity: LinearVelocity(*linear_velocity),
-            skill_instance_hash: SkillInstanceHash(skill_instance_hash),
-            hit_source: HitSource(from_team),
+            physics: Self::physics(spawn.team),
+            position: Position(spawn.position),
+            previous_position: PreviousPosition(spawn.position),
+            linear_velocity: LinearVelocity(spawn.velocity),
+            skill_instance_hash: SkillInstanceHash(spawn.skill_instance_hash),
+            hit_source: HitSource(spawn.team),
+            behaviors: spawn.behaviors,
             ..default()
         }
     }
-    pub fn from_protocol() -> Self {
-        Self { ..default() }
+    /// The sweep of the first tick starts from the replicated position
+    pub fn from_protocol(position: Vec2) -> Self {
+        Self {
+            previous_position: PreviousPosition(position),
+            ..default()
+        }
     }
-    pub fn physics() -> PhysicsBundle {
+    pub fn physics(from_team: Team) -> PhysicsBundle {
         PhysicsBundle {
             rigid_body: RigidBody::Kinematic,
             collider: Collider::circle(PROJECTILE_SIZE / 2.),
+            collision_layers: GameLayer::projectile(from_team),
         }
     }
 }
@@ -87,24 +179,36 @@ pub fn on_execute_skill_projectile_event(
     identity: NetworkIdentity,
     mut commands: Commands,
     mut excecute_skill_ev: EventReader<ExcecuteSkillEvent>,
-    skill_projectile_q: Query<(Entity, &SkillProjectile, Option<&SkillDamageOnHit>), With<Skill>>,
+    skill_projectile_q: Query<
+        (
+            Entity,
+            &SkillProjectile,
+            Option<&SkillDamageOnHit>,
+            Option<&SkillKnockback>,
+        ),
+        With<Skill>,
+    >,
     initiator_q: Query<(&Position, &Team, Option<&Player>), Without<Skill>>,
 ) {
     for event in excecute_skill_ev.read() {
         // Try to retrieve the skill data from the query.
         // If it does not exist, then this skill is not a projectile and will be ignored
-        let Ok((skill_entity, skill_projectile, skill_damage_on_hit)) =
+        let Ok((skill_entity, skill_projectile, skill_damage_on_hit, skill_knockback)) =
             skill_projectile_q.get(event.skill)
         else {
             continue;
         };
 
-        let Ok((initiator_position, initiator_team, _initiator_player)) =
+        let Ok((initiator_position, initiator_team, initiator_player)) =
             initiator_q.get(event.initiator)
         else {
             println!("[on_execute_skill_projectile_event] Cannot find initiator entity");
             continue;
         };
+        let lag_compensation = event
+            .target_tick
+            .filter(|_| identity.is_server() && initiator_player.is_some())
+            .map(|target_tick| LagCompensation::new(event.trigger_tick, target_tick));
 
         let directions = generate_fan_projectile_directions(
             initiator_position.0,
@@ -116,58 +220,93 @@ pub fn on_execute_skill_projectile_event(
         let mut projectile_nb = 0;
         for direction in directions {
             projectile_nb += 1;
-            let linear_velocity = direction * PROJECTILE_BASE_MOVEMENT_SPEED;
-
-            // Create base Projectile
-            let projectile_entity = commands
-                .spawn((
-                    ProjectileBundle::new(
-                        &initiator_position.0,
-                        &linear_velocity,
-                        skill_entity,
-                        event.skill_instance_hash,
-                        *initiator_team,
-                    ),
-                    PreSpawnedPlayerObject::new(xor_u64s(&[
-                        event.skill_instance_hash,
-                        projectile_nb,
-                    ])),
-                ))
-                .id();
-
-            // Add optional components based on skill data
-            if let Some(skill_damage_on_hit) = skill_damage_on_hit {
-                commands.entity(projectile_entity).insert((DamageOnHit {
-                    value: skill_damage_on_hit.value,
-                },));
-            }
+            spawn_projectile(
+                &mut commands,
+                &identity,
+                ProjectileSpawn {
+                    position: initiator_position.0,
+                    velocity: direction * PROJECTILE_BASE_MOVEMENT_SPEED,
+                    skill_source: skill_entity,
+                    caster: event.initiator,
+                    skill_instance_hash: event.skill_instance_hash,
+                    spawn_hash: xor_u64s(&[event.skill_instance_hash, projectile_nb]),
+                    team: *initiator_team,
+                    damage: skill_damage_on_hit.map(|damage| damage.value),
+                    knockback: skill_knockback.map(|knockback| knockback.distance),
+                    pierce: skill_projectile.pierce_count,
+                    behaviors: skill_projectile.behaviors,
+                    hit_targets: vec![],
+                    lag_compensation,
+                },
+            );
+        }
+    }
+}
 
-            if skill_projectile.pierce_count > 0 {
-                commands.entity(projectile_entity).insert((Pierce {
-                    count: skill_projectile.pierce_count,
-                },));
-            }
+pub fn spawn_projectile(
+    commands: &mut Commands,
+    identity: &NetworkIdentity,
+    spawn: ProjectileSpawn,
+) -> Entity {
+    // Create base Projectile
+    let projectile_entity = commands
+        .spawn((
+            ProjectileBundle::new(&spawn),
+            PreSpawnedPlayerObject::new(spawn.spawn_hash),
+        ))
+        .id();
 
-            // Setup replication if we are on the server
-            if identity.is_server() {
-                commands.entity(projectile_entity).insert((Replicate {
-                    sync: SyncTarget {
-                        prediction: NetworkTarget::All,
-                        interpolation: NetworkTarget::None,
-                    },
-                    target: ReplicationTarget {
-                        target: NetworkTarget::All,
-                    },
-                    controlled_by: ControlledBy {
-                        target: NetworkTarget::None,
-                        ..default()
-                    },
-                    group: REPLICATION_GROUP,
-                    ..default()
-                },));
-            }
-        }
+    // Add optional components based on skill data
+    if let Some(damage) = spawn.damage {
+        commands
+            .entity(projectile_entity)
+            .insert((DamageOnHit { value: damage },));
+    }
+
+    if let Some(knockback) = spawn.knockback {
+        commands.entity(projectile_entity).insert((KnockbackOnHit {
+            distance: knockback,
+        },));
     }
+
+    if spawn.pierce > 0 {
+        commands.entity(projectile_entity).insert((Pierce {
+            count: spawn.pierce,
+        },));
+    }
+
+    if let Some(lag_compensation) = spawn.lag_compensation {
+        commands.entity(projectile_entity).insert(lag_compensation);
+    }
+
+    // Setup replication if we are on the server
+    // Projectiles are simulated by the clients from their spawn state, behaviors included
+    if identity.is_server() {
+        commands.entity(projectile_entity).insert((
+            ReplicateOnce::<Position>::default(),
+            ReplicateOnce::<LinearVelocity>::default(),
+            ReplicateOnce::<ProjectileData>::default(),
+            ReplicateOnce::<ProjectileBehaviors>::default(),
+            ReplicateOnce::<SkillInstanceHash>::default(),
+            ReplicateOnce::<Pierce>::default(),
+        ));
+        commands.entity(projectile_entity).insert((Replicate {
+            sync: SyncTarget {
+                prediction: NetworkTarget::All,
+                interpolation: NetworkTarget::None,
+            },
+            target: ReplicationTarget {
+                target: NetworkTarget::All,
+            },
+            controlled_by: ControlledBy {
+                target: NetworkTarget::None,
+                ..default()
+            },
+            group: REPLICATION_GROUP,
+            ..default()
+        },));
+    }
+    projectile_entity
 }
 
 fn generate_fan_projectile_directions(
@@ -206,15 +345,102 @@ fn generate_fan_projectile_directions(
         .collect()
 }
 
+/// Projectiles built from the protocol get their replicated team after their physics,
+/// see [`ProjectileBundle::from_protocol`]
+pub fn update_projectile_collision_layers(
+    mut projectile_q: Query<
+        (&HitSource, &mut CollisionLayers),
+        (With<Projectile>, Changed<HitSource>),
+    >,
+) {
+    for (hit_source, mut collision_layers) in projectile_q.iter_mut() {
+        *collision_layers = GameLayer::projectile(hit_source.0);
+    }
+}
+
+/// Homing projectiles turn towards the nearest target, returning boomerangs fly straight to their caster
+pub fn steer_projectiles(
+    time: Res<Time>,
+    spatial_index: Res<SpatialIndex>,
+    caster_q: Query<&Position, Without<Projectile>>,
+    mut projectile_q: Query<
+        (
+            &Position,
+            &mut LinearVelocity,
+            &ProjectileData,
+            &ProjectileBehaviors,
+            &HitSource,
+        ),
+        (
+            With<Projectile>,
+            Or<(
+                With<Predicted>,
+                With<PreSpawnedPlayerObject>,
+                With<ReplicationTarget>,
+            )>,
+        ),
+    >,
+) {
+    for (position, mut linear_velocity, projectile_data, behaviors, hit_source) in
+        projectile_q.iter_mut()
+    {
+        let speed = linear_velocity.length();
+
+        if projectile_data.returning {
+            if let Ok(caster_position) = caster_q.get(projectile_data.caster) {
+                linear_velocity.0 = (caster_position.0 - position.0).normalize_or_zero() * speed;
+            }
+            continue;
+        }
+
+        let Some(turn_rate) = behaviors.homing else {
+            continue;
+        };
+        let Some(target) = nearest_target(
+            &spatial_index,
+            position.0,
+            hit_source.0,
+            &projectile_data.hit_targets,
+        ) else {
+            continue;
+        };
+        let max_angle = turn_rate * time.delta_secs();
+        let angle = linear_velocity
+            .angle_to(target.position - position.0)
+            .clamp(-max_angle, max_angle);
+        linear_velocity.0 = Vec2::from_angle(angle).rotate(linear_velocity.0);
+    }
+}
+
+/// Nearest character of another team than the projectile, in the order of the spatial index on ties
+fn nearest_target<'a>(
+    spatial_index: &'a SpatialIndex,
+    position: Vec2,
+    team: Team,
+    excluded: &[Entity],
+) -> Option<&'a SpatialEntry> {
+    spatial_index
+        .query(position, PROJECTILE_SEEK_RADIUS)
+        .filter(|entry| entry.team != team && !excluded.contains(&entry.entity))
+        .min_by(|a, b| {
+            a.position
+                .distance_squared(position)
+                .total_cmp(&b.position.distance_squared(position))
+        })
+}
+
 pub fn process_projectile_distance(
     identity: NetworkIdentity,
     mut commands: Commands,
+    caster_q: Query<&Position, Without<Projectile>>,
     mut query: Query<
         (
             Entity,
-            &mut PreviousPosition,
-            &mut Position,
+            &PreviousPosition,
+            &Position,
             &mut ProjectileData,
+            &ProjectileBehaviors,
+            &mut SkillInstanceHash,
         ),
         (
             With<Projectile>,
@@ -226,91 +452,502 @@ pub fn process_projectile_distance(
         ),
     >,
 ) {
-    for (entity, mut previous_position, current_position, mut projectile_data) in query.iter_mut() {
+    for (
+        entity,
+        previous_position,
+        current_position,
+        mut projectile_data,
+        behaviors,
+        mut skill_instance_hash,
+    ) in query.iter_mut()
+    {
         let distance_traveled = previous_position.0.distance(current_position.0);
         projectile_data.distance_traveled += distance_traveled;
 
-        if projectile_data.distance_traveled >= projectile_data.max_distance {
+        // Back to its caster, or lost it
+        let returned = projectile_data.returning
+            && caster_q
+                .get(projectile_data.caster)
+                .is_none_or(|caster_position| {
+                    caster_position.0.distance(current_position.0) <= PROJECTILE_SIZE
+                });
+
+        if (returned || projectile_data.distance_traveled >= projectile_data.max_distance)
+            && !projectile_data.end_of_flight(behaviors, &mut skill_instance_hash)
+        {
             if identity.is_server() {
                 commands.entity(entity).despawn();
             } else {
                 commands.entity(entity).prediction_despawn();
             }
+        }
+    }
+}
+
+/// Targets crossed by a projectile during a tick, up to the first wall
+pub struct ProjectileSweep {
+    /// In order of distance
+    pub targets: Vec<Entity>,
+    /// Distance to the wall along the movement, and its normal facing the projectile
+    pub wall: Option<(f32, Vec2)>,
+}
+
+/// Cast the projectile shape from its previous position to its current one, so that the walls and the targets
+/// it moved past within a tick are still hit.
+/// With a rewound spatial index the characters are tested where they were in it instead of where they are
+pub fn sweep_projectile(
+    spatial_query: &SpatialQuery,
+    from: Vec2,
+    to: Vec2,
+    team: Team,
+    rewound: Option<&SpatialIndex>,
+    is_hittable: impl Fn(Entity) -> bool,
+) -> ProjectileSweep {
+    let mut sweep = ProjectileSweep {
+        targets: vec![],
+        wall: None,
+    };
+    let Ok((direction, distance)) = Dir2::new_and_length(to - from) else {
+        return sweep;
+    };
+    let shape = Collider::circle(PROJECTILE_SIZE / 2.);
+    let config = ShapeCastConfig {
+        max_distance: distance,
+        // Leaving a wall it bounced on, or a target it already hit, is not a hit
+        ignore_origin_penetration: true,
+        ..default()
+    };
+
+    // The nearest wall first, the targets behind it are not hit
+    if let Some(hit) = spatial_query.cast_shape(
+        &shape,
+        from,
+        0.,
+        direction,
+        &config,
+        &SpatialQueryFilter::from_mask(GameLayer::Wall),
+    ) {
+        let normal = if hit.normal1.dot(*direction) > 0. {
+            -hit.normal1
         } else {
-            previous_position.0 = current_position.0;
+            hit.normal1
+        };
+        sweep.wall = Some((hit.distance, normal));
+    }
+    let max_distance = sweep
+        .wall
+        .map_or(distance, |(wall_distance, _)| wall_distance);
+
+    let mut layers = GameLayer::projectile(team).filters;
+    layers.remove(GameLayer::Wall);
+    if rewound.is_some() {
+        layers.remove([GameLayer::PlayerCharacter, GameLayer::EnemyCharacter]);
+    }
+    // Every target on the way, however many there are
+    let mut hits: Vec<(f32, Entity)> = vec![];
+    spatial_query.shape_hits_callback(
+        &shape,
+        from,
+        0.,
+        direction,
+        &ShapeCastConfig {
+            max_distance,
+            ..config
+        },
+        &SpatialQueryFilter::from_mask(layers),
+        |hit| {
+            if is_hittable(hit.entity) {
+                hits.push((hit.distance, hit.entity));
+            }
+            true
+        },
+    );
+
+    if let Some(rewound) = rewound {
+        let max_radius = PLAYER_SIZE.max(ENEMY_SIZE) / 2. + PROJECTILE_SIZE / 2.;
+        for entry in rewound.query(
+            from + *direction * max_distance / 2.,
+            max_distance / 2. + max_radius,
+        ) {
+            if entry.team == team || !is_hittable(entry.entity) {
+                continue;
+            }
+            if let Some(hit_distance) = circle_cast(
+                from,
+                *direction,
+                max_distance,
+                entry.position,
+                entry.radius + PROJECTILE_SIZE / 2.,
+            ) {
+                hits.push((hit_distance, entry.entity));
+            }
         }
     }
+
+    // Stable, the ties keep the order of the queries
+    hits.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+    sweep.targets = hits.into_iter().map(|(_, entity)| entity).collect();
+    sweep
+}
+
+/// Distance along the direction at which a point touches the circle, ignoring the circle it starts in
+/// when moving out of it, like the shape casts
+fn circle_cast(
+    from: Vec2,
+    direction: Vec2,
+    max_distance: f32,
+    center: Vec2,
+    radius: f32,
+) -> Option<f32> {
+    let offset = center - from;
+    let along = offset.dot(direction);
+    if along < 0. {
+        return None;
+    }
+    let distance_sq = offset.length_squared();
+    if distance_sq <= radius * radius {
+        return Some(0.);
+    }
+    let side_sq = distance_sq - along * along;
+    if side_sq > radius * radius {
+        return None;
+    }
+    let distance = along - (radius * radius - side_sq).sqrt();
+    (distance <= max_distance).then_some(distance)
 }
 
 pub fn process_projectile_collisions(
-    // mut collision_event_reader: EventReader<Collision>,
-    collisions: Res<Collisions>,
+    spatial_query: SpatialQuery,
+    spatial_index_history: Option<Res<SpatialIndexHistory>>,
     mut hit_events: EventWriter<HitEvent>,
     // TODO: Query hittable entities
-    hittable_q: Query<&Hittable, With<Alive>>,
-    projectile_q: Query<(&Projectile, &ProjectileData)>,
-    wall_q: Query<&Wall>,
+    hittable_q: Query<&Hittable, Or<(With<Alive>, With<Obstacle>)>>,
+    mut projectile_q: Query<
+        (
+            Entity,
+            &ProjectileData,
+            &HitSource,
+            &mut Position,
+            &mut PreviousPosition,
+            &mut LinearVelocity,
+            &mut ProjectileBehaviors,
+            Option<&LagCompensation>,
+        ),
+        (
+            With<Projectile>,
+            Or<(
+                With<Predicted>,
+                With<PreSpawnedPlayerObject>,
+                With<ReplicationTarget>,
+            )>,
+        ),
+    >,
     mut commands: Commands,
     identity: NetworkIdentity,
 ) {
-    let mut despawned_entities = HashSet::new();
     let mut event_data = Vec::new();
 
-    // when A and B collide, it can be reported as one of:
-    // * A collides with B
-    // * B collides with A
-    // which is why logic is duplicated twice here
-    for contacts in collisions.iter() {
-        if !contacts.collision_started() {
-            continue;
-        }
+    for (
+        entity,
+        projectile_data,
+        hit_source,
+        mut position,
+        mut previous_position,
+        mut linear_velocity,
+        mut behaviors,
+        lag_compensation,
+    ) in projectile_q.iter_mut()
+    {
+        let rewound = spatial_index_history
+            .as_ref()
+            .zip(lag_compensation)
+            .and_then(|(history, lag_compensation)| history.rewind(lag_compensation.rewind_ticks));
+        let sweep = sweep_projectile(
+            &spatial_query,
+            previous_position.0,
+            position.0,
+            hit_source.0,
+            rewound,
+            |entity| hittable_q.contains(entity),
+        );
 
-        if let Ok((_, projectile_data)) = projectile_q.get(contacts.entity1) {
-            let collide_with_wall = wall_q.get(contacts.entity2).is_ok();
-            let collide_with_hittable = hittable_q.get(contacts.entity2).is_ok();
-
-            // despawn the projectile if it hit a wall
-            if collide_with_wall && despawned_entities.insert(contacts.entity1) {
-                if identity.is_server() {
-                    commands.entity(contacts.entity1).despawn();
-                } else {
-                    commands.entity(contacts.entity1).prediction_despawn();
-                }
-            }
+        event_data.extend(sweep.targets.into_iter().map(|target| HitEventData {
+            source: entity,
+            skill: projectile_data.skill_source,
+            target,
+        }));
 
-            if collide_with_hittable {
-                event_data.push(HitEventData {
-                    source: contacts.entity1,
-                    skill: projectile_data.skill_source,
-                    target: contacts.entity2,
-                })
+        if let Some((distance, normal)) = sweep.wall {
+            // bounce off the wall from where it touched it, or despawn the projectile
+            if ricochet(&mut linear_velocity, &mut behaviors, Some(normal)) {
+                position.0 = previous_position.0
+                    + (position.0 - previous_position.0).normalize_or_zero() * distance;
+            } else if identity.is_server() {
+                commands.entity(entity).despawn();
+            } else {
+                commands.entity(entity).prediction_despawn();
             }
         }
 
-        if let Ok((_, projectile_data)) = projectile_q.get(contacts.entity2) {
-            let collide_with_wall = wall_q.get(contacts.entity1).is_ok();
-            let collide_with_hittable = hittable_q.get(contacts.entity1).is_ok();
-
-            // despawn the projectile if it hit a wall
-            if collide_with_wall && despawned_entities.insert(contacts.entity2) {
-                if identity.is_server() {
-                    commands.entity(contacts.entity2).despawn();
-                } else {
-                    commands.entity(contacts.entity2).prediction_despawn();
-                }
-            }
-
-            if collide_with_hittable {
-                event_data.push(HitEventData {
-                    source: contacts.entity2,
-                    skill: projectile_data.skill_source,
-                    target: contacts.entity1,
-                })
-            }
-        }
+        previous_position.0 = position.0;
     }
 
     if !event_data.is_empty() {
         hit_events.send(HitEvent(event_data));
     }
 }
+
+/// Reflect the velocity on the wall normal if the projectile has bounces left
+fn ricochet(
+    linear_velocity: &mut LinearVelocity,
+    behaviors: &mut ProjectileBehaviors,
+    normal: Option<Vec2>,
+) -> bool {
+    let Some(normal) = normal.filter(|_| behaviors.ricochet > 0) else {
+        return false;
+    };
+    behaviors.ricochet -= 1;
+    linear_velocity.0 -= 2. * linear_velocity.dot(normal) * normal;
+    true
+}
+
+/// Split and chain of a projectile that hit a target, once its pierce is used up.
+/// Returns whether the projectile keeps flying
+#[allow(clippy::too_many_arguments)]
+pub fn projectile_on_hit(
+    commands: &mut Commands,
+    identity: &NetworkIdentity,
+    spatial_index: &SpatialIndex,
+    team: Team,
+    skill_instance_hash: u64,
+    position: Vec2,
+    linear_velocity: &mut LinearVelocity,
+    projectile_data: &mut ProjectileData,
+    behaviors: &mut ProjectileBehaviors,
+    damage_on_hit: Option<&DamageOnHit>,
+    knockback_on_hit: Option<&KnockbackOnHit>,
+    lag_compensation: Option<&LagCompensation>,
+) -> bool {
+    let speed = linear_velocity.length();
+
+    if behaviors.split > 0 {
+        let directions = generate_fan_projectile_directions(
+            position,
+            position + linear_velocity.0,
+            behaviors.split,
+            PROJECTILE_SPLIT_ANGLE,
+        );
+        for (index, direction) in directions.into_iter().enumerate() {
+            spawn_projectile(
+                commands,
+                identity,
+                ProjectileSpawn {
+                    position,
+                    velocity: direction * speed,
+                    skill_source: projectile_data.skill_source,
+                    caster: projectile_data.caster,
+                    skill_instance_hash,
+                    spawn_hash: split_spawn_hash(
+                        projectile_data.spawn_hash,
+                        projectile_data.hit_targets.len(),
+                        index,
+                    ),
+                    team,
+                    damage: damage_on_hit.map(|damage| damage.value),
+                    knockback: knockback_on_hit.map(|knockback| knockback.distance),
+                    pierce: 0,
+                    behaviors: ProjectileBehaviors {
+                        split: 0,
+                        ..*behaviors
+                    },
+                    hit_targets: projectile_data.hit_targets.clone(),
+                    lag_compensation: lag_compensation.copied(),
+                },
+            );
+        }
+        return false;
+    }
+
+    chain(
+        spatial_index,
+        team,
+        position,
+        linear_velocity,
+        projectile_data,
+        behaviors,
+    )
+}
+
+/// Redirect the projectile to the nearest target it did not hit yet, if it has chains left.
+/// Returns whether it found one
+fn chain(
+    spatial_index: &SpatialIndex,
+    team: Team,
+    position: Vec2,
+    linear_velocity: &mut LinearVelocity,
+    projectile_data: &mut ProjectileData,
+    behaviors: &mut ProjectileBehaviors,
+) -> bool {
+    if behaviors.chain == 0 {
+        return false;
+    }
+    let Some(next_target) =
+        nearest_target(spatial_index, position, team, &projectile_data.hit_targets)
+    else {
+        return false;
+    };
+    behaviors.chain -= 1;
+    linear_velocity.0 =
+        (next_target.position - position).normalize_or_zero() * linear_velocity.length();
+    projectile_data.distance_traveled = 0.;
+    true
+}
+
+/// Hash of the PreSpawnedPlayerObject of a split projectile, the xor of [`xor_u64s`] would collide between the hits and the indexes
+fn split_spawn_hash(parent_spawn_hash: u64, hit_count: usize, index: usize) -> u64 {
+    let mut hasher = StableHasher::new();
+    hasher.write_u64(parent_spawn_hash);
+    hasher.write_u64(hit_count as u64);
+    hasher.write_u64(index as u64);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn projectile_data() -> ProjectileData {
+        ProjectileData {
+            skill_source: Entity::PLACEHOLDER,
+            max_distance: 10. * PIXEL_METER,
+            distance_traveled: 4. * PIXEL_METER,
+            caster: Entity::PLACEHOLDER,
+            spawn_hash: 42,
+            hit_targets: vec![],
+            returning: false,
+        }
+    }
+
+    fn enemy(index: u32, position: Vec2) -> SpatialEntry {
+        SpatialEntry {
+            entity: Entity::from_raw(index),
+            position,
+            velocity: Vec2::ZERO,
+            radius: ENEMY_SIZE / 2.,
+            team: Team::Enemy,
+        }
+    }
+
+    #[test]
+    fn ricochet_reflects_on_the_wall_normal_until_out_of_bounces() {
+        let mut linear_velocity = LinearVelocity(Vec2::new(3., -4.));
+        let mut behaviors = ProjectileBehaviors {
+            ricochet: 1,
+            ..default()
+        };
+
+        assert!(ricochet(
+            &mut linear_velocity,
+            &mut behaviors,
+            Some(Vec2::Y)
+        ));
+        assert_eq!(linear_velocity.0, Vec2::new(3., 4.));
+        assert_eq!(behaviors.ricochet, 0);
+
+        assert!(!ricochet(
+            &mut linear_velocity,
+            &mut behaviors,
+            Some(Vec2::Y)
+        ));
+        assert_eq!(linear_velocity.0, Vec2::new(3., 4.));
+    }
+
+    #[test]
+    fn chain_retargets_the_nearest_target_not_hit_yet() {
+        let hit = enemy(1, Vec2::new(PIXEL_METER, 0.));
+        let next = enemy(2, Vec2::new(0., 3. * PIXEL_METER));
+        let mut spatial_index = SpatialIndex::default();
+        spatial_index.rebuild([hit, next].into_iter());
+
+        let mut linear_velocity = LinearVelocity(Vec2::new(PROJECTILE_BASE_MOVEMENT_SPEED, 0.));
+        let mut projectile_data = ProjectileData {
+            hit_targets: vec![hit.entity],
+            ..projectile_data()
+        };
+        let mut behaviors = ProjectileBehaviors {
+            chain: 1,
+            ..default()
+        };
+
+        assert!(chain(
+            &spatial_index,
+            Team::Player,
+            Vec2::ZERO,
+            &mut linear_velocity,
+            &mut projectile_data,
+            &mut behaviors,
+        ));
+        assert!(linear_velocity
+            .0
+            .abs_diff_eq(Vec2::new(0., PROJECTILE_BASE_MOVEMENT_SPEED), 1e-3));
+        assert_eq!(behaviors.chain, 0);
+        assert_eq!(projectile_data.distance_traveled, 0.);
+
+        // Every target in range was hit
+        projectile_data.hit_targets.push(next.entity);
+        behaviors.chain = 1;
+        assert!(!chain(
+            &spatial_index,
+            Team::Player,
+            Vec2::ZERO,
+            &mut linear_velocity,
+            &mut projectile_data,
+            &mut behaviors,
+        ));
+        assert_eq!(behaviors.chain, 1);
+    }
+
+    #[test]
+    fn split_spawn_hashes_are_distinct_and_stable() {
+        let first = split_spawn_hash(42, 1, 0);
+        let second = split_spawn_hash(42, 1, 1);
+        assert_ne!(first, second);
+        assert_ne!(first, split_spawn_hash(42, 2, 0));
+        assert_ne!(first, split_spawn_hash(43, 1, 0));
+        // Compared between the clients and the server, must not depend on the build
+        assert_eq!(first, 0xe06b_9eec_a60c_e66e);
+        assert_eq!(second, 0xff66_65f5_b0fc_308f);
+    }
+
+    #[test]
+    fn boomerang_turns_back_once_with_a_new_skill_instance_hash() {
+        let behaviors = ProjectileBehaviors {
+            boomerang: true,
+            ..default()
+        };
+        let mut projectile_data = ProjectileData {
+            hit_targets: vec![Entity::from_raw(1)],
+            ..projectile_data()
+        };
+        let mut skill_instance_hash = SkillInstanceHash(7);
+
+        assert!(projectile_data.end_of_flight(&behaviors, &mut skill_instance_hash));
+        assert!(projectile_data.returning);
+        assert_eq!(projectile_data.distance_traveled, 0.);
+        assert!(projectile_data.hit_targets.is_empty());
+        assert_eq!(skill_instance_hash.0, 7 ^ PROJECTILE_RETURN_HASH);
+
+        // Back to its caster
+        assert!(!projectile_data.end_of_flight(&behaviors, &mut skill_instance_hash));
+        assert_eq!(skill_instance_hash.0, 7 ^ PROJECTILE_RETURN_HASH);
+    }
+
+    #[test]
+    fn projectiles_without_boomerang_end_at_max_distance() {
+        let mut projectile_data = projectile_data();
+        let mut skill_instance_hash = SkillInstanceHash(7);
+        assert!(!projectile_data.end_of_flight(&default(), &mut skill_instance_hash));
+        assert!(!projectile_data.returning);
+        assert_eq!(skill_instance_hash.0, 7);
+    }
+}
@@ -4,6 +4,7 @@ use avian2d::prelude::*;
 use bevy::prelude::*;
 
 use lightyear::client::components::ComponentSyncMode;
+use lightyear::prelude::client::ConnectionManager;
 use lightyear::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -17,10 +18,35 @@ pub const LOOT_REPLICATION_GROUP: ReplicationGroup = ReplicationGroup::new_id(2)
 #[derive(Channel)]
 pub struct Channel1;
 
+#[derive(Channel)]
+pub struct ChatChannel;
+
+/// Carries the [`MapData`] sent on connect, apart from the gameplay messages
+#[derive(Channel)]
+pub struct MapChannel;
+
 // Messages
 
+/// How a client takes part in the game, chosen before connecting
+#[derive(Resource, Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum JoinMode {
+    #[default]
+    Player,
+    /// Receives the interpolated world but owns no player
+    Spectator,
+}
+
+/// Name the client logged in with in the lobby
+#[derive(Resource, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Username(pub String);
+
+/// Sent by the client once connected, the server does not replicate the world before receiving it
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
-pub struct SpawnEnemies;
+pub struct JoinGame {
+    pub mode: JoinMode,
+    /// Used by the server to find the party of the player
+    pub username: String,
+}
 
 // Components
 
@@ -45,44 +71,111 @@ impl Plugin for ProtocolPlugin {
         // Inputs
         app.add_plugins(LeafwingInputPlugin::<PlayerActions>::default());
         // Messages
-        app.register_message::<SpawnEnemies>(ChannelDirection::ClientToServer);
+        app.register_message::<JoinGame>(ChannelDirection::ClientToServer);
+        app.register_message::<SendChat>(ChannelDirection::ClientToServer);
+        app.register_message::<ChatMessage>(ChannelDirection::ServerToClient);
+        app.register_message::<MapData>(ChannelDirection::ServerToClient);
         // Components
+        // Predicted by players, interpolated by spectators
         app.register_component::<PlayerClient>(ChannelDirection::ServerToClient)
             .add_prediction(ComponentSyncMode::Simple);
 
         app.register_component::<Character>(ChannelDirection::ServerToClient)
-            .add_prediction(ComponentSyncMode::Once);
+            .add_prediction(ComponentSyncMode::Once)
+            .add_interpolation(ComponentSyncMode::Once);
 
         app.register_component::<Projectile>(ChannelDirection::ServerToClient)
+            .add_prediction(ComponentSyncMode::Once)
+            .add_interpolation(ComponentSyncMode::Once);
+
+        app.register_component::<HitSource>(ChannelDirection::ServerToClient)
             .add_prediction(ComponentSyncMode::Once);
 
+        // Spawn state of the projectiles, replicated once then simulated by the clients
+        app.register_component::<ProjectileData>(ChannelDirection::ServerToClient)
+            .add_prediction(ComponentSyncMode::Once)
+            .add_map_entities();
+
+        app.register_component::<ProjectileBehaviors>(ChannelDirection::ServerToClient)
+            .add_prediction(ComponentSyncMode::Once);
+
+        app.register_component::<SkillInstanceHash>(ChannelDirection::ServerToClient)
+            .add_prediction(ComponentSyncMode::Once);
+
+        app.register_component::<Pierce>(ChannelDirection::ServerToClient)
+            .add_prediction(ComponentSyncMode::Once);
+
+        app.register_component::<Obstacle>(ChannelDirection::ServerToClient)
+            .add_prediction(ComponentSyncMode::Once)
+            .add_interpolation(ComponentSyncMode::Once);
+
         app.register_component::<SkillSlotMap>(ChannelDirection::ServerToClient)
             .add_prediction(ComponentSyncMode::Once);
 
         app.register_component::<MovementSpeed>(ChannelDirection::ServerToClient)
             .add_prediction(ComponentSyncMode::Once);
 
+        app.register_component::<PreferredDistance>(ChannelDirection::ServerToClient)
+            .add_prediction(ComponentSyncMode::Once);
+
+        app.register_component::<Aggro>(ChannelDirection::ServerToClient)
+            .add_prediction(ComponentSyncMode::Simple)
+            .add_map_entities();
+
         app.register_component::<Health>(ChannelDirection::ServerToClient)
-            .add_prediction(ComponentSyncMode::Full);
+            .add_prediction(ComponentSyncMode::Full)
+            .add_interpolation(ComponentSyncMode::Simple);
 
         app.register_component::<Mana>(ChannelDirection::ServerToClient)
-            .add_prediction(ComponentSyncMode::Full);
+            .add_prediction(ComponentSyncMode::Full)
+            .add_interpolation(ComponentSyncMode::Simple);
 
         app.register_component::<Dead>(ChannelDirection::ServerToClient)
-            .add_prediction(ComponentSyncMode::Full);
+            .add_prediction(ComponentSyncMode::Full)
+            .add_interpolation(ComponentSyncMode::Simple);
 
         app.register_component::<MovementTarget>(ChannelDirection::ServerToClient)
             .add_prediction(ComponentSyncMode::Full);
 
-        app.register_component::<LinearVelocity>(ChannelDirection::ServerToClient)
+        app.register_component::<ForcedMovement>(ChannelDirection::ServerToClient)
             .add_prediction(ComponentSyncMode::Full);
 
-        app.register_component::<AngularVelocity>(ChannelDirection::ServerToClient)
+        app.register_component::<PlayerPath>(ChannelDirection::ServerToClient)
             .add_prediction(ComponentSyncMode::Full);
 
-        app.register_component::<Position>(ChannelDirection::ServerToClient)
+        app.register_component::<EnemyPath>(ChannelDirection::ServerToClient)
             .add_prediction(ComponentSyncMode::Full);
 
+        app.register_component::<PartyMember>(ChannelDirection::ServerToClient)
+            .add_prediction(ComponentSyncMode::Once)
+            .add_interpolation(ComponentSyncMode::Once);
+
+        app.register_component::<Experience>(ChannelDirection::ServerToClient)
+            .add_prediction(ComponentSyncMode::Simple);
+
+        app.register_component::<GodMode>(ChannelDirection::ServerToClient)
+            .add_prediction(ComponentSyncMode::Simple);
+
+        // Quantized, see network::quantize
+        app.register_component_custom_serde::<LinearVelocity>(
+            ChannelDirection::ServerToClient,
+            linear_velocity_serialize_fns(),
+        )
+        .add_prediction(ComponentSyncMode::Full)
+        .add_should_rollback(linear_velocity_should_rollback)
+        .add_interpolation(ComponentSyncMode::Simple);
+
+        app.register_component_custom_serde::<Position>(
+            ChannelDirection::ServerToClient,
+            position_serialize_fns(),
+        )
+        .add_prediction(ComponentSyncMode::Full)
+        .add_should_rollback(position_should_rollback)
+        .add_interpolation(ComponentSyncMode::Full)
+        .add_interpolation_fn(|start: &Position, end: &Position, t: f32| {
+            Position(start.0.lerp(end.0, t))
+        });
+
         // Server driven components
         app.register_component::<ItemDropped>(ChannelDirection::ServerToClient);
 
@@ -91,5 +184,29 @@ impl Plugin for ProtocolPlugin {
             mode: ChannelMode::OrderedReliable(ReliableSettings::default()),
             ..default()
         });
+        app.add_channel::<ChatChannel>(ChannelSettings {
+            mode: ChannelMode::OrderedReliable(ReliableSettings::default()),
+            ..default()
+        });
+        app.add_channel::<MapChannel>(ChannelSettings {
+            mode: ChannelMode::OrderedReliable(ReliableSettings::default()),
+            ..default()
+        });
+    }
+}
+
+/// Tell the server how this client joins the game, see [`JoinGame`]
+pub fn send_join_game(
+    join_mode: Res<JoinMode>,
+    username: Res<Username>,
+    mut connection_manager: ResMut<ConnectionManager>,
+) {
+    info!("[send_join_game] Joining as {:?}", *join_mode);
+    let join_game = JoinGame {
+        mode: *join_mode,
+        username: username.0.clone(),
+    };
+    if let Err(err) = connection_manager.send_message::<Channel1, _>(&join_game) {
+        error!("[send_join_game] Failed to send JoinGame: {:?}", err);
     }
 }
@@ -4,6 +4,8 @@ use lightyear::prelude::*;
 
 pub const FIXED_TIMESTEP_HZ: f64 = 64.0;
 pub const REPLICATION_INTERVAL: Duration = Duration::from_millis(40);
+/// Ticks between an input and the tick it is applied at, on the clients and the server
+pub const INPUT_DELAY_TICKS: u16 = 6;
 
 pub fn shared_config() -> SharedConfig {
     SharedConfig {
@@ -14,3 +16,12 @@ pub fn shared_config() -> SharedConfig {
         },
     }
 }
+
+pub fn client_prediction_config() -> client::PredictionConfig {
+    client::PredictionConfig {
+        minimum_input_delay_ticks: INPUT_DELAY_TICKS,
+        maximum_input_delay_before_prediction: INPUT_DELAY_TICKS,
+        maximum_predicted_ticks: 100,
+        ..Default::default()
+    }
+}
@@ -66,7 +66,9 @@ impl Plugin for SharedPlugin {
         app.insert_resource(Gravity(Vec2::ZERO));
         app.insert_resource(SkillDb::default());
         app.insert_resource(Map::default());
-        app.insert_resource(FlowField::default());
+        app.init_resource::<SelectedMap>();
+        app.insert_resource(FlowFields::default());
+        app.insert_resource(SpatialIndex::default());
 
         app.add_event::<HitEvent>();
         app.add_event::<TriggerSkillEvent>();
@@ -90,8 +92,12 @@ impl Plugin for SharedPlugin {
         app.add_systems(
             FixedUpdate,
             (
+                update_projectile_collision_layers,
+                update_spatial_index,
                 update_flow_field.run_if(not(is_in_rollback)),
                 enemy_movement_behavior,
+                apply_forced_movement,
+                steer_projectiles,
                 process_projectile_distance,
             )
                 .chain()
@@ -102,6 +108,7 @@ impl Plugin for SharedPlugin {
             FixedUpdate,
             (
                 mana_regeneration,
+                apply_terrain_damage,
                 progress_skill_cooldown_timers.run_if(not(is_in_rollback)),
                 progress_skill_in_progress_timers,
             )
@@ -118,7 +125,10 @@ impl Plugin for SharedPlugin {
 
         app.add_systems(
             FixedUpdate,
-            on_execute_skill_projectile_event
+            (
+                on_execute_skill_projectile_event,
+                on_execute_skill_dash_event,
+            )
                 .run_if(on_event::<ExcecuteSkillEvent>)
                 .in_set(GameSimulationSet::ExcecuteSkills),
         );
@@ -134,6 +144,7 @@ impl Plugin for SharedPlugin {
                 on_hit_event.run_if(on_event::<HitEvent>),
                 set_character_local,
                 set_character_life_state,
+                set_obstacle_local,
             )
                 .chain()
                 .in_set(GameSimulationSet::ConsumeHitEvents),
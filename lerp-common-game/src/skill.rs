@@ -12,12 +12,28 @@ pub enum SkillName {
     BowAttack = 1,
     SplitArrow = 2,
     FlowerArrow = 3,
+    Dash = 4,
+    ChainArrow = 5,
+    Boomerang = 6,
+    RicochetArrow = 7,
+    HomingArrow = 8,
 }
 impl SkillName {
     /// You could use the `strum` crate to derive this automatically!
     pub fn variants() -> impl Iterator<Item = SkillName> {
         use SkillName::*;
-        [BowAttack, SplitArrow, FlowerArrow].iter().copied()
+        [
+            BowAttack,
+            SplitArrow,
+            FlowerArrow,
+            Dash,
+            ChainArrow,
+            Boomerang,
+            RicochetArrow,
+            HomingArrow,
+        ]
+        .iter()
+        .copied()
     }
 }
 
@@ -26,6 +42,8 @@ pub struct TriggerSkillEvent {
     pub initiator: Entity,
     pub skill: Entity,
     pub target: Vec2,
+    /// Tick of the characters the initiator aimed at, when it is a player
+    pub target_tick: Option<Tick>,
 }
 
 #[derive(Event, Clone, Copy)]
@@ -34,16 +52,23 @@ pub struct ExcecuteSkillEvent {
     pub skill: Entity,
     pub target: Vec2,
     pub skill_instance_hash: u64,
+    /// Tick the skill was triggered at
+    pub trigger_tick: Tick,
+    /// Tick of the characters the initiator aimed at, when it is a player
+    pub target_tick: Option<Tick>,
 }
 
+#[derive(Serialize)]
 pub struct SkillData {
     pub cooldown: Option<Duration>,
     pub cost: Option<SkillCost>,
     pub projectile: Option<SkillProjectile>,
     pub damage_on_hit: Option<SkillDamageOnHit>,
+    pub knockback: Option<SkillKnockback>,
+    pub dash: Option<SkillDash>,
 }
 
-#[derive(Component, Default, Deref)]
+#[derive(Component, Serialize, Deserialize, Clone, Copy, Default, Debug, PartialEq, Deref)]
 pub struct SkillInstanceHash(pub u64);
 
 #[derive(Component)]
@@ -61,7 +86,7 @@ pub struct SkillSpeed {
 #[derive(Component, Deref, DerefMut)]
 pub struct Skill(pub SkillName);
 
-#[derive(Component, Clone, Copy)]
+#[derive(Component, Serialize, Clone, Copy)]
 pub struct SkillCost {
     mana: f32,
 }
@@ -71,17 +96,30 @@ pub struct SkillCooldown {
     timer: Timer,
 }
 
-#[derive(Component, Clone, Copy)]
+#[derive(Component, Serialize, Clone, Copy)]
 pub struct SkillProjectile {
     pub count: f32,
     pub pierce_count: u32,
+    pub behaviors: ProjectileBehaviors,
 }
 
-#[derive(Component, Clone, Copy)]
+#[derive(Component, Serialize, Clone, Copy)]
 pub struct SkillDamageOnHit {
     pub value: f32,
 }
 
+/// In pixels, distance the hit characters are pushed away
+#[derive(Component, Serialize, Clone, Copy)]
+pub struct SkillKnockback {
+    pub distance: f32,
+}
+
+/// In pixels, distance the initiator dashes towards the target
+#[derive(Component, Serialize, Clone, Copy)]
+pub struct SkillDash {
+    pub distance: f32,
+}
+
 #[derive(Resource, Deref)]
 pub struct SkillDb {
     map: HashMap<SkillName, SkillData>,
@@ -97,8 +135,11 @@ impl Default for SkillDb {
                 projectile: Some(SkillProjectile {
                     count: 1.,
                     pierce_count: 0,
+                    behaviors: ProjectileBehaviors::default(),
                 }),
                 damage_on_hit: Some(SkillDamageOnHit { value: 10. }),
+                knockback: None,
+                dash: None,
             },
         );
         map.insert(
@@ -109,8 +150,11 @@ impl Default for SkillDb {
                 projectile: Some(SkillProjectile {
                     count: 3.,
                     pierce_count: 2,
+                    behaviors: ProjectileBehaviors::default(),
                 }),
                 damage_on_hit: Some(SkillDamageOnHit { value: 7. }),
+                knockback: None,
+                dash: None,
             },
         );
         map.insert(
@@ -121,14 +165,118 @@ impl Default for SkillDb {
                 projectile: Some(SkillProjectile {
                     count: 20.,
                     pierce_count: 99,
+                    behaviors: ProjectileBehaviors::default(),
                 }),
                 damage_on_hit: Some(SkillDamageOnHit { value: 100. }),
+                knockback: None,
+                dash: None,
+            },
+        );
+        map.insert(
+            SkillName::Dash,
+            SkillData {
+                cooldown: Some(Duration::from_millis(1000)),
+                cost: Some(SkillCost { mana: 5. }),
+                projectile: None,
+                damage_on_hit: None,
+                knockback: None,
+                dash: Some(SkillDash {
+                    distance: 4. * PIXEL_METER,
+                }),
+            },
+        );
+        map.insert(
+            SkillName::ChainArrow,
+            SkillData {
+                cooldown: Some(Duration::from_millis(1000)),
+                cost: Some(SkillCost { mana: 10. }),
+                projectile: Some(SkillProjectile {
+                    count: 1.,
+                    pierce_count: 0,
+                    behaviors: ProjectileBehaviors {
+                        chain: 3,
+                        ..default()
+                    },
+                }),
+                damage_on_hit: Some(SkillDamageOnHit { value: 8. }),
+                knockback: None,
+                dash: None,
+            },
+        );
+        map.insert(
+            SkillName::Boomerang,
+            SkillData {
+                cooldown: Some(Duration::from_millis(2000)),
+                cost: Some(SkillCost { mana: 12. }),
+                projectile: Some(SkillProjectile {
+                    count: 1.,
+                    pierce_count: 99,
+                    behaviors: ProjectileBehaviors {
+                        boomerang: true,
+                        ..default()
+                    },
+                }),
+                damage_on_hit: Some(SkillDamageOnHit { value: 12. }),
+                knockback: None,
+                dash: None,
+            },
+        );
+        map.insert(
+            SkillName::RicochetArrow,
+            SkillData {
+                cooldown: Some(Duration::from_millis(1500)),
+                cost: Some(SkillCost { mana: 12. }),
+                projectile: Some(SkillProjectile {
+                    count: 1.,
+                    pierce_count: 0,
+                    behaviors: ProjectileBehaviors {
+                        ricochet: 1,
+                        split: 2,
+                        ..default()
+                    },
+                }),
+                damage_on_hit: Some(SkillDamageOnHit { value: 9. }),
+                knockback: Some(SkillKnockback {
+                    distance: 1. * PIXEL_METER,
+                }),
+                dash: None,
+            },
+        );
+        map.insert(
+            SkillName::HomingArrow,
+            SkillData {
+                cooldown: Some(Duration::from_millis(1000)),
+                cost: Some(SkillCost { mana: 10. }),
+                projectile: Some(SkillProjectile {
+                    count: 3.,
+                    pierce_count: 0,
+                    behaviors: ProjectileBehaviors {
+                        homing: Some(3.),
+                        ..default()
+                    },
+                }),
+                damage_on_hit: Some(SkillDamageOnHit { value: 6. }),
+                knockback: None,
+                dash: None,
             },
         );
         Self { map }
     }
 }
 
+impl SkillDb {
+    /// Hash of the serialized skills, used to detect that two builds do not share the same skills
+    pub fn checksum(&self) -> u64 {
+        // The map order is not stable
+        let mut skills: Vec<(&SkillName, &SkillData)> = self.map.iter().collect();
+        skills.sort_by_key(|(skill_name, _)| **skill_name as u64);
+
+        let mut hasher = StableHasher::new();
+        bincode::serialize_into(&mut hasher, &skills).expect("Skills are serializable");
+        hasher.finish()
+    }
+}
+
 #[derive(Component, Deref, DerefMut, Default)]
 pub struct SkillsAvailable {
     pub map: HashMap<SkillName, Entity>,
@@ -139,7 +287,13 @@ pub struct DamageOnHit {
     pub value: f32,
 }
 
+/// In pixels, see [`SkillKnockback`]
 #[derive(Component)]
+pub struct KnockbackOnHit {
+    pub distance: f32,
+}
+
+#[derive(Component, Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
 pub struct Pierce {
     pub count: u32,
 }
@@ -173,6 +327,14 @@ pub fn attach_skill(
             skill.insert(damage_on_hit);
         }
 
+        if let Some(knockback) = skill_data.knockback {
+            skill.insert(knockback);
+        }
+
+        if let Some(dash) = skill_data.dash {
+            skill.insert(dash);
+        }
+
         skills_available.insert(*skill_name, skill.id());
     });
 }
@@ -266,6 +428,8 @@ pub fn on_trigger_skill_event(
                 skill: event.skill,
                 target: event.target,
                 skill_instance_hash,
+                trigger_tick: tick_manager.tick(),
+                target_tick: event.target_tick,
             },
         });
     }
@@ -293,3 +457,23 @@ pub fn progress_skill_in_progress_timers(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checksum_covers_the_skill_data() {
+        let skill_db = SkillDb::default();
+        assert_eq!(skill_db.checksum(), SkillDb::default().checksum());
+
+        let mut changed = SkillDb::default();
+        let projectile = changed
+            .map
+            .get_mut(&SkillName::BowAttack)
+            .and_then(|skill_data| skill_data.projectile.as_mut())
+            .unwrap();
+        projectile.behaviors.chain += 1;
+        assert_ne!(changed.checksum(), skill_db.checksum());
+    }
+}
@@ -0,0 +1,95 @@
+// Uniform grid of the alive characters, rebuilt every tick, so that the queries around a position
+// (enemy avoidance, aggro, area of effect hits) do not go through every character.
+use avian2d::prelude::{LinearVelocity, Position};
+use bevy::{prelude::*, utils::HashMap};
+use lightyear::prelude::{client::Predicted, server::ReplicationTarget, PreSpawnedPlayerObject};
+
+use crate::prelude::*;
+
+/// Size of a cell of the grid, close to the radius of the most frequent queries
+pub const SPATIAL_CELL_SIZE: f32 = 2. * PIXEL_METER;
+
+#[derive(Clone, Copy)]
+pub struct SpatialEntry {
+    pub entity: Entity,
+    pub position: Vec2,
+    pub velocity: Vec2,
+    /// Radius of the collider
+    pub radius: f32,
+    pub team: Team,
+}
+
+#[derive(Resource, Default, Clone)]
+pub struct SpatialIndex {
+    /// Cells are never removed, so that their allocations are reused by the next rebuild
+    cells: HashMap<IVec2, Vec<SpatialEntry>>,
+}
+impl SpatialIndex {
+    fn cell(position: Vec2) -> IVec2 {
+        (position / SPATIAL_CELL_SIZE).floor().as_ivec2()
+    }
+
+    /// Replace the entries, each cell being sorted by position so that the queries return them
+    /// in the same order on the clients and the server
+    pub fn rebuild(&mut self, entries: impl Iterator<Item = SpatialEntry>) {
+        for cell in self.cells.values_mut() {
+            cell.clear();
+        }
+        for entry in entries {
+            self.cells
+                .entry(Self::cell(entry.position))
+                .or_default()
+                .push(entry);
+        }
+        for cell in self.cells.values_mut() {
+            cell.sort_by(|a, b| {
+                a.position
+                    .x
+                    .total_cmp(&b.position.x)
+                    .then_with(|| a.position.y.total_cmp(&b.position.y))
+            });
+        }
+    }
+
+    /// Entries within the radius of the position, cell by cell from the bottom left
+    pub fn query(&self, position: Vec2, radius: f32) -> impl Iterator<Item = &SpatialEntry> {
+        let min = Self::cell(position - radius);
+        let max = Self::cell(position + radius);
+        (min.y..=max.y)
+            .flat_map(move |y| (min.x..=max.x).map(move |x| IVec2::new(x, y)))
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .filter(move |entry| entry.position.distance_squared(position) <= radius * radius)
+    }
+}
+
+pub fn update_spatial_index(
+    mut spatial_index: ResMut<SpatialIndex>,
+    character_q: Query<
+        (
+            Entity,
+            &Character,
+            &Position,
+            Option<&LinearVelocity>,
+            &Team,
+        ),
+        (
+            With<Alive>,
+            Or<(
+                With<Predicted>,
+                With<PreSpawnedPlayerObject>,
+                With<ReplicationTarget>,
+            )>,
+        ),
+    >,
+) {
+    spatial_index.rebuild(character_q.iter().map(
+        |(entity, character, position, velocity, team)| SpatialEntry {
+            entity,
+            position: position.0,
+            velocity: velocity.map_or(Vec2::ZERO, |velocity| velocity.0),
+            radius: character.id.data().collider_diameter / 2.,
+            team: *team,
+        },
+    ));
+}
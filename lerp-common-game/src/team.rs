@@ -1,6 +1,7 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Component, Clone, Copy, Default, PartialEq, Eq)]
+#[derive(Component, Serialize, Deserialize, Clone, Copy, Default, Debug, PartialEq, Eq)]
 pub enum Team {
     Player,
     Enemy,
@@ -0,0 +1,93 @@
+// Terrain kinds of the floor cells and their gameplay effects.
+// The nav tiles carry the kind, each system reads the effects it cares about.
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use lightyear::prelude::{client::Predicted, server::ReplicationTarget};
+
+use crate::prelude::*;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TerrainKind {
+    #[default]
+    Ground,
+    /// `~` cells, slow down the characters
+    ShallowWater,
+    /// `L` cells, damage the characters standing in it
+    Lava,
+    /// `P` cells, block the characters but not the projectiles
+    Pit,
+}
+
+impl TerrainKind {
+    pub fn from_cell(cell: char) -> Self {
+        match cell {
+            '~' => Self::ShallowWater,
+            'L' => Self::Lava,
+            'P' => Self::Pit,
+            _ => Self::Ground,
+        }
+    }
+
+    pub fn movement_speed_multiplier(&self) -> f32 {
+        match self {
+            Self::ShallowWater => 0.5,
+            _ => 1.,
+        }
+    }
+
+    pub fn damage_per_second(&self) -> f32 {
+        match self {
+            Self::Lava => 15.,
+            _ => 0.,
+        }
+    }
+
+    pub fn blocks_walking(&self) -> bool {
+        *self == Self::Pit
+    }
+
+    /// Cost of a nav tile for the enemies pathing, None when they never path through it.
+    /// They still walk it when pushed in
+    pub fn path_cost(&self) -> Option<f32> {
+        match self {
+            Self::Ground | Self::ShallowWater => Some(1. / self.movement_speed_multiplier()),
+            Self::Lava => Some(8.),
+            Self::Pit => None,
+        }
+    }
+}
+
+/// Collider of the pit cells, see [`TerrainKind::Pit`]
+#[derive(Component, Clone)]
+pub struct Pit;
+
+/// Movement speed of a character standing at the given position
+pub fn terrain_movement_speed(map_grid: &Map, position: &Position, movement_speed: f32) -> f32 {
+    movement_speed
+        * map_grid
+            .get_terrain_from_position(position)
+            .movement_speed_multiplier()
+}
+
+pub fn apply_terrain_damage(
+    time: Res<Time<Fixed>>,
+    map_grid: Res<Map>,
+    mut character_q: Query<
+        (&Position, &mut Health),
+        (
+            With<Alive>,
+            Without<GodMode>,
+            Or<(With<Predicted>, With<ReplicationTarget>)>,
+        ),
+    >,
+) {
+    for (position, mut health) in character_q.iter_mut() {
+        let damage = map_grid
+            .get_terrain_from_position(position)
+            .damage_per_second()
+            * time.delta_secs();
+        if damage > 0. {
+            health.current = (health.current - damage).max(0.);
+        }
+    }
+}
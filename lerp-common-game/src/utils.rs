@@ -7,6 +7,80 @@ pub fn xor_u64s(values: &[u64]) -> u64 {
     values.iter().fold(0, |acc, &val| acc ^ val)
 }
 
+/// FNV-1a over little endian bytes. Unlike `DefaultHasher` it gives the same result on every
+/// Rust release and target, for the hashes compared between the clients and the server
+#[derive(Clone, Copy, Debug)]
+pub struct StableHasher(u64);
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl StableHasher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+
+    pub fn write_u8(&mut self, value: u8) {
+        self.write_bytes(&[value]);
+    }
+
+    pub fn write_u32(&mut self, value: u32) {
+        self.write_bytes(&value.to_le_bytes());
+    }
+
+    pub fn write_u64(&mut self, value: u64) {
+        self.write_bytes(&value.to_le_bytes());
+    }
+
+    pub fn write_f32(&mut self, value: f32) {
+        self.write_u32(value.to_bits());
+    }
+
+    pub fn write_bool(&mut self, value: bool) {
+        self.write_u8(value as u8);
+    }
+
+    /// Length prefixed, so that `"ab", "c"` and `"a", "bc"` differ
+    pub fn write_str(&mut self, value: &str) {
+        self.write_u64(value.len() as u64);
+        self.write_bytes(value.as_bytes());
+    }
+
+    /// Presence tag then the value
+    pub fn write_option<T>(&mut self, value: Option<T>, write: impl FnOnce(&mut Self, T)) {
+        self.write_bool(value.is_some());
+        if let Some(value) = value {
+            write(self, value);
+        }
+    }
+
+    pub fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Hash of serialized values
+impl std::io::Write for StableHasher {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.write_bytes(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
 pub fn isometric_to_cartesian(iso_x: f32, iso_y: f32) -> Vec2 {
     Vec2::new(
         (iso_x - 2.0 * iso_y) / 2.0, // Cartesian X
@@ -31,3 +105,30 @@ pub fn u64_to_vec3(value: u64) -> Vec3 {
 pub fn vec3_to_u64(v: Vec3) -> u64 {
     ((v.x as u64) << 42) | ((v.y as u64) << 20) | (v.z as u64)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stable_hasher_matches_fnv1a_reference_values() {
+        assert_eq!(StableHasher::new().finish(), 0xcbf2_9ce4_8422_2325);
+        let mut hasher = StableHasher::new();
+        hasher.write_bytes(b"a");
+        assert_eq!(hasher.finish(), 0xaf63_dc4c_8601_ec8c);
+        let mut hasher = StableHasher::new();
+        hasher.write_bytes(b"foobar");
+        assert_eq!(hasher.finish(), 0x8594_4171_f739_67e8);
+    }
+
+    #[test]
+    fn stable_hasher_prefixes_strings_with_their_length() {
+        let mut split_first = StableHasher::new();
+        split_first.write_str("ab");
+        split_first.write_str("c");
+        let mut split_second = StableHasher::new();
+        split_second.write_str("a");
+        split_second.write_str("bc");
+        assert_ne!(split_first.finish(), split_second.finish());
+    }
+}
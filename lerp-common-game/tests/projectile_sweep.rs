@@ -0,0 +1,170 @@
+use avian2d::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use lerp_common_game::prelude::*;
+
+/// Distance moved by a projectile within a tick at 20 Hz, three times the one of the game tick rate
+const LOW_TICK_RATE_STEP: f32 = PROJECTILE_BASE_MOVEMENT_SPEED / 20.;
+
+fn init_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, TransformPlugin, PhysicsPlugins::default()));
+    app
+}
+
+/// Wall one nav tile thick, spanning vertically across the path of the projectiles
+fn spawn_wall(app: &mut App, x: f32) -> Entity {
+    app.world_mut()
+        .spawn((
+            Wall,
+            GameLayer::wall(),
+            Position::from_xy(x, 0.),
+            Rotation::default(),
+            RigidBody::Static,
+            Collider::rectangle(NAV_TILE_SIZE, 4. * NAV_TILE_SIZE),
+        ))
+        .id()
+}
+
+fn spawn_enemy(app: &mut App, x: f32) -> Entity {
+    app.world_mut()
+        .spawn((
+            Hittable::default(),
+            GameLayer::character(Team::Enemy),
+            Position::from_xy(x, 0.),
+            Rotation::default(),
+            RigidBody::Kinematic,
+            Collider::circle(PROJECTILE_SIZE / 2.),
+        ))
+        .id()
+}
+
+fn sweep(app: &mut App, from: Vec2, to: Vec2) -> ProjectileSweep {
+    sweep_rewound(app, from, to, None)
+}
+
+fn sweep_rewound(
+    app: &mut App,
+    from: Vec2,
+    to: Vec2,
+    rewound: Option<SpatialIndex>,
+) -> ProjectileSweep {
+    app.world_mut()
+        .run_system_once(
+            move |mut spatial_query: SpatialQuery, hittable_q: Query<&Hittable>| {
+                spatial_query.update_pipeline();
+                sweep_projectile(
+                    &spatial_query,
+                    from,
+                    to,
+                    Team::Player,
+                    rewound.as_ref(),
+                    |entity| hittable_q.contains(entity),
+                )
+            },
+        )
+        .unwrap()
+}
+
+#[test]
+fn projectile_does_not_tunnel_through_a_one_tile_wall() {
+    let mut app = init_app();
+    spawn_wall(&mut app, 0.);
+
+    // Both ends of the tick are clear of the wall, a discrete overlap test would miss it
+    let from = Vec2::new(-LOW_TICK_RATE_STEP / 2., 0.);
+    let to = Vec2::new(LOW_TICK_RATE_STEP / 2., 0.);
+    assert!(to.x - from.x > NAV_TILE_SIZE + PROJECTILE_SIZE);
+
+    let sweep = sweep(&mut app, from, to);
+    let (distance, normal) = sweep.wall.expect("Projectile tunnelled through the wall");
+    let expected_distance = LOW_TICK_RATE_STEP / 2. - NAV_TILE_SIZE / 2. - PROJECTILE_SIZE / 2.;
+    assert!((distance - expected_distance).abs() < 0.1, "{distance}");
+    assert!(normal.abs_diff_eq(Vec2::NEG_X, 1e-3), "{normal}");
+}
+
+#[test]
+fn projectile_does_not_hit_targets_behind_a_wall() {
+    let mut app = init_app();
+    spawn_wall(&mut app, 0.);
+    let enemy_in_front = spawn_enemy(&mut app, -NAV_TILE_SIZE);
+    spawn_enemy(&mut app, NAV_TILE_SIZE);
+
+    let sweep = sweep(
+        &mut app,
+        Vec2::new(-LOW_TICK_RATE_STEP, 0.),
+        Vec2::new(LOW_TICK_RATE_STEP, 0.),
+    );
+    assert!(sweep.wall.is_some());
+    assert_eq!(sweep.targets, vec![enemy_in_front]);
+}
+
+#[test]
+fn projectile_hits_every_target_it_moved_past_in_order() {
+    let mut app = init_app();
+    let far_enemy = spawn_enemy(&mut app, LOW_TICK_RATE_STEP / 2.);
+    let near_enemy = spawn_enemy(&mut app, -LOW_TICK_RATE_STEP / 4.);
+
+    let sweep = sweep(
+        &mut app,
+        Vec2::new(-LOW_TICK_RATE_STEP, 0.),
+        Vec2::new(LOW_TICK_RATE_STEP, 0.),
+    );
+    assert!(sweep.wall.is_none());
+    assert_eq!(sweep.targets, vec![near_enemy, far_enemy]);
+}
+
+#[test]
+fn projectile_crossing_a_crowd_still_stops_at_the_wall() {
+    let mut app = init_app();
+    spawn_wall(&mut app, 0.);
+    let crowd: Vec<Entity> = (0..40)
+        .map(|_| spawn_enemy(&mut app, -NAV_TILE_SIZE))
+        .collect();
+
+    let sweep = sweep(
+        &mut app,
+        Vec2::new(-LOW_TICK_RATE_STEP, 0.),
+        Vec2::new(LOW_TICK_RATE_STEP, 0.),
+    );
+    assert!(sweep.wall.is_some());
+    assert_eq!(sweep.targets.len(), crowd.len());
+}
+
+#[test]
+fn projectile_bouncing_off_a_wall_is_not_stopped_by_it() {
+    let mut app = init_app();
+    spawn_wall(&mut app, 0.);
+
+    // Touching the wall after a ricochet, moving away from it
+    let from = Vec2::new(-(NAV_TILE_SIZE + PROJECTILE_SIZE) / 2., 0.);
+    let sweep = sweep(&mut app, from, from - Vec2::new(LOW_TICK_RATE_STEP, 0.));
+    assert!(sweep.wall.is_none());
+}
+
+#[test]
+fn lag_compensated_projectile_hits_targets_where_they_were() {
+    let mut app = init_app();
+    // Moved out of the path of the projectile since the shooter saw it
+    let enemy = spawn_enemy(&mut app, 0.);
+    app.world_mut()
+        .entity_mut(enemy)
+        .insert(Position::from_xy(0., 2. * PIXEL_METER));
+
+    let mut rewound = SpatialIndex::default();
+    rewound.rebuild(std::iter::once(SpatialEntry {
+        entity: enemy,
+        position: Vec2::ZERO,
+        velocity: Vec2::ZERO,
+        radius: PROJECTILE_SIZE / 2.,
+        team: Team::Enemy,
+    }));
+
+    let from = Vec2::new(-LOW_TICK_RATE_STEP, 0.);
+    let to = Vec2::new(LOW_TICK_RATE_STEP, 0.);
+    assert!(sweep(&mut app, from, to).targets.is_empty());
+    assert_eq!(
+        sweep_rewound(&mut app, from, to, Some(rewound)).targets,
+        vec![enemy]
+    );
+}
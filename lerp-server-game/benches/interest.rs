@@ -0,0 +1,73 @@
+// Area of interest benchmarks, run with `cargo bench -p lerp-server-game`.
+// Compares going through every entity for each player with querying the interest grid.
+use bevy::prelude::*;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use lerp_common_game::prelude::*;
+use lerp_server_game::game::interest::*;
+
+const ENTITY_COUNTS: [usize; 3] = [1000, 4000, 16000];
+const PLAYER_COUNT: usize = 16;
+/// Side of the square the entities and the players are spread on, in meters
+const WORLD_SIZE: f32 = 300.;
+
+/// Points spread evenly on the world square (additive recurrence on the plastic number)
+fn spread(count: usize) -> Vec<Vec2> {
+    let plastic = 1.324_718;
+    let step = Vec2::new(1. / plastic, 1. / (plastic * plastic));
+    (0..count)
+        .map(|i| (step * i as f32).fract() * WORLD_SIZE * PIXEL_METER)
+        .collect()
+}
+
+fn bench_interest(c: &mut Criterion) {
+    let config = InterestConfig::default();
+    let leave_radius = config.radius + config.hysteresis;
+    let players = spread(PLAYER_COUNT);
+
+    let mut group = c.benchmark_group("interest");
+    for entity_count in ENTITY_COUNTS {
+        let entities: Vec<(Entity, Vec2)> = spread(entity_count)
+            .into_iter()
+            .enumerate()
+            .map(|(i, position)| (Entity::from_raw(i as u32), position))
+            .collect();
+
+        // Every entity checked for each player
+        group.bench_with_input(
+            BenchmarkId::new("scan", entity_count),
+            &entities,
+            |b, entities| {
+                b.iter(|| {
+                    players
+                        .iter()
+                        .map(|player| {
+                            entities
+                                .iter()
+                                .filter(|(_, position)| position.distance(*player) <= config.radius)
+                                .count()
+                        })
+                        .sum::<usize>()
+                })
+            },
+        );
+        // What runs every tick, rebuilding the grid then querying it for each player
+        let mut grid = InterestGrid::default();
+        group.bench_with_input(
+            BenchmarkId::new("grid", entity_count),
+            &entities,
+            |b, entities| {
+                b.iter(|| {
+                    grid.rebuild(leave_radius, entities.iter().copied());
+                    players
+                        .iter()
+                        .map(|player| grid.query(*player, config.radius).count())
+                        .sum::<usize>()
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_interest);
+criterion_main!(benches);
@@ -0,0 +1,486 @@
+// Admin commands changing a running instance, to debug and operate it live.
+// They come from the HTTP API, or from the chat with `/admin <command>` for the clients logged in with the admin token.
+// Usernames are declared by the clients, so the rights are bound to the connection that gave the token instead.
+use avian2d::prelude::*;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::utils::HashSet;
+use lerp_common_game::prelude::*;
+use lightyear::prelude::server::*;
+use lightyear::prelude::*;
+use tokio::sync::{mpsc, oneshot};
+
+use super::enemy::{spawn_enemy_pack, EnemyArchetypes, DEFAULT_ENEMY_ARCHETYPE};
+use super::join::ClientUsernames;
+use super::spawner::SpawnerPresets;
+use super::ClientPlayerMap;
+
+/// Token expected from the HTTP API and the chat logins, the admin commands are disabled when it is not set
+pub const ADMIN_TOKEN_ENV: &str = "LERP_ADMIN_TOKEN";
+
+const ADMIN_HELP: &str =
+    "Admin commands: login <token>, spawn <count> [archetype] [x y], killall, \
+    give <common|magic|rare|unique>, health <value>, teleport <x> <y>, god, reload";
+const MAX_SPAWNED_ENEMIES: u32 = 500;
+
+#[derive(Resource, Clone, Debug)]
+pub struct AdminConfig {
+    pub token: Option<String>,
+}
+
+impl Default for AdminConfig {
+    fn default() -> Self {
+        let token = std::env::var(ADMIN_TOKEN_ENV)
+            .ok()
+            .filter(|token| !token.is_empty());
+        Self { token }
+    }
+}
+
+impl AdminConfig {
+    pub fn is_valid_token(&self, token: &str) -> bool {
+        self.token.as_deref() == Some(token)
+    }
+}
+
+/// Clients that logged in with the admin token, until they disconnect
+#[derive(Resource, Default)]
+pub struct AdminClients(pub HashSet<ClientId>);
+
+/// Command sent by the HTTP API to the instance, answered once executed
+pub struct AdminRequest {
+    pub command: String,
+    /// Player targeted by the player commands
+    pub client_id: Option<ClientId>,
+    pub reply_tx: oneshot::Sender<Result<String, String>>,
+}
+
+/// Only inserted for the instances started by the HTTP API
+#[derive(Resource)]
+pub(crate) struct AdminRequests(pub mpsc::Receiver<AdminRequest>);
+
+/// `/admin` chat command of a client, only run for the [`AdminClients`]
+#[derive(Event)]
+pub struct AdminCommandIssued {
+    pub client_id: ClientId,
+    pub command: String,
+}
+
+/// Admin command that changed the instance, from the chat or the HTTP API, recorded by the replays
+#[derive(Event, Clone, Debug)]
+pub struct AdminCommandExecuted {
+    /// Player targeted by the player commands
+    pub client_id: Option<ClientId>,
+    pub command: String,
+}
+
+#[derive(Debug, PartialEq)]
+enum AdminCommand {
+    /// Chat only, the HTTP requests carry the token
+    Login(String),
+    SpawnEnemies {
+        count: u32,
+        archetype: String,
+        /// Around the target player when not given
+        position: Option<Vec2>,
+    },
+    KillAll,
+    GiveItem(ItemRarity),
+    SetHealth(f32),
+    Teleport(Vec2),
+    GodMode,
+    ReloadData,
+    Help,
+}
+
+fn parse_arg<T: std::str::FromStr>(arg: &str, name: &str) -> Result<T, String> {
+    arg.parse::<T>()
+        .map_err(|_| format!("Invalid {}: {}", name, arg))
+}
+
+/// Positions are typed in meters
+fn parse_position(x: &str, y: &str) -> Result<Vec2, String> {
+    Ok(Vec2::new(parse_arg::<f32>(x, "x")?, parse_arg::<f32>(y, "y")?) * PIXEL_METER)
+}
+
+fn parse_admin_command(text: &str) -> Result<AdminCommand, String> {
+    let args: Vec<&str> = text.split_whitespace().collect();
+    let Some((name, args)) = args.split_first() else {
+        return Ok(AdminCommand::Help);
+    };
+
+    let command = match (*name, args) {
+        ("spawn", [count, rest @ ..]) => {
+            let count = parse_arg::<u32>(count, "count")?;
+            if count == 0 || count > MAX_SPAWNED_ENEMIES {
+                return Err(format!("Count must be in 1..={}", MAX_SPAWNED_ENEMIES));
+            }
+            let (archetype, position) = match rest {
+                [] => (DEFAULT_ENEMY_ARCHETYPE, None),
+                [archetype] => (*archetype, None),
+                [x, y] => (DEFAULT_ENEMY_ARCHETYPE, Some(parse_position(x, y)?)),
+                [archetype, x, y] => (*archetype, Some(parse_position(x, y)?)),
+                _ => return Err("Usage: spawn <count> [archetype] [x y]".to_string()),
+            };
+            AdminCommand::SpawnEnemies {
+                count,
+                archetype: archetype.to_string(),
+                position,
+            }
+        }
+        ("login", [token]) => AdminCommand::Login(token.to_string()),
+        ("killall", []) => AdminCommand::KillAll,
+        ("give", [rarity]) => AdminCommand::GiveItem(match *rarity {
+            "common" => ItemRarity::Common,
+            "magic" => ItemRarity::Magic,
+            "rare" => ItemRarity::Rare,
+            "unique" => ItemRarity::Unique,
+            rarity => return Err(format!("Invalid rarity: {}", rarity)),
+        }),
+        ("health", [value]) => AdminCommand::SetHealth(parse_arg(value, "health")?),
+        ("teleport", [x, y]) => AdminCommand::Teleport(parse_position(x, y)?),
+        ("god", []) => AdminCommand::GodMode,
+        ("reload", []) => AdminCommand::ReloadData,
+        ("help", []) => AdminCommand::Help,
+        _ => return Err(format!("Invalid command `{}`, see help", text.trim())),
+    };
+    Ok(command)
+}
+
+#[derive(SystemParam)]
+pub(crate) struct AdminWorld<'w, 's> {
+    commands: Commands<'w, 's>,
+    archetypes: ResMut<'w, EnemyArchetypes>,
+    spawner_presets: ResMut<'w, SpawnerPresets>,
+    map: Res<'w, Map>,
+    client_player_map: Res<'w, ClientPlayerMap>,
+    client_usernames: Res<'w, ClientUsernames>,
+    character_q: Query<
+        'w,
+        's,
+        (
+            &'static Character,
+            &'static mut Health,
+            &'static mut Position,
+            Has<GodMode>,
+            Has<Alive>,
+        ),
+    >,
+}
+
+impl AdminWorld<'_, '_> {
+    /// Player entity of the client, it can be queried with `character_q`
+    fn target_player(&self, client_id: Option<ClientId>) -> Result<Entity, String> {
+        let client_id = client_id.ok_or("No target player")?;
+        self.client_player_map
+            .0
+            .get(&client_id)
+            .copied()
+            .filter(|player| self.character_q.contains(*player))
+            .ok_or_else(|| format!("{} has no player", self.client_usernames.name(client_id)))
+    }
+
+    pub(crate) fn execute_text(
+        &mut self,
+        command: &str,
+        client_id: Option<ClientId>,
+    ) -> Result<String, String> {
+        parse_admin_command(command).and_then(|command| self.execute(command, client_id))
+    }
+
+    fn execute(
+        &mut self,
+        command: AdminCommand,
+        client_id: Option<ClientId>,
+    ) -> Result<String, String> {
+        match command {
+            AdminCommand::Login(_) => Err("Already authenticated".to_string()),
+            AdminCommand::SpawnEnemies {
+                count,
+                archetype,
+                position,
+            } => {
+                let Some(archetype_data) = self.archetypes.0.get(&archetype).cloned() else {
+                    return Err(format!("Unknown enemy archetype: {}", archetype));
+                };
+                let origin = match position {
+                    Some(position) => position,
+                    None => self
+                        .target_player(client_id)
+                        .ok()
+                        .and_then(|player| self.character_q.get(player).ok())
+                        .map_or(Vec2::ZERO, |(_, _, position, _, _)| position.0),
+                };
+
+                spawn_enemy_pack(
+                    &mut self.commands,
+                    &self.map,
+                    origin,
+                    count,
+                    &archetype_data,
+                );
+                Ok(format!("Spawned {} {}", count, archetype))
+            }
+            AdminCommand::KillAll => {
+                let mut killed = 0;
+                for (character, mut health, _, _, alive) in self.character_q.iter_mut() {
+                    if character.id == CharacterId::Enemy && alive {
+                        health.current = 0.;
+                        killed += 1;
+                    }
+                }
+                Ok(format!("Killed {} enemies", killed))
+            }
+            AdminCommand::GiveItem(ratity) => {
+                let player = self.target_player(client_id)?;
+                let (_, _, position, _, _) = self.character_q.get(player).unwrap();
+                let message = format!("Dropped a {:?} item", ratity);
+                self.commands.spawn((
+                    ItemDropped {
+                        position: position.0,
+                        ratity,
+                        reserved_for: None,
+                    },
+                    Replicate {
+                        target: ReplicationTarget {
+                            target: NetworkTarget::All,
+                        },
+                        group: LOOT_REPLICATION_GROUP,
+                        ..default()
+                    },
+                ));
+                Ok(message)
+            }
+            AdminCommand::SetHealth(value) => {
+                let player = self.target_player(client_id)?;
+                let (_, mut health, _, _, _) = self.character_q.get_mut(player).unwrap();
+                health.current = value.clamp(0., health.max);
+                Ok(format!("Health set to {}", health.current))
+            }
+            AdminCommand::Teleport(target) => {
+                let player = self.target_player(client_id)?;
+                let (_, _, mut position, _, _) = self.character_q.get_mut(player).unwrap();
+                position.0 = target;
+                Ok(format!(
+                    "Teleported to {}, {}",
+                    target.x / PIXEL_METER,
+                    target.y / PIXEL_METER
+                ))
+            }
+            AdminCommand::GodMode => {
+                let player = self.target_player(client_id)?;
+                let (_, _, _, god_mode, _) = self.character_q.get(player).unwrap();
+                if god_mode {
+                    self.commands.entity(player).remove::<GodMode>();
+                    Ok("God mode off".to_string())
+                } else {
+                    self.commands.entity(player).insert(GodMode);
+                    Ok("God mode on".to_string())
+                }
+            }
+            AdminCommand::ReloadData => {
+                let archetypes = EnemyArchetypes::load()?;
+                let spawner_presets = SpawnerPresets::load()?;
+                let message = format!(
+                    "Reloaded {} enemy archetypes and {} spawner presets",
+                    archetypes.0.len(),
+                    spawner_presets.presets.len()
+                );
+                *self.archetypes = archetypes;
+                *self.spawner_presets = spawner_presets;
+                Ok(message)
+            }
+            AdminCommand::Help => Ok(ADMIN_HELP.to_string()),
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn execute_admin_commands(
+    mut admin_command_ev: EventReader<AdminCommandIssued>,
+    mut admin_command_executed_ev: EventWriter<AdminCommandExecuted>,
+    mut send_chat_ev: EventWriter<ServerSendMessage<ChatMessage>>,
+    admin_requests: Option<ResMut<AdminRequests>>,
+    admin_config: Res<AdminConfig>,
+    mut admin_clients: ResMut<AdminClients>,
+    client_usernames: Res<ClientUsernames>,
+    mut admin_world: AdminWorld,
+) {
+    for ev in admin_command_ev.read() {
+        let username = client_usernames
+            .0
+            .get(&ev.client_id)
+            .map_or("unknown", String::as_str);
+        let result = match parse_admin_command(&ev.command) {
+            // The token is not logged
+            Ok(AdminCommand::Login(token)) => {
+                if admin_config.is_valid_token(&token) {
+                    info!(
+                        "[execute_admin_commands] {:?} ({}) logged in as admin",
+                        ev.client_id, username
+                    );
+                    admin_clients.0.insert(ev.client_id);
+                    Ok("Logged in as admin".to_string())
+                } else {
+                    warn!(
+                        "[execute_admin_commands] {:?} ({}) gave an invalid admin token",
+                        ev.client_id, username
+                    );
+                    Err("Invalid admin token".to_string())
+                }
+            }
+            _ if !admin_clients.0.contains(&ev.client_id) => {
+                Err("You are not allowed to use admin commands, see /admin login".to_string())
+            }
+            command => {
+                let result =
+                    command.and_then(|command| admin_world.execute(command, Some(ev.client_id)));
+                info!(
+                    "[execute_admin_commands] {:?} ({}) ran `{}`: {:?}",
+                    ev.client_id, username, ev.command, result
+                );
+                if result.is_ok() {
+                    admin_command_executed_ev.send(AdminCommandExecuted {
+                        client_id: Some(ev.client_id),
+                        command: ev.command.clone(),
+                    });
+                }
+                result
+            }
+        };
+
+        let (Ok(text) | Err(text)) = result;
+        send_chat_ev.send(ServerSendMessage::new_with_target::<ChatChannel>(
+            ChatMessage::system(text),
+            NetworkTarget::Single(ev.client_id),
+        ));
+    }
+
+    let Some(mut admin_requests) = admin_requests else {
+        return;
+    };
+    while let Ok(request) = admin_requests.0.try_recv() {
+        let result = admin_world.execute_text(&request.command, request.client_id);
+        info!(
+            "[execute_admin_commands] HTTP API ran `{}`: {:?}",
+            request.command, result
+        );
+        if result.is_ok() {
+            admin_command_executed_ev.send(AdminCommandExecuted {
+                client_id: request.client_id,
+                command: request.command.clone(),
+            });
+        }
+        // The HTTP request may have been dropped in the meantime
+        let _ = request.reply_tx.send(result);
+    }
+}
+
+/// Admin rights end with the connection, a new one has to log in again
+pub(crate) fn forget_admin_clients(
+    mut disconnect_ev: EventReader<DisconnectEvent>,
+    mut admin_clients: ResMut<AdminClients>,
+) {
+    for disconnection in disconnect_ev.read() {
+        admin_clients.0.remove(&disconnection.client_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_spawn_arguments() {
+        let spawn = |count, archetype: &str, position| AdminCommand::SpawnEnemies {
+            count,
+            archetype: archetype.to_string(),
+            position,
+        };
+        assert_eq!(
+            parse_admin_command("spawn 10"),
+            Ok(spawn(10, DEFAULT_ENEMY_ARCHETYPE, None))
+        );
+        assert_eq!(
+            parse_admin_command("spawn 3 brute"),
+            Ok(spawn(3, "brute", None))
+        );
+        assert_eq!(
+            parse_admin_command("spawn 3 1.5 -2"),
+            Ok(spawn(
+                3,
+                DEFAULT_ENEMY_ARCHETYPE,
+                Some(Vec2::new(1.5, -2.) * PIXEL_METER)
+            ))
+        );
+        assert_eq!(
+            parse_admin_command("  spawn   3 brute 1 2 "),
+            Ok(spawn(3, "brute", Some(Vec2::new(1., 2.) * PIXEL_METER)))
+        );
+    }
+
+    #[test]
+    fn parse_rejects_invalid_spawns() {
+        assert!(parse_admin_command("spawn").is_err());
+        assert!(parse_admin_command("spawn ten").is_err());
+        assert!(parse_admin_command("spawn 0").is_err());
+        assert!(parse_admin_command(&format!("spawn {}", MAX_SPAWNED_ENEMIES + 1)).is_err());
+        // Read as a position
+        assert!(parse_admin_command("spawn 3 brute 1").is_err());
+        assert!(parse_admin_command("spawn 3 brute 1 two").is_err());
+        assert!(parse_admin_command("spawn 3 brute 1 2 3").is_err());
+    }
+
+    #[test]
+    fn parse_player_commands() {
+        assert_eq!(
+            parse_admin_command("give rare"),
+            Ok(AdminCommand::GiveItem(ItemRarity::Rare))
+        );
+        assert!(parse_admin_command("give legendary").is_err());
+        assert_eq!(
+            parse_admin_command("health 50"),
+            Ok(AdminCommand::SetHealth(50.))
+        );
+        assert_eq!(
+            parse_admin_command("teleport 2 3"),
+            Ok(AdminCommand::Teleport(Vec2::new(2., 3.) * PIXEL_METER))
+        );
+        assert!(parse_admin_command("teleport 2").is_err());
+        assert_eq!(parse_admin_command("god"), Ok(AdminCommand::GodMode));
+        assert!(parse_admin_command("god mode").is_err());
+    }
+
+    #[test]
+    fn parse_instance_commands() {
+        assert_eq!(parse_admin_command("killall"), Ok(AdminCommand::KillAll));
+        assert!(parse_admin_command("timescale 2").is_err());
+        assert_eq!(parse_admin_command("reload"), Ok(AdminCommand::ReloadData));
+        assert_eq!(parse_admin_command(""), Ok(AdminCommand::Help));
+        assert_eq!(parse_admin_command("help"), Ok(AdminCommand::Help));
+        assert!(parse_admin_command("fly").is_err());
+    }
+
+    #[test]
+    fn parse_login() {
+        assert_eq!(
+            parse_admin_command("login secret"),
+            Ok(AdminCommand::Login("secret".to_string()))
+        );
+        assert!(parse_admin_command("login").is_err());
+    }
+
+    #[test]
+    fn only_the_configured_token_is_valid() {
+        let config = AdminConfig {
+            token: Some("secret".to_string()),
+        };
+        assert!(config.is_valid_token("secret"));
+        assert!(!config.is_valid_token("Secret"));
+        assert!(!config.is_valid_token(""));
+
+        let disabled = AdminConfig { token: None };
+        assert!(!disabled.is_valid_token(""));
+        assert!(!disabled.is_valid_token("secret"));
+    }
+}
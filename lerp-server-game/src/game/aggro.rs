@@ -0,0 +1,144 @@
+// Enemies aggro the player with the most threat: the damage dealt to them, or a small threat for being close.
+// Pulled too far away from their spawn position, they forget their threat and walk back to it.
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use lerp_common_game::prelude::*;
+
+/// In meters, players closer than this to an enemy are added to its threat table
+const AGGRO_RADIUS: f32 = 20.;
+/// In meters from the spawn position, beyond it the enemy leashes back
+const LEASH_RADIUS: f32 = 40.;
+/// In meters from the spawn position, at which a leashing enemy is back
+const LEASH_RETURN_DISTANCE: f32 = 1.;
+/// Threat of a player within the aggro radius, lower than any damage
+const PROXIMITY_THREAT: f32 = 0.1;
+/// Another player takes the aggro over once it has this much more threat than the target
+const AGGRO_SWITCH_RATIO: f32 = 1.1;
+
+/// Server only, the clients only receive the resulting [`Aggro`]
+#[derive(Component, Debug)]
+pub struct ThreatTable {
+    pub spawn_position: Vec2,
+    pub threat: HashMap<Entity, f32>,
+}
+
+impl ThreatTable {
+    pub fn new(spawn_position: Vec2) -> Self {
+        Self {
+            spawn_position,
+            threat: HashMap::default(),
+        }
+    }
+}
+
+/// Damage dealt to an enemy is added to the threat of the player owning the skill
+pub(crate) fn add_threat_on_hit(
+    mut hit_events: EventReader<HitEvent>,
+    source_q: Query<Option<&DamageOnHit>, With<HitSource>>,
+    skill_q: Query<&Parent, With<Skill>>,
+    mut threat_q: Query<(&mut ThreatTable, Option<&Aggro>)>,
+) {
+    for event in hit_events.read() {
+        for event_data in &event.0 {
+            let Ok((mut threat_table, aggro)) = threat_q.get_mut(event_data.target) else {
+                continue;
+            };
+            if matches!(aggro, Some(Aggro::Leash(_))) {
+                continue;
+            }
+            let Ok(attacker) = skill_q.get(event_data.skill) else {
+                continue;
+            };
+            let damage = source_q
+                .get(event_data.source)
+                .ok()
+                .flatten()
+                .map_or(1., |damage_on_hit| damage_on_hit.value);
+            *threat_table.threat.entry(attacker.get()).or_default() += damage;
+        }
+    }
+}
+
+pub(crate) fn update_aggro(
+    mut commands: Commands,
+    spatial_index: Res<SpatialIndex>,
+    player_q: Query<(), (With<Player>, With<Alive>)>,
+    mut enemy_q: Query<
+        (
+            Entity,
+            &Position,
+            &mut ThreatTable,
+            &mut Health,
+            Option<&Aggro>,
+        ),
+        (With<Enemy>, With<Alive>),
+    >,
+) {
+    for (entity, position, mut threat_table, mut health, aggro) in enemy_q.iter_mut() {
+        let threat_table = &mut *threat_table;
+
+        // Leashing enemies ignore the players until they are back, fully healed
+        if let Some(Aggro::Leash(spawn_position)) = aggro {
+            if position.0.distance(*spawn_position) <= LEASH_RETURN_DISTANCE * PIXEL_METER {
+                health.current = health.max;
+                commands.entity(entity).remove::<Aggro>();
+            }
+            continue;
+        }
+        if position.0.distance(threat_table.spawn_position) > LEASH_RADIUS * PIXEL_METER {
+            threat_table.threat.clear();
+            commands
+                .entity(entity)
+                .insert(Aggro::Leash(threat_table.spawn_position));
+            continue;
+        }
+
+        // Forget the dead and disconnected players
+        threat_table
+            .threat
+            .retain(|player, _| player_q.contains(*player));
+        for nearby in spatial_index.query(position.0, AGGRO_RADIUS * PIXEL_METER) {
+            if nearby.team == Team::Player && player_q.contains(nearby.entity) {
+                threat_table
+                    .threat
+                    .entry(nearby.entity)
+                    .or_insert(PROXIMITY_THREAT);
+            }
+        }
+
+        let current = match aggro {
+            Some(Aggro::Target(target)) => threat_table
+                .threat
+                .get(target)
+                .map(|threat| (*target, *threat)),
+            _ => None,
+        };
+        // Ties are broken by entity, so that the choice does not depend on the table order
+        let highest = threat_table
+            .threat
+            .iter()
+            .max_by(|(player_a, threat_a), (player_b, threat_b)| {
+                threat_a.total_cmp(threat_b).then(player_a.cmp(player_b))
+            })
+            .map(|(player, threat)| (*player, *threat));
+
+        let target = match (current, highest) {
+            (Some((current, current_threat)), Some((_, highest_threat)))
+                if highest_threat <= current_threat * AGGRO_SWITCH_RATIO =>
+            {
+                Some(current)
+            }
+            (_, highest) => highest.map(|(player, _)| player),
+        };
+        match target {
+            Some(target) if aggro != Some(&Aggro::Target(target)) => {
+                commands.entity(entity).insert(Aggro::Target(target));
+            }
+            None if aggro.is_some() => {
+                commands.entity(entity).remove::<Aggro>();
+            }
+            _ => {}
+        }
+    }
+}
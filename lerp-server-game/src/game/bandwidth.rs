@@ -0,0 +1,192 @@
+// Replication bandwidth of the server, as measured on its transports, and broken down by component.
+// The breakdown is estimated: every replication interval, each changed component is serialized, with the
+// serialize functions of the protocol and without quantization, and counted once per client it is relevant to,
+// so that the quantization savings can be compared to the measured total. Message headers are not counted.
+use std::time::Duration;
+
+use avian2d::prelude::*;
+use bevy::diagnostic::{DiagnosticPath, DiagnosticsStore};
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use lerp_common_game::prelude::*;
+use lightyear::prelude::server::*;
+use lightyear::prelude::*;
+use lightyear::protocol::serialize::SerializeFns;
+use lightyear::serialize::writer::Writer;
+use lightyear::transport::io::IoDiagnosticsPlugin;
+
+use super::interest::ClientInterest;
+
+/// Period over which the stats are accumulated before being reported
+pub const BANDWIDTH_REPORT_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Serialization of a replicated component, as registered in the protocol and without quantization
+pub trait ReplicatedSerialization: Component + Sized {
+    fn serialize_fns() -> SerializeFns<Self>;
+    fn full_precision_serialize_fns() -> SerializeFns<Self>;
+}
+
+impl ReplicatedSerialization for Position {
+    fn serialize_fns() -> SerializeFns<Self> {
+        position_serialize_fns()
+    }
+
+    fn full_precision_serialize_fns() -> SerializeFns<Self> {
+        SerializeFns::default()
+    }
+}
+
+impl ReplicatedSerialization for LinearVelocity {
+    fn serialize_fns() -> SerializeFns<Self> {
+        linear_velocity_serialize_fns()
+    }
+
+    fn full_precision_serialize_fns() -> SerializeFns<Self> {
+        SerializeFns::default()
+    }
+}
+
+impl ReplicatedSerialization for Health {
+    fn serialize_fns() -> SerializeFns<Self> {
+        SerializeFns::default()
+    }
+
+    fn full_precision_serialize_fns() -> SerializeFns<Self> {
+        SerializeFns::default()
+    }
+}
+
+/// Bytes written by the serialization of a component
+fn encoded_size<C>(serialize_fns: &SerializeFns<C>, component: &C) -> u64 {
+    let mut writer = Writer::default();
+    match (serialize_fns.serialize)(component, &mut writer) {
+        Ok(()) => writer.to_bytes().len() as u64,
+        Err(err) => {
+            error!("[encoded_size] Cannot serialize: {:?}", err);
+            0
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct ComponentBandwidth {
+    pub updates: u64,
+    pub bytes: u64,
+    /// What the same updates would have cost without quantization
+    pub full_precision_bytes: u64,
+}
+
+/// Bytes per second through the transports of the server, all clients and messages included
+#[derive(Default, Debug, Clone, Copy)]
+pub struct MeasuredBandwidth {
+    pub sent: f64,
+    pub received: f64,
+}
+
+#[derive(Resource, Default, Debug)]
+pub struct BandwidthStats {
+    current: HashMap<&'static str, ComponentBandwidth>,
+    /// Stats of the last complete report interval, by component name
+    pub last_report: HashMap<&'static str, ComponentBandwidth>,
+    /// Measured at the last report, None until the transports recorded a measurement
+    pub last_measured: Option<MeasuredBandwidth>,
+}
+
+/// The lightyear server plugins may already record the transport diagnostics
+pub(crate) fn add_transport_diagnostics(app: &mut App) {
+    if !app.is_plugin_added::<IoDiagnosticsPlugin>() {
+        app.add_plugins(IoDiagnosticsPlugin);
+    }
+}
+
+/// Average of a transport diagnostic over its history, recorded in kilobytes per second
+fn measured_bytes_per_second(diagnostics: &DiagnosticsStore, path: &DiagnosticPath) -> Option<f64> {
+    diagnostics
+        .get(path)
+        .and_then(|diagnostic| diagnostic.average())
+        .map(|kilobytes| kilobytes * 1000.)
+}
+
+fn component_name<C>() -> &'static str {
+    let type_name = std::any::type_name::<C>();
+    type_name.rsplit("::").next().unwrap_or(type_name)
+}
+
+pub(crate) fn measure_component_bandwidth<C: ReplicatedSerialization>(
+    client_interest: Res<ClientInterest>,
+    mut stats: ResMut<BandwidthStats>,
+    changed_q: Query<
+        (Entity, &C),
+        (
+            Changed<C>,
+            With<ReplicationTarget>,
+            Without<ReplicateOnce<C>>,
+        ),
+    >,
+) {
+    let serialize_fns = C::serialize_fns();
+    let full_precision_serialize_fns = C::full_precision_serialize_fns();
+    let component = stats.current.entry(component_name::<C>()).or_default();
+
+    for (entity, value) in changed_q.iter() {
+        let updates = client_interest
+            .0
+            .values()
+            .filter(|relevant| relevant.contains(&entity))
+            .count() as u64;
+        if updates == 0 {
+            continue;
+        }
+        component.updates += updates;
+        component.bytes += updates * encoded_size(&serialize_fns, value);
+        component.full_precision_bytes +=
+            updates * encoded_size(&full_precision_serialize_fns, value);
+    }
+}
+
+pub(crate) fn report_bandwidth_stats(
+    diagnostics: Res<DiagnosticsStore>,
+    mut stats: ResMut<BandwidthStats>,
+) {
+    let measured = measured_bytes_per_second(&diagnostics, &IoDiagnosticsPlugin::BYTES_OUT)
+        .zip(measured_bytes_per_second(
+            &diagnostics,
+            &IoDiagnosticsPlugin::BYTES_IN,
+        ))
+        .map(|(sent, received)| MeasuredBandwidth { sent, received });
+    if let Some(measured) = measured {
+        info!(
+            "[report_bandwidth_stats] Measured: {:.0} B/s sent, {:.0} B/s received",
+            measured.sent, measured.received,
+        );
+    }
+    stats.last_measured = measured;
+
+    let seconds = BANDWIDTH_REPORT_INTERVAL.as_secs_f32();
+    for (name, component) in stats.current.iter() {
+        info!(
+            "[report_bandwidth_stats] Estimated {}: {:.0} updates/s, {:.0} B/s (full precision {:.0} B/s)",
+            name,
+            component.updates as f32 / seconds,
+            component.bytes as f32 / seconds,
+            component.full_precision_bytes as f32 / seconds,
+        );
+    }
+    stats.last_report = std::mem::take(&mut stats.current);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quantized_components_are_smaller() {
+        let position = Position::from_xy(1234.5, -678.9);
+        assert_eq!(encoded_size(&Position::serialize_fns(), &position), 4);
+        assert!(encoded_size(&Position::full_precision_serialize_fns(), &position) > 4);
+
+        let velocity = LinearVelocity(Vec2::new(120., -45.5));
+        assert_eq!(encoded_size(&LinearVelocity::serialize_fns(), &velocity), 4);
+        assert!(encoded_size(&LinearVelocity::full_precision_serialize_fns(), &velocity) > 4);
+    }
+}
@@ -0,0 +1,244 @@
+// Relay chat messages between the clients that joined the instance.
+// Messages are length and rate limited, text starting with `/` is parsed as a command.
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use lerp_common_game::prelude::*;
+use lightyear::prelude::server::*;
+use lightyear::prelude::*;
+
+use super::admin::AdminCommandIssued;
+use super::join::{ClientUsernames, PlayerJoined, Spectators};
+use super::party::Parties;
+use super::ClientPlayerMap;
+
+const CHAT_HELP: &str = "Commands: /say <text>, /p <text>, /me <text>, /who, /help";
+
+/// Send time of the messages each client sent during the last [`CHAT_RATE_LIMIT_PERIOD`]
+#[derive(Resource, Default)]
+pub struct ChatRateLimits(HashMap<ClientId, VecDeque<Duration>>);
+
+enum ChatCommand<'a> {
+    Say(&'a str),
+    Party(&'a str),
+    Emote(&'a str),
+    Who,
+    Help,
+    Admin(&'a str),
+    Unknown(&'a str),
+}
+
+fn parse_chat_command(text: &str) -> ChatCommand<'_> {
+    let Some(command) = text.strip_prefix('/') else {
+        return ChatCommand::Say(text);
+    };
+    let (name, args) = command.split_once(' ').unwrap_or((command, ""));
+    let args = args.trim();
+
+    match name {
+        "s" | "say" => ChatCommand::Say(args),
+        "p" | "party" => ChatCommand::Party(args),
+        "me" | "emote" => ChatCommand::Emote(args),
+        "who" => ChatCommand::Who,
+        "help" => ChatCommand::Help,
+        "admin" => ChatCommand::Admin(args),
+        name => ChatCommand::Unknown(name),
+    }
+}
+
+fn joined_clients(client_player_map: &ClientPlayerMap, spectators: &Spectators) -> Vec<ClientId> {
+    client_player_map
+        .0
+        .keys()
+        .chain(spectators.0.iter())
+        .copied()
+        .collect()
+}
+
+fn send_chat(
+    send_chat_ev: &mut EventWriter<ServerSendMessage<ChatMessage>>,
+    message: ChatMessage,
+    target: NetworkTarget,
+) {
+    send_chat_ev.send(ServerSendMessage::new_with_target::<ChatChannel>(
+        message, target,
+    ));
+}
+
+/// Joined clients whose user is a member of the sender's party, none if the sender is not in a party
+fn party_clients(
+    client_id: ClientId,
+    joined: &[ClientId],
+    client_usernames: &ClientUsernames,
+    parties: &Parties,
+) -> Option<Vec<ClientId>> {
+    let username = client_usernames.0.get(&client_id)?;
+    let party = parties.0.get_by_member(username)?;
+    Some(
+        joined
+            .iter()
+            .copied()
+            .filter(|joined_client| {
+                client_usernames
+                    .0
+                    .get(joined_client)
+                    .is_some_and(|username| party.members.contains(username))
+            })
+            .collect(),
+    )
+}
+
+/// Whether the client can send one more message, and record it if so
+fn check_rate_limit(sent: &mut VecDeque<Duration>, now: Duration) -> bool {
+    while sent
+        .front()
+        .is_some_and(|sent_at| now.saturating_sub(*sent_at) > CHAT_RATE_LIMIT_PERIOD)
+    {
+        sent.pop_front();
+    }
+    if sent.len() >= CHAT_RATE_LIMIT_MESSAGES {
+        return false;
+    }
+    sent.push_back(now);
+    true
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn handle_chat_messages(
+    time: Res<Time>,
+    mut chat_ev: EventReader<ServerReceiveMessage<SendChat>>,
+    mut send_chat_ev: EventWriter<ServerSendMessage<ChatMessage>>,
+    mut admin_command_ev: EventWriter<AdminCommandIssued>,
+    mut rate_limits: ResMut<ChatRateLimits>,
+    client_player_map: Res<ClientPlayerMap>,
+    spectators: Res<Spectators>,
+    client_usernames: Res<ClientUsernames>,
+    parties: Res<Parties>,
+) {
+    let joined = joined_clients(&client_player_map, &spectators);
+
+    for ev in chat_ev.read() {
+        let client_id = ev.from;
+        if !joined.contains(&client_id) {
+            continue;
+        }
+        let reply = |send_chat_ev: &mut EventWriter<ServerSendMessage<ChatMessage>>,
+                     text: String| {
+            send_chat(
+                send_chat_ev,
+                ChatMessage::system(text),
+                NetworkTarget::Single(client_id),
+            );
+        };
+
+        let text = ev.message.text.trim();
+        if text.is_empty() {
+            continue;
+        }
+        if text.chars().count() > CHAT_MESSAGE_MAX_LEN {
+            reply(
+                &mut send_chat_ev,
+                format!("Message too long, max {} characters", CHAT_MESSAGE_MAX_LEN),
+            );
+            continue;
+        }
+        let sent = rate_limits.0.entry(client_id).or_default();
+        if !check_rate_limit(sent, time.elapsed()) {
+            reply(
+                &mut send_chat_ev,
+                "You are sending messages too fast".to_string(),
+            );
+            continue;
+        }
+
+        let (kind, text, recipients) = match parse_chat_command(text) {
+            ChatCommand::Say(text) => (ChatKind::Say, text, joined.clone()),
+            ChatCommand::Emote(text) => (ChatKind::Emote, text, joined.clone()),
+            ChatCommand::Party(text) => {
+                let Some(members) = party_clients(client_id, &joined, &client_usernames, &parties)
+                else {
+                    reply(&mut send_chat_ev, "You are not in a party".to_string());
+                    continue;
+                };
+                (ChatKind::Party, text, members)
+            }
+            ChatCommand::Who => {
+                let names: Vec<String> = joined
+                    .iter()
+                    .map(|client_id| client_usernames.name(*client_id))
+                    .collect();
+                reply(&mut send_chat_ev, format!("In game: {}", names.join(", ")));
+                continue;
+            }
+            ChatCommand::Help => {
+                reply(&mut send_chat_ev, CHAT_HELP.to_string());
+                continue;
+            }
+            // Answered by the admin systems
+            ChatCommand::Admin(command) => {
+                admin_command_ev.send(AdminCommandIssued {
+                    client_id,
+                    command: command.to_string(),
+                });
+                continue;
+            }
+            ChatCommand::Unknown(name) => {
+                reply(
+                    &mut send_chat_ev,
+                    format!("Unknown command /{}, see /help", name),
+                );
+                continue;
+            }
+        };
+        if text.is_empty() {
+            continue;
+        }
+
+        send_chat(
+            &mut send_chat_ev,
+            ChatMessage {
+                kind,
+                sender: Some(client_usernames.name(client_id)),
+                text: text.to_string(),
+            },
+            NetworkTarget::Only(recipients),
+        );
+    }
+}
+
+/// System messages for the players joining and the clients leaving.
+/// Runs before the leaving clients are forgotten, to announce them with their username
+pub(crate) fn announce_joins_and_leaves(
+    mut player_joined_ev: EventReader<PlayerJoined>,
+    mut disconnect_ev: EventReader<DisconnectEvent>,
+    mut send_chat_ev: EventWriter<ServerSendMessage<ChatMessage>>,
+    mut rate_limits: ResMut<ChatRateLimits>,
+    client_player_map: Res<ClientPlayerMap>,
+    spectators: Res<Spectators>,
+    client_usernames: Res<ClientUsernames>,
+) {
+    let left: Vec<ClientId> = disconnect_ev
+        .read()
+        .map(|disconnection| disconnection.client_id)
+        .collect();
+    let mut joined = joined_clients(&client_player_map, &spectators);
+    joined.retain(|client_id| !left.contains(client_id));
+
+    for PlayerJoined(client_id) in player_joined_ev.read() {
+        send_chat(
+            &mut send_chat_ev,
+            ChatMessage::system(format!("{} joined", client_usernames.name(*client_id))),
+            NetworkTarget::Only(joined.clone()),
+        );
+    }
+    for client_id in left {
+        rate_limits.0.remove(&client_id);
+        send_chat(
+            &mut send_chat_ev,
+            ChatMessage::system(format!("{} left", client_usernames.name(client_id))),
+            NetworkTarget::Only(joined.clone()),
+        );
+    }
+}
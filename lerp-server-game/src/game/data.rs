@@ -0,0 +1,24 @@
+// Game data files, embedded in the binary and optionally read from a directory to be tuned without a rebuild.
+use std::path::PathBuf;
+
+use serde::de::DeserializeOwned;
+
+/// When set, data files are read from this directory instead of the ones embedded in the binary
+pub const DATA_DIR_ENV: &str = "LERP_DATA_DIR";
+
+/// Read the data file from [`DATA_DIR_ENV`], or use its embedded content when it is not set
+pub(crate) fn load_data_file<T: DeserializeOwned>(
+    file_name: &str,
+    embedded: &str,
+) -> Result<T, String> {
+    let content = match std::env::var(DATA_DIR_ENV) {
+        Ok(data_dir) => {
+            let path = PathBuf::from(data_dir).join(file_name);
+            std::fs::read_to_string(&path)
+                .map_err(|err| format!("Cannot read {}: {}", path.display(), err))?
+        }
+        Err(_) => embedded.to_string(),
+    };
+
+    serde_json::from_str(&content).map_err(|err| format!("Invalid {}: {}", file_name, err))
+}
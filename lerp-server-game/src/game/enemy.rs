@@ -0,0 +1,195 @@
+// Enemy archetypes are read from a data file so that they can be tuned and reloaded without a rebuild.
+use std::collections::VecDeque;
+
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use bevy::utils::{HashMap, HashSet};
+use lerp_common_game::prelude::*;
+use lightyear::prelude::server::*;
+use lightyear::prelude::*;
+use serde::Deserialize;
+
+use super::aggro::ThreatTable;
+use super::data::load_data_file;
+use super::leap::Leap;
+
+const ENEMY_ARCHETYPES_FILE: &str = "enemy_archetypes.json";
+const EMBEDDED_ENEMY_ARCHETYPES: &str = include_str!("../../data/enemy_archetypes.json");
+
+/// Name of the archetype used when none is given
+pub const DEFAULT_ENEMY_ARCHETYPE: &str = "enemy";
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct EnemyArchetype {
+    pub health: f32,
+    /// In meters per second
+    pub movement_speed: f32,
+    /// In meters, ranged enemies keep this path distance from the players instead of chasing them
+    #[serde(default)]
+    pub preferred_distance: Option<f32>,
+    /// In meters, the enemy leaps on its target once it is this close
+    #[serde(default)]
+    pub leap_distance: Option<f32>,
+}
+
+#[derive(Resource, Clone, Debug)]
+pub struct EnemyArchetypes(pub HashMap<String, EnemyArchetype>);
+
+impl EnemyArchetypes {
+    pub fn load() -> Result<Self, String> {
+        load_data_file(ENEMY_ARCHETYPES_FILE, EMBEDDED_ENEMY_ARCHETYPES).map(Self)
+    }
+}
+
+impl Default for EnemyArchetypes {
+    fn default() -> Self {
+        Self::load().unwrap_or_else(|err| {
+            error!("[EnemyArchetypes] {}, using the embedded archetypes", err);
+            Self(serde_json::from_str(EMBEDDED_ENEMY_ARCHETYPES).unwrap())
+        })
+    }
+}
+
+/// Spawn a replicated enemy with the stats of the given archetype
+pub(crate) fn spawn_enemy(
+    commands: &mut Commands,
+    position: Vec2,
+    archetype: &EnemyArchetype,
+) -> Entity {
+    let mut enemy = commands.spawn(EnemyBundle::new(&position));
+    if let Some(preferred_distance) = archetype.preferred_distance {
+        enemy.insert(PreferredDistance(preferred_distance * PIXEL_METER));
+    }
+    if let Some(leap_distance) = archetype.leap_distance {
+        enemy.insert(Leap::new(leap_distance * PIXEL_METER));
+    }
+    enemy
+        .insert((
+            Health::new(archetype.health),
+            MovementSpeed(archetype.movement_speed * PIXEL_METER),
+            ThreatTable::new(position),
+            Replicate {
+                sync: SyncTarget {
+                    prediction: NetworkTarget::All,
+                    interpolation: NetworkTarget::None,
+                },
+                target: ReplicationTarget {
+                    target: NetworkTarget::All,
+                },
+                group: REPLICATION_GROUP,
+                ..default()
+            },
+        ))
+        .id()
+}
+
+/// In nav tiles, farthest a pack spreads from its origin
+const MAX_PACK_RADIUS: u32 = 12;
+
+/// Spawn one enemy per walkable nav tile, the closest tiles to the origin first
+pub(crate) fn spawn_enemy_pack(
+    commands: &mut Commands,
+    map: &Map,
+    origin: Vec2,
+    count: u32,
+    archetype: &EnemyArchetype,
+) -> Vec<Entity> {
+    pack_positions(map, origin, count)
+        .into_iter()
+        .map(|position| spawn_enemy(commands, position, archetype))
+        .collect()
+}
+
+/// Centers of the walkable ground tiles reached by a breadth first search from the origin,
+/// cycling over them when the pack is larger than the room around the origin
+fn pack_positions(map: &Map, origin: Vec2, count: u32) -> Vec<Vec2> {
+    let start = map.position_to_nav_map_tile_coord(&Position(origin));
+    let is_free = |coord: &NavTileCoord| {
+        map.nav_map
+            .get(coord)
+            .is_some_and(|nav_tile| nav_tile.walkable && nav_tile.terrain.damage_per_second() == 0.)
+    };
+
+    let mut tiles = vec![];
+    let mut visited = HashSet::new();
+    let mut queue = VecDeque::from([start]);
+    visited.insert(start);
+    while let Some(coord) = queue.pop_front() {
+        if tiles.len() as u32 >= count {
+            break;
+        }
+        // The origin may be in a wall, its walkable neighbors are still searched
+        if is_free(&coord) {
+            tiles.push(coord);
+        } else if coord != start {
+            continue;
+        }
+        for offset in [IVec2::X, IVec2::NEG_X, IVec2::Y, IVec2::NEG_Y] {
+            let neighbor = coord.as_ivec2() + offset;
+            if neighbor.cmplt(IVec2::ZERO).any()
+                || (neighbor - start.as_ivec2()).abs().max_element() > MAX_PACK_RADIUS as i32
+            {
+                continue;
+            }
+            let neighbor = NavTileCoord(neighbor.as_uvec2());
+            if visited.insert(neighbor) {
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    if tiles.is_empty() {
+        return vec![origin; count as usize];
+    }
+    (0..count as usize)
+        .map(|i| map.nav_map_tile_coord_to_position(tiles[i % tiles.len()]))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Ground map with a wall on the nav tiles of the given column
+    fn map_with_wall_column(wall_x: u32) -> Map {
+        let mut map = Map::default();
+        map.reset(UVec2::new(10, 10));
+        for x in 0..map.nav_map_size.x {
+            for y in 0..map.nav_map_size.y {
+                map.nav_map.insert(
+                    NavTileCoord(UVec2::new(x, y)),
+                    NavTile {
+                        walkable: x != wall_x,
+                        terrain: TerrainKind::Ground,
+                    },
+                );
+            }
+        }
+        map
+    }
+
+    #[test]
+    fn packs_spawn_on_distinct_walkable_tiles() {
+        let map = map_with_wall_column(21);
+        let origin = map.nav_map_tile_coord_to_position(NavTileCoord(UVec2::new(20, 20)));
+
+        let positions = pack_positions(&map, origin, 25);
+        assert_eq!(positions.len(), 25);
+        for (i, position) in positions.iter().enumerate() {
+            let coord = map.position_to_nav_map_tile_coord(&Position(*position));
+            assert_ne!(coord.x, 21, "enemy spawned in the wall");
+            assert!(positions[..i].iter().all(|other| other != position));
+        }
+    }
+
+    #[test]
+    fn packs_in_a_wall_spawn_next_to_it() {
+        let map = map_with_wall_column(21);
+        let origin = map.nav_map_tile_coord_to_position(NavTileCoord(UVec2::new(21, 20)));
+
+        for position in pack_positions(&map, origin, 4) {
+            let coord = map.position_to_nav_map_tile_coord(&Position(position));
+            assert_ne!(coord.x, 21, "enemy spawned in the wall");
+        }
+    }
+}
@@ -0,0 +1,246 @@
+// Area of interest: world entities are only replicated to the players close enough to them.
+// Entities become relevant to a client inside `radius` around its player and stop being relevant
+// outside `radius + hysteresis`, so that entities moving around the edge do not flicker.
+// Party members are always relevant to each other, for the party HUD.
+// The entities are put in a grid every tick, so that each player only goes through the cells
+// around it instead of every entity, see `benches/interest.rs`.
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use bevy::utils::{HashMap, HashSet};
+use lerp_common_game::prelude::*;
+use lightyear::prelude::server::*;
+use lightyear::prelude::*;
+
+use super::join::Spectators;
+use super::ClientPlayerMap;
+
+/// Override the interest radius, in meters, read when the instance starts
+pub const INTEREST_RADIUS_ENV: &str = "LERP_INTEREST_RADIUS";
+/// In meters
+const DEFAULT_INTEREST_RADIUS: f32 = 40.;
+
+#[derive(Resource, Clone, Debug)]
+pub struct InterestConfig {
+    /// Distance in pixels under which an entity becomes relevant to a player
+    pub radius: f32,
+    /// Extra distance in pixels before a relevant entity stops being relevant
+    pub hysteresis: f32,
+}
+
+impl InterestConfig {
+    /// Radius in meters
+    pub fn with_radius(radius: f32) -> Self {
+        Self {
+            radius: radius * PIXEL_METER,
+            hysteresis: 5. * PIXEL_METER,
+        }
+    }
+}
+
+impl Default for InterestConfig {
+    fn default() -> Self {
+        Self::with_radius(DEFAULT_INTEREST_RADIUS)
+    }
+}
+
+/// Entities currently relevant to each client
+#[derive(Resource, Default)]
+pub struct ClientInterest(pub HashMap<ClientId, HashSet<Entity>>);
+
+/// Uniform grid of the entities under interest management, with cells half the leave radius wide
+#[derive(Default)]
+pub struct InterestGrid {
+    cell_size: f32,
+    /// Cells are never removed, so that their allocations are reused by the next rebuild
+    cells: HashMap<IVec2, Vec<(Entity, Vec2)>>,
+}
+
+impl InterestGrid {
+    fn cell(&self, position: Vec2) -> IVec2 {
+        (position / self.cell_size).floor().as_ivec2()
+    }
+
+    /// Replace the entries, for queries up to the given radius
+    pub fn rebuild(&mut self, radius: f32, entries: impl Iterator<Item = (Entity, Vec2)>) {
+        let cell_size = (radius / 2.).max(1.);
+        if cell_size != self.cell_size {
+            self.cell_size = cell_size;
+            self.cells.clear();
+        }
+        for cell in self.cells.values_mut() {
+            cell.clear();
+        }
+        for (entity, position) in entries {
+            let cell = self.cell(position);
+            self.cells.entry(cell).or_default().push((entity, position));
+        }
+    }
+
+    /// Entities within the radius of the position
+    pub fn query(&self, position: Vec2, radius: f32) -> impl Iterator<Item = Entity> + '_ {
+        let min = self.cell(position - radius);
+        let max = self.cell(position + radius);
+        (min.y..=max.y)
+            .flat_map(move |y| (min.x..=max.x).map(move |x| IVec2::new(x, y)))
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .filter(move |(_, entry)| entry.distance_squared(position) <= radius * radius)
+            .map(|(entity, _)| *entity)
+    }
+}
+
+/// Position used for the interest of an entity, dropped items keep theirs in `ItemDropped`
+fn interest_position(
+    position: Option<&Position>,
+    item_dropped: Option<&ItemDropped>,
+) -> Option<Vec2> {
+    position
+        .map(|position| position.0)
+        .or(item_dropped.map(|item_dropped| item_dropped.position))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn update_client_interest(
+    config: Res<InterestConfig>,
+    client_player_map: Res<ClientPlayerMap>,
+    spectators: Res<Spectators>,
+    mut client_interest: ResMut<ClientInterest>,
+    mut relevance_manager: ResMut<RelevanceManager>,
+    mut grid: Local<InterestGrid>,
+    mut parties: Local<HashMap<PartyMember, Vec<Entity>>>,
+    player_q: Query<(&Position, Option<&PartyMember>), With<Player>>,
+    entity_q: Query<
+        (
+            Entity,
+            &NetworkRelevanceMode,
+            Option<&Position>,
+            Option<&ItemDropped>,
+            Option<&PartyMember>,
+        ),
+        With<ReplicationTarget>,
+    >,
+) {
+    // Forget clients that left, lightyear already dropped their relevance
+    client_interest.0.retain(|client_id, _| {
+        client_player_map.0.contains_key(client_id) || spectators.0.contains(client_id)
+    });
+
+    let leave_radius = config.radius + config.hysteresis;
+
+    parties.clear();
+    grid.rebuild(
+        leave_radius,
+        entity_q
+            .iter()
+            .filter(|(_, relevance_mode, ..)| {
+                matches!(relevance_mode, NetworkRelevanceMode::InterestManagement)
+            })
+            .filter_map(|(entity, _, position, item_dropped, party)| {
+                let position = interest_position(position, item_dropped)?;
+                if let Some(party) = party {
+                    parties.entry(*party).or_default().push(entity);
+                }
+                Some((entity, position))
+            }),
+    );
+
+    for (client_id, player_entity) in client_player_map.0.iter() {
+        let Ok((player_position, player_party)) = player_q.get(*player_entity) else {
+            continue;
+        };
+        let relevant = client_interest.0.entry(*client_id).or_default();
+        let party_entities = player_party
+            .and_then(|party| parties.get(party))
+            .map_or(&[][..], Vec::as_slice);
+
+        // Entities past the leave radius, and the despawned ones
+        relevant.retain(|entity| {
+            let Ok((_, relevance_mode, position, item_dropped, _)) = entity_q.get(*entity) else {
+                return false;
+            };
+            if !matches!(relevance_mode, NetworkRelevanceMode::InterestManagement) {
+                return true;
+            }
+            let Some(entity_position) = interest_position(position, item_dropped) else {
+                return true;
+            };
+            if party_entities.contains(entity)
+                || player_position.0.distance(entity_position) <= leave_radius
+            {
+                return true;
+            }
+            relevance_manager.lose_relevance(*client_id, *entity);
+            false
+        });
+
+        for entity in grid
+            .query(player_position.0, config.radius)
+            .chain(party_entities.iter().copied())
+        {
+            if relevant.insert(entity) {
+                relevance_manager.gain_relevance(*client_id, entity);
+            }
+        }
+    }
+
+    // Spectators roam freely, everything is relevant to them
+    for client_id in spectators.0.iter() {
+        let relevant = client_interest.0.entry(*client_id).or_default();
+        for (entity, relevance_mode, _, _, _) in entity_q.iter() {
+            if matches!(relevance_mode, NetworkRelevanceMode::InterestManagement)
+                && relevant.insert(entity)
+            {
+                relevance_manager.gain_relevance(*client_id, entity);
+            }
+        }
+        relevant.retain(|entity| entity_q.contains(*entity));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_query_matches_distance() {
+        let radius = 45. * PIXEL_METER;
+        let entries: Vec<(Entity, Vec2)> = (0..500)
+            .map(|i| {
+                let position = Vec2::new((i * 37 % 101) as f32, (i * 61 % 97) as f32 - 48.);
+                (Entity::from_raw(i), position * PIXEL_METER * 2.)
+            })
+            .collect();
+        let mut grid = InterestGrid::default();
+        grid.rebuild(radius, entries.iter().copied());
+
+        for center in [Vec2::ZERO, Vec2::new(100., 20.), Vec2::new(-30., 75.)] {
+            let center = center * PIXEL_METER;
+            let mut found: Vec<Entity> = grid.query(center, radius).collect();
+            let mut expected: Vec<Entity> = entries
+                .iter()
+                .filter(|(_, position)| position.distance(center) <= radius)
+                .map(|(entity, _)| *entity)
+                .collect();
+            found.sort();
+            expected.sort();
+            assert!(!expected.is_empty());
+            assert_eq!(found, expected);
+        }
+    }
+
+    #[test]
+    fn grid_rebuild_replaces_entries() {
+        let mut grid = InterestGrid::default();
+        grid.rebuild(10., [(Entity::from_raw(0), Vec2::ZERO)].into_iter());
+        grid.rebuild(
+            10.,
+            [(Entity::from_raw(1), Vec2::new(100., 0.))].into_iter(),
+        );
+
+        assert_eq!(grid.query(Vec2::ZERO, 10.).count(), 0);
+        assert_eq!(
+            grid.query(Vec2::new(100., 0.), 10.).collect::<Vec<_>>(),
+            vec![Entity::from_raw(1)]
+        );
+    }
+}
@@ -7,9 +7,12 @@ use lightyear::prelude::*;
 use rand_core::RngCore;
 use lerp_common_game::prelude::*;
 
+use super::party::nearest_party;
+
 pub(crate) fn generate_item_dropped_on_death(
     mut commands: Commands,
     dead_enemy_q: Query<&Position, (Added<Dead>, With<Enemy>)>,
+    player_q: Query<(&Position, Option<&PartyMember>), With<Player>>,
     mut rng: GlobalEntropy<WyRand>,
 ) {
     for position in dead_enemy_q.iter() {
@@ -30,6 +33,7 @@ pub(crate) fn generate_item_dropped_on_death(
             ItemDropped {
                 position: position.0,
                 ratity,
+                reserved_for: nearest_party(position.0, &player_q),
             },
             Replicate {
                 target: ReplicationTarget {
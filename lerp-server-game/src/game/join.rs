@@ -0,0 +1,139 @@
+// Clients are connected but receive nothing until they tell how they join the game.
+// Players get a predicted player entity, spectators get the interpolated world and own nothing.
+use bevy::prelude::*;
+use bevy::utils::{HashMap, HashSet};
+use lerp_common_game::prelude::*;
+use lightyear::prelude::server::*;
+use lightyear::prelude::*;
+
+use super::party::InstanceParty;
+use super::{spawn_player, ClientPlayerMap};
+
+/// Clients connected with [`JoinMode::Spectator`]
+#[derive(Resource, Default)]
+pub struct Spectators(pub HashSet<ClientId>);
+
+/// Username each joined client declared in [`JoinGame`]
+#[derive(Resource, Default)]
+pub struct ClientUsernames(pub HashMap<ClientId, String>);
+impl ClientUsernames {
+    /// Name displayed for a client in the chat, its id until it joined
+    pub fn name(&self, client_id: ClientId) -> String {
+        self.0
+            .get(&client_id)
+            .cloned()
+            .unwrap_or_else(|| format!("{:?}", client_id))
+    }
+}
+
+/// A client joined as player and its player entity was spawned
+#[derive(Event)]
+pub struct PlayerJoined(pub ClientId);
+
+/// A player disconnected, its player entity is despawned with the client session
+#[derive(Event)]
+pub struct PlayerLeft(pub ClientId);
+
+pub(crate) fn handle_join_game(
+    mut commands: Commands,
+    mut join_game_ev: EventReader<ServerReceiveMessage<JoinGame>>,
+    mut player_joined_ev: EventWriter<PlayerJoined>,
+    mut client_player_map: ResMut<ClientPlayerMap>,
+    mut spectators: ResMut<Spectators>,
+    mut client_usernames: ResMut<ClientUsernames>,
+    instance_party: Res<InstanceParty>,
+    map: Res<Map>,
+) {
+    for ev in join_game_ev.read() {
+        let client_id = ev.from;
+        if client_player_map.0.contains_key(&client_id) || spectators.0.contains(&client_id) {
+            warn!("[handle_join_game] Client {:?} already joined", client_id);
+            continue;
+        }
+
+        info!(
+            "[handle_join_game] Client {:?} joined as {:?}",
+            client_id, ev.message.mode
+        );
+        client_usernames
+            .0
+            .insert(client_id, ev.message.username.clone());
+        match ev.message.mode {
+            JoinMode::Player => {
+                let player = spawn_player(&mut commands, &mut client_player_map, &map, client_id);
+                if let Some(party) = instance_party.party_of(&ev.message.username) {
+                    commands.entity(player).insert(party);
+                }
+                player_joined_ev.send(PlayerJoined(client_id));
+            }
+            JoinMode::Spectator => {
+                spectators.0.insert(client_id);
+            }
+        }
+    }
+}
+
+/// The player entity is despawned by lightyear with the client session.
+/// Forgetting the client lets it join again, when it reconnects to apply new network conditions.
+pub(crate) fn handle_disconnections(
+    mut disconnections: EventReader<DisconnectEvent>,
+    mut player_left_ev: EventWriter<PlayerLeft>,
+    mut client_player_map: ResMut<ClientPlayerMap>,
+    mut spectators: ResMut<Spectators>,
+    mut client_usernames: ResMut<ClientUsernames>,
+) {
+    for disconnection in disconnections.read() {
+        info!("Client disconnected {:?}", disconnection.client_id);
+        if client_player_map
+            .0
+            .remove(&disconnection.client_id)
+            .is_some()
+        {
+            player_left_ev.send(PlayerLeft(disconnection.client_id));
+        }
+        spectators.0.remove(&disconnection.client_id);
+        client_usernames.0.remove(&disconnection.client_id);
+    }
+}
+
+/// Entities are spawned replicated to [`NetworkTarget::All`] and predicted by all.
+///
+/// Restrict them to the clients that joined, predicted by players and interpolated by spectators,
+/// and let the area of interest decide to which of these clients they are relevant.
+/// Entities replicated to a single client (like [`PlayerClient`]) are left untouched.
+pub(crate) fn update_world_replication_targets(
+    client_player_map: Res<ClientPlayerMap>,
+    spectators: Res<Spectators>,
+    mut replicated_q: Query<(
+        &mut ReplicationTarget,
+        &mut SyncTarget,
+        &mut NetworkRelevanceMode,
+    )>,
+) {
+    let joined_changed = client_player_map.is_changed() || spectators.is_changed();
+
+    let players: Vec<ClientId> = client_player_map.0.keys().copied().collect();
+    let spectators: Vec<ClientId> = spectators.0.iter().copied().collect();
+    let joined: Vec<ClientId> = players.iter().chain(spectators.iter()).copied().collect();
+
+    for (mut target, mut sync, mut relevance_mode) in replicated_q.iter_mut() {
+        if !joined_changed && !target.is_added() && !sync.is_added() {
+            continue;
+        }
+        if matches!(
+            target.target,
+            NetworkTarget::Single(_) | NetworkTarget::None
+        ) {
+            continue;
+        }
+
+        target.target = NetworkTarget::Only(joined.clone());
+        if target.is_added() {
+            *relevance_mode = NetworkRelevanceMode::InterestManagement;
+        }
+        if matches!(sync.prediction, NetworkTarget::All | NetworkTarget::Only(_)) {
+            sync.prediction = NetworkTarget::Only(players.clone());
+            sync.interpolation = NetworkTarget::Only(spectators.clone());
+        }
+    }
+}
@@ -0,0 +1,86 @@
+// Enemies with a leap jump on their aggro target once it is in range, then wait for the cooldown.
+// Decided by the server only, the clients predict the replicated ForcedMovement.
+use std::time::Duration;
+
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use lerp_common_game::prelude::*;
+
+const LEAP_COOLDOWN: Duration = Duration::from_millis(4000);
+
+/// Server only, see [`super::enemy::EnemyArchetype::leap_distance`]
+#[derive(Component)]
+pub struct Leap {
+    /// In pixels
+    pub distance: f32,
+    pub cooldown: Timer,
+}
+
+impl Leap {
+    pub fn new(distance: f32) -> Self {
+        Self {
+            distance,
+            cooldown: Timer::new(LEAP_COOLDOWN, TimerMode::Once),
+        }
+    }
+}
+
+/// Length of the leap landing next to the target, None when the target is too close or out of range
+fn leap_length(from: Vec2, to: Vec2, max_distance: f32) -> Option<f32> {
+    let distance = from.distance(to) - ENEMY_SIZE;
+    (distance > 0. && distance <= max_distance).then_some(distance)
+}
+
+pub(crate) fn enemy_leap(
+    time: Res<Time<Fixed>>,
+    mut commands: Commands,
+    target_q: Query<&Position>,
+    mut enemy_q: Query<
+        (Entity, &Position, &Aggro, &mut Leap),
+        (With<Enemy>, With<Alive>, Without<ForcedMovement>),
+    >,
+) {
+    for (entity, position, aggro, mut leap) in enemy_q.iter_mut() {
+        leap.cooldown.tick(time.delta());
+        if !leap.cooldown.finished() {
+            continue;
+        }
+        let Aggro::Target(target) = aggro else {
+            continue;
+        };
+        let Ok(target_position) = target_q.get(*target) else {
+            continue;
+        };
+
+        // Land next to the target rather than on it
+        let Some(distance) = leap_length(position.0, target_position.0, leap.distance) else {
+            continue;
+        };
+        commands.entity(entity).insert(ForcedMovement::new(
+            ForcedMovementKind::Leap,
+            target_position.0 - position.0,
+            distance,
+            LEAP_SPEED,
+        ));
+        leap.cooldown.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leap_lands_next_to_targets_in_range() {
+        let max_distance = 4. * PIXEL_METER;
+        let leap = |x: f32| leap_length(Vec2::ZERO, Vec2::new(x, 0.), max_distance);
+
+        assert_eq!(leap(ENEMY_SIZE + PIXEL_METER), Some(PIXEL_METER));
+        assert_eq!(leap(ENEMY_SIZE + max_distance), Some(max_distance));
+        // Already in contact
+        assert_eq!(leap(ENEMY_SIZE), None);
+        assert_eq!(leap(ENEMY_SIZE / 2.), None);
+        // Out of range
+        assert_eq!(leap(ENEMY_SIZE + max_distance + 1.), None);
+    }
+}
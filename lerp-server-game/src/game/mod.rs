@@ -1,21 +1,52 @@
+use admin::*;
+use aggro::*;
+use avian2d::prelude::*;
+use bandwidth::*;
 use bevy::prelude::*;
+use chat::*;
 use bevy::state::app::StatesPlugin;
 use bevy::time::common_conditions::on_timer;
 use bevy::utils::HashMap;
 use bevy_rand::plugin::EntropyPlugin;
 use bevy_rand::prelude::WyRand;
+use enemy::EnemyArchetypes;
+use interest::*;
 use item_drop::generate_item_dropped_on_death;
+use join::*;
+use leap::*;
+use obstacle::*;
+use party::*;
 use lightyear::prelude::server::*;
 use lightyear::prelude::*;
 use lightyear::server::input::leafwing::InputSystemSet;
 use local_ip_address::local_ip;
 use lerp_common_game::input::PlayerActions;
 use lerp_common_game::prelude::*;
+use replay::{ReplayRecordPlugin, REPLAY_DIR_ENV};
+use spawner::*;
 use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::{mpsc, oneshot};
+use uuid::Uuid;
 
+use crate::http_api::party::PartyRepo;
+
+pub mod admin;
+mod aggro;
+pub mod bandwidth;
+pub mod chat;
+pub mod data;
+pub mod enemy;
+pub mod interest;
 mod item_drop;
+pub mod join;
+mod leap;
+mod obstacle;
+pub mod party;
+pub mod replay;
+pub mod spawner;
 
 #[derive(Resource, Default)]
 pub struct ClientPlayerMap(HashMap<ClientId, Entity>);
@@ -27,61 +58,80 @@ fn start_server(mut commands: Commands) {
 
 fn handle_connections(
     mut connections: EventReader<ConnectEvent>,
-    mut commands: Commands,
-    mut client_player_map: ResMut<ClientPlayerMap>,
-    map: Res<Map>,
+    map_data: Option<Res<MapData>>,
+    mut send_map_ev: EventWriter<ServerSendMessage<MapData>>,
 ) {
     for connection in connections.read() {
-        let client_id = connection.client_id;
-        info!("New client {:?}", client_id);
-
-        let player_id = commands.spawn_empty().id();
-        commands.entity(player_id).insert((
-            PlayerBundle::new(&map.player_spawn_position),
-            Replicate {
-                sync: SyncTarget {
-                    prediction: NetworkTarget::All,
-                    interpolation: NetworkTarget::None,
-                },
-                target: ReplicationTarget {
-                    target: NetworkTarget::All,
-                },
-                controlled_by: ControlledBy {
-                    target: NetworkTarget::Single(client_id),
-                    ..default()
-                },
-                group: REPLICATION_GROUP,
+        // Nothing is replicated to the client until it sends JoinGame
+        info!("New client {:?}", connection.client_id);
+
+        // The client builds the map from it, instead of loading its own copy
+        let Some(map_data) = &map_data else {
+            error!("[handle_connections] Map not generated yet");
+            continue;
+        };
+        send_map_ev.send(ServerSendMessage::new_with_target::<MapChannel>(
+            (**map_data).clone(),
+            NetworkTarget::Single(connection.client_id),
+        ));
+    }
+}
+
+/// Spawn the replicated player controlled by the given client and its PlayerClient
+pub(crate) fn spawn_player(
+    commands: &mut Commands,
+    client_player_map: &mut ClientPlayerMap,
+    map: &Map,
+    client_id: ClientId,
+) -> Entity {
+    let player_id = commands.spawn_empty().id();
+    commands.entity(player_id).insert((
+        PlayerBundle::new(&map.player_spawn_position(client_player_map.0.len())),
+        Replicate {
+            sync: SyncTarget {
+                prediction: NetworkTarget::All,
+                interpolation: NetworkTarget::None,
+            },
+            target: ReplicationTarget {
+                target: NetworkTarget::All,
+            },
+            controlled_by: ControlledBy {
+                target: NetworkTarget::Single(client_id),
                 ..default()
             },
-        ));
+            group: REPLICATION_GROUP,
+            ..default()
+        },
+    ));
 
-        let player_client = (
-            PlayerClient {
-                client_id,
-                rtt: Duration::ZERO,
-                jitter: Duration::ZERO,
-                player_ref: player_id,
+    let player_client = (
+        PlayerClient {
+            client_id,
+            rtt: Duration::ZERO,
+            jitter: Duration::ZERO,
+            player_ref: player_id,
+        },
+        Replicate {
+            sync: SyncTarget {
+                prediction: NetworkTarget::Single(client_id),
+                interpolation: NetworkTarget::None,
             },
-            Replicate {
-                sync: SyncTarget {
-                    prediction: NetworkTarget::Single(client_id),
-                    interpolation: NetworkTarget::None,
-                },
-                target: ReplicationTarget {
-                    target: NetworkTarget::Single(client_id),
-                },
-                controlled_by: ControlledBy {
-                    target: NetworkTarget::Single(client_id),
-                    ..default()
-                },
-                group: REPLICATION_GROUP,
+            target: ReplicationTarget {
+                target: NetworkTarget::Single(client_id),
+            },
+            controlled_by: ControlledBy {
+                target: NetworkTarget::Single(client_id),
                 ..default()
             },
-        );
-        commands.spawn(player_client);
+            group: REPLICATION_GROUP,
+            ..default()
+        },
+    );
+    commands.spawn(player_client);
 
-        client_player_map.0.insert(client_id, player_id);
-    }
+    client_player_map.0.insert(client_id, player_id);
+
+    player_id
 }
 
 fn replicate_inputs(
@@ -117,23 +167,35 @@ struct ExitState {
     pub lifetime: Duration,
     pub instance_exit_rx: oneshot::Receiver<bool>,
     pub instance_exit_tx: mpsc::Sender<u16>,
+    /// Set once the shutdown message is sent, the instance exits on the next run so that the message is flushed
+    pub shutting_down: bool,
 }
 
 fn exit_listener_system(
     mut exit_state: ResMut<ExitState>,
     mut app_exit_event: EventWriter<AppExit>,
+    mut send_chat_ev: EventWriter<ServerSendMessage<ChatMessage>>,
     player_q: Query<&Player>,
 ) {
     exit_state.lifetime += Duration::from_millis(100);
 
-    if exit_state.instance_exit_rx.try_recv().is_ok()
-        || (exit_state.lifetime > Duration::from_secs(10) && player_q.is_empty())
-    {
+    if exit_state.shutting_down {
         exit_state
             .instance_exit_tx
             .blocking_send(exit_state.port)
             .unwrap();
         app_exit_event.send(AppExit::Success);
+        return;
+    }
+
+    if exit_state.instance_exit_rx.try_recv().is_ok()
+        || (exit_state.lifetime > Duration::from_secs(10) && player_q.is_empty())
+    {
+        send_chat_ev.send(ServerSendMessage::new_with_target::<ChatChannel>(
+            ChatMessage::system("Instance shutting down"),
+            NetworkTarget::All,
+        ));
+        exit_state.shutting_down = true;
     }
 }
 
@@ -141,27 +203,77 @@ pub(crate) struct GameInstanceConfig {
     pub port: u16,
     pub exit_channel_rx: oneshot::Receiver<bool>,
     pub instance_exit_tx: mpsc::Sender<u16>,
+    pub party: InstanceParty,
+    pub party_repo: Arc<dyn PartyRepo>,
+    pub admin_rx: mpsc::Receiver<AdminRequest>,
+    /// Builtin map name, validated by the HTTP API
+    pub map: String,
 }
 
 pub(crate) fn start_game_world(config: GameInstanceConfig) {
     let server_addr = SocketAddr::new(local_ip().unwrap().to_canonical(), config.port);
 
-    let netcode_config = NetcodeConfig::default().with_protocol_id(0).with_key([
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0,
-    ]);
-
     let net_config = NetConfig::Netcode {
-        config: netcode_config,
+        config: netcode_config(),
         io: IoConfig {
             transport: ServerTransport::UdpSocket(server_addr),
             ..default()
         },
     };
 
+    let rng_seed = Uuid::new_v4().as_u64_pair().0;
+    let mut app = build_game_world(vec![net_config], rng_seed);
+    app.insert_resource(SelectedMap(config.map));
+
+    if let Ok(radius) = std::env::var(INTEREST_RADIUS_ENV) {
+        match radius.parse::<f32>() {
+            Ok(radius) => {
+                app.insert_resource(InterestConfig::with_radius(radius));
+            }
+            Err(_) => error!("Invalid {}: {}", INTEREST_RADIUS_ENV, radius),
+        }
+    }
+
+    if let Ok(replay_dir) = std::env::var(REPLAY_DIR_ENV) {
+        let path = PathBuf::from(replay_dir).join(format!("{}-{}.replay", config.port, rng_seed));
+        info!("Recording replay to {}", path.display());
+        app.add_plugins(ReplayRecordPlugin { path, rng_seed });
+    }
+
+    app.insert_resource(config.party);
+    app.insert_resource(Parties(config.party_repo));
+    app.insert_resource(AdminRequests(config.admin_rx));
+    app.insert_resource(ExitState {
+        port: config.port,
+        instance_exit_rx: config.exit_channel_rx,
+        instance_exit_tx: config.instance_exit_tx,
+        lifetime: Duration::ZERO,
+        shutting_down: false,
+    })
+    .add_systems(
+        Update,
+        exit_listener_system.run_if(on_timer(Duration::from_millis(100))),
+    )
+    .run();
+
+    info!("start_game_world stopped");
+}
+
+pub(crate) fn netcode_config() -> NetcodeConfig {
+    NetcodeConfig::default().with_protocol_id(0).with_key([
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0,
+    ])
+}
+
+/// Build the headless game server [`App`] listening on the given transports.
+///
+/// The caller is responsible for running it and for any instance lifecycle logic.
+/// The `rng_seed` drives every random roll of the simulation (loot...), so that it can be replayed.
+pub fn build_game_world(net: Vec<NetConfig>, rng_seed: u64) -> App {
     let server_config = server::ServerConfig {
         shared: shared_config(),
-        net: vec![net_config],
+        net,
         replication: ReplicationConfig {
             send_updates_mode: SendUpdatesMode::SinceLastAck,
         },
@@ -169,20 +281,35 @@ pub(crate) fn start_game_world(config: GameInstanceConfig) {
     };
     let server_plugin = server::ServerPlugins::new(server_config);
 
-    App::new()
-        .add_plugins((MinimalPlugins, StatesPlugin))
-        .add_plugins(EntropyPlugin::<WyRand>::default())
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, StatesPlugin))
+        .add_plugins(EntropyPlugin::<WyRand>::with_seed(rng_seed.to_le_bytes()))
         .add_plugins(server_plugin.build())
         .add_plugins(SharedPlugin)
+        .add_plugins(add_transport_diagnostics)
         .init_resource::<ClientPlayerMap>()
-        .insert_resource(ExitState {
-            port: config.port,
-            instance_exit_rx: config.exit_channel_rx,
-            instance_exit_tx: config.instance_exit_tx,
-            lifetime: Duration::ZERO,
-        })
+        .init_resource::<Spectators>()
+        .init_resource::<InterestConfig>()
+        .init_resource::<ClientInterest>()
+        .init_resource::<BandwidthStats>()
+        .init_resource::<ChatRateLimits>()
+        .init_resource::<InstanceParty>()
+        .init_resource::<Parties>()
+        .init_resource::<ClientUsernames>()
+        .init_resource::<AdminConfig>()
+        .init_resource::<AdminClients>()
+        .init_resource::<EnemyArchetypes>()
+        .init_resource::<SpawnerPresets>()
+        .init_resource::<SpatialIndexHistory>()
+        .add_event::<PlayerJoined>()
+        .add_event::<PlayerLeft>()
+        .add_event::<AdminCommandIssued>()
+        .add_event::<AdminCommandExecuted>()
         .add_systems(Startup, start_server)
-        .add_systems(OnEnter(NetworkingState::Started), generate_map)
+        .add_systems(
+            OnEnter(NetworkingState::Started),
+            (generate_map, (spawn_enemy_spawners, spawn_obstacles)).chain(),
+        )
         .add_systems(
             PreUpdate,
             replicate_inputs.after(InputSystemSet::ReceiveInputs),
@@ -191,12 +318,75 @@ pub(crate) fn start_game_world(config: GameInstanceConfig) {
             Update,
             (
                 handle_connections,
+                handle_disconnections,
+                (
+                    handle_join_game,
+                    update_world_replication_targets,
+                    update_client_interest,
+                )
+                    .chain(),
                 update_player_client_metrics.run_if(on_timer(Duration::from_secs(1))),
-                exit_listener_system.run_if(on_timer(Duration::from_millis(100))),
             ),
         )
-        .add_systems(FixedUpdate, generate_item_dropped_on_death)
-        .run();
-
-    info!("start_game_world stopped");
+        .add_systems(
+            Update,
+            (
+                handle_chat_messages
+                    .after(handle_join_game)
+                    .after(handle_disconnections),
+                announce_joins_and_leaves
+                    .after(handle_join_game)
+                    .before(handle_disconnections),
+            ),
+        )
+        .add_systems(
+            Update,
+            (
+                execute_admin_commands.after(handle_chat_messages),
+                forget_admin_clients.after(execute_admin_commands),
+            ),
+        )
+        .add_systems(
+            Update,
+            (
+                (
+                    measure_component_bandwidth::<Position>,
+                    measure_component_bandwidth::<LinearVelocity>,
+                    measure_component_bandwidth::<Health>,
+                )
+                    .run_if(on_timer(REPLICATION_INTERVAL)),
+                report_bandwidth_stats.run_if(on_timer(BANDWIDTH_REPORT_INTERVAL)),
+            )
+                .chain()
+                .after(update_client_interest),
+        )
+        .add_systems(
+            FixedUpdate,
+            (
+                generate_item_dropped_on_death,
+                award_experience_on_death,
+                update_enemy_spawners,
+                destroy_obstacles,
+            ),
+        )
+        .add_systems(
+            FixedUpdate,
+            (
+                update_aggro
+                    .in_set(GameSimulationSet::Others)
+                    .after(update_spatial_index)
+                    .before(update_flow_field),
+                enemy_leap
+                    .in_set(GameSimulationSet::Others)
+                    .after(update_aggro)
+                    .before(update_flow_field),
+                add_threat_on_hit
+                    .in_set(GameSimulationSet::ConsumeHitEvents)
+                    .before(on_hit_event),
+                record_spatial_index_history
+                    .in_set(GameSimulationSet::Others)
+                    .after(update_spatial_index),
+            ),
+        );
+    app
 }
@@ -0,0 +1,37 @@
+// Destructible obstacles placed on the `C` and `B` cells of the map, replicated like the enemies.
+use bevy::prelude::*;
+use lerp_common_game::prelude::*;
+use lightyear::prelude::server::*;
+use lightyear::prelude::*;
+
+pub(crate) fn spawn_obstacles(mut commands: Commands, map: Res<Map>) {
+    for (kind, position) in map.obstacle_spawn_positions.iter() {
+        commands.spawn((
+            ObstacleBundle::new(*kind, position),
+            Replicate {
+                sync: SyncTarget {
+                    prediction: NetworkTarget::All,
+                    interpolation: NetworkTarget::None,
+                },
+                target: ReplicationTarget {
+                    target: NetworkTarget::All,
+                },
+                group: REPLICATION_GROUP,
+                ..default()
+            },
+        ));
+    }
+}
+
+/// Obstacles have no death state, they are removed as soon as they are destroyed.
+/// Their nav tiles are opened again by the next update of the flow fields, see [`update_flow_field`]
+pub(crate) fn destroy_obstacles(
+    mut commands: Commands,
+    obstacle_q: Query<(Entity, &Health), (With<Obstacle>, Changed<Health>)>,
+) {
+    for (entity, health) in obstacle_q.iter() {
+        if health.current <= 0. {
+            commands.entity(entity).despawn();
+        }
+    }
+}
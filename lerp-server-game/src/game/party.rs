@@ -0,0 +1,93 @@
+// In game party rules: experience earned near a party is split between all its members,
+// and loot dropped near a party member is reserved to the party.
+use std::sync::Arc;
+
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use bevy::utils::{HashMap, HashSet};
+use lerp_common_game::prelude::*;
+use uuid::Uuid;
+
+use crate::http_api::party::{InMemoryPartyRepo, PartyRepo};
+
+/// Party the instance was started for, members are fixed when the instance starts
+#[derive(Resource, Clone, Debug, Default)]
+pub struct InstanceParty {
+    pub party_id: Option<Uuid>,
+    pub members: HashSet<String>,
+}
+
+impl InstanceParty {
+    pub fn party_of(&self, username: &str) -> Option<PartyMember> {
+        self.party_id
+            .filter(|_| self.members.contains(username))
+            .map(PartyMember)
+    }
+}
+
+/// Lobby parties shared with the HTTP API, so that the party chat follows the members joining and leaving
+#[derive(Resource, Clone)]
+pub(crate) struct Parties(pub Arc<dyn PartyRepo>);
+
+impl Default for Parties {
+    fn default() -> Self {
+        Self(Arc::new(InMemoryPartyRepo::default()))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum ExperienceGroup {
+    Solo(Entity),
+    Party(PartyMember),
+}
+
+/// Party of the player closest to the given position, if any player is in [`EXPERIENCE_RADIUS`]
+pub(crate) fn nearest_party(
+    position: Vec2,
+    player_q: &Query<(&Position, Option<&PartyMember>), With<Player>>,
+) -> Option<PartyMember> {
+    player_q
+        .iter()
+        .map(|(player_position, party)| (player_position.0.distance(position), party))
+        .filter(|(distance, _)| *distance <= EXPERIENCE_RADIUS)
+        .min_by(|(a, _), (b, _)| a.total_cmp(b))
+        .and_then(|(_, party)| party.copied())
+}
+
+/// Each solo player and each party with a member near the dying enemy earns its experience,
+/// a party splits it between all its members
+pub(crate) fn award_experience_on_death(
+    dead_enemy_q: Query<&Position, (Added<Dead>, With<Enemy>)>,
+    mut player_q: Query<(Entity, &Position, Option<&PartyMember>, &mut Experience), With<Player>>,
+) {
+    for enemy_position in dead_enemy_q.iter() {
+        let groups: HashSet<ExperienceGroup> = player_q
+            .iter()
+            .filter(|(_, position, _, _)| {
+                position.0.distance(enemy_position.0) <= EXPERIENCE_RADIUS
+            })
+            .map(|(entity, _, party, _)| match party {
+                Some(party) => ExperienceGroup::Party(*party),
+                None => ExperienceGroup::Solo(entity),
+            })
+            .collect();
+
+        let mut party_sizes: HashMap<PartyMember, u32> = HashMap::default();
+        for (_, _, party, _) in player_q.iter() {
+            if let Some(party) = party {
+                *party_sizes.entry(*party).or_default() += 1;
+            }
+        }
+
+        for (entity, _, party, mut experience) in player_q.iter_mut() {
+            let earned = match party {
+                Some(party) if groups.contains(&ExperienceGroup::Party(*party)) => {
+                    ENEMY_EXPERIENCE.div_ceil(party_sizes[party])
+                }
+                None if groups.contains(&ExperienceGroup::Solo(entity)) => ENEMY_EXPERIENCE,
+                _ => continue,
+            };
+            experience.0 += earned;
+        }
+    }
+}
@@ -0,0 +1,649 @@
+// Record the inputs of a game instance to a file and re-simulate them headlessly.
+// The simulation only depends on the map, the rng seed, the skill db, the players inputs, joins and leaves,
+// the admin commands and the time speed, so re-applying them tick by tick must lead to the same world state.
+// Periodic snapshots of the recorded world are compared against the replayed one to detect divergences.
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use leafwing_input_manager::prelude::*;
+use lerp_common_game::prelude::*;
+use lightyear::inputs::leafwing::input_buffer::InputBuffer;
+use lightyear::prelude::server::*;
+use lightyear::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::admin::{execute_admin_commands, AdminCommandExecuted, AdminWorld};
+use super::join::{handle_disconnections, handle_join_game, PlayerJoined, PlayerLeft};
+use super::{build_game_world, spawn_player, ClientPlayerMap};
+
+/// When set, every game instance records a replay file in this directory
+pub const REPLAY_DIR_ENV: &str = "LERP_REPLAY_DIR";
+
+/// Number of fixed ticks between two recorded world snapshots
+const SNAPSHOT_INTERVAL: u32 = 64;
+
+/// Max distance in pixels between a recorded and a replayed position
+const POSITION_EPSILON: f32 = 0.01;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReplayHeader {
+    pub map_name: String,
+    pub rng_seed: u64,
+    pub skill_db_checksum: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CharacterSnapshot {
+    pub id: CharacterId,
+    pub position: Vec2,
+    pub health: f32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct WorldSnapshot {
+    pub characters: Vec<CharacterSnapshot>,
+    pub projectile_count: usize,
+}
+
+impl WorldSnapshot {
+    fn matches(&self, other: &WorldSnapshot) -> bool {
+        self.projectile_count == other.projectile_count
+            && self.characters.len() == other.characters.len()
+            && self.characters.iter().zip(&other.characters).all(|(a, b)| {
+                a.id == b.id
+                    && a.health == b.health
+                    && a.position.distance(b.position) <= POSITION_EPSILON
+            })
+    }
+}
+
+/// Input applied to a player for one fixed tick.
+///
+/// This is the [`ActionState`] decoded by lightyear from the client `InputMessage`,
+/// not the raw message, since this is what the simulation actually consumes.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReplayInput {
+    pub client_id: ClientId,
+    pub action_state: ActionState<PlayerActions>,
+    /// Whether the input buffer had an input for this tick
+    pub buffered: bool,
+}
+
+/// Admin command executed successfully, re-executed as is
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ReplayAdminCommand {
+    pub client_id: Option<ClientId>,
+    pub command: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct ReplayFrame {
+    pub fixed_tick: u32,
+    /// Clients that joined as player during the frame that ran this tick
+    pub player_joins: Vec<ClientId>,
+    /// Players that disconnected during the frame that ran this tick
+    pub player_leaves: Vec<ClientId>,
+    /// Executed after the joins and leaves of the frame
+    pub admin_commands: Vec<ReplayAdminCommand>,
+    /// Relative speed of the virtual time, when it changed during the frame
+    pub time_speed: Option<f32>,
+    pub inputs: Vec<ReplayInput>,
+    pub snapshot: Option<WorldSnapshot>,
+}
+
+impl ReplayFrame {
+    fn is_empty(&self) -> bool {
+        self.player_joins.is_empty()
+            && self.player_leaves.is_empty()
+            && self.admin_commands.is_empty()
+            && self.time_speed.is_none()
+            && self.inputs.is_empty()
+            && self.snapshot.is_none()
+    }
+}
+
+/// Number of fixed ticks simulated since the world was built.
+/// Unlike the lightyear tick it always starts at 0, which makes recordings comparable.
+#[derive(Resource, Default)]
+pub struct ReplayTick(pub u32);
+
+fn progress_replay_tick(mut replay_tick: ResMut<ReplayTick>) {
+    replay_tick.0 += 1;
+}
+
+fn take_world_snapshot(
+    character_q: &Query<(&Character, &Position, &Health), With<ReplicationTarget>>,
+    projectile_q: &Query<(), (With<Projectile>, With<ReplicationTarget>)>,
+) -> WorldSnapshot {
+    let mut characters: Vec<CharacterSnapshot> = character_q
+        .iter()
+        .map(|(character, position, health)| CharacterSnapshot {
+            id: character.id.clone(),
+            position: position.0,
+            health: health.current,
+        })
+        .collect();
+    // Entity order is not stable across runs, positions are
+    characters.sort_by(|a, b| {
+        a.position
+            .x
+            .total_cmp(&b.position.x)
+            .then(a.position.y.total_cmp(&b.position.y))
+    });
+
+    WorldSnapshot {
+        characters,
+        projectile_count: projectile_q.iter().count(),
+    }
+}
+
+#[derive(Resource)]
+struct ReplayRecorder {
+    writer: BufWriter<File>,
+    frame: ReplayFrame,
+    /// Last recorded relative speed of the virtual time
+    time_speed: f32,
+}
+
+impl ReplayRecorder {
+    fn write<T: Serialize>(&mut self, value: &T) {
+        if let Err(err) = bincode::serialize_into(&mut self.writer, value) {
+            error!("[ReplayRecorder] Failed to write replay: {}", err);
+        }
+    }
+}
+
+/// Stream the replay of the game instance to the given file
+pub struct ReplayRecordPlugin {
+    pub path: PathBuf,
+    /// Seed given to [`build_game_world`]
+    pub rng_seed: u64,
+}
+
+impl Plugin for ReplayRecordPlugin {
+    fn build(&self, app: &mut App) {
+        let file = match File::create(&self.path) {
+            Ok(file) => file,
+            Err(err) => {
+                error!(
+                    "[ReplayRecordPlugin] Cannot create {}: {}",
+                    self.path.display(),
+                    err
+                );
+                return;
+            }
+        };
+
+        let header = ReplayHeader {
+            map_name: app.world().resource::<SelectedMap>().0.clone(),
+            rng_seed: self.rng_seed,
+            skill_db_checksum: app.world().resource::<SkillDb>().checksum(),
+        };
+
+        let mut recorder = ReplayRecorder {
+            writer: BufWriter::new(file),
+            frame: ReplayFrame::default(),
+            time_speed: 1.,
+        };
+        recorder.write(&header);
+
+        app.init_resource::<ReplayTick>()
+            .insert_resource(recorder)
+            .add_systems(
+                FixedFirst,
+                (progress_replay_tick, flush_replay_frame).chain(),
+            )
+            .add_systems(
+                FixedUpdate,
+                record_replay_inputs.before(GameSimulationSet::RegisterInputs),
+            )
+            .add_systems(FixedPostUpdate, record_replay_snapshot)
+            .add_systems(
+                Update,
+                record_replay_events
+                    .after(handle_join_game)
+                    .after(handle_disconnections)
+                    .after(execute_admin_commands),
+            )
+            .add_systems(Last, flush_replay_on_exit.run_if(on_event::<AppExit>));
+    }
+}
+
+/// Write the frame of the previous tick and start the one of the current tick
+fn flush_replay_frame(replay_tick: Res<ReplayTick>, mut recorder: ResMut<ReplayRecorder>) {
+    let frame = std::mem::replace(
+        &mut recorder.frame,
+        ReplayFrame {
+            fixed_tick: replay_tick.0,
+            ..default()
+        },
+    );
+    if !frame.is_empty() {
+        recorder.write(&frame);
+    }
+}
+
+fn flush_replay_on_exit(mut recorder: ResMut<ReplayRecorder>) {
+    let frame = std::mem::take(&mut recorder.frame);
+    if !frame.is_empty() {
+        recorder.write(&frame);
+    }
+    if let Err(err) = recorder.writer.flush() {
+        error!("[flush_replay_on_exit] Failed to flush replay: {}", err);
+    }
+}
+
+fn record_replay_events(
+    mut player_joined_ev: EventReader<PlayerJoined>,
+    mut player_left_ev: EventReader<PlayerLeft>,
+    mut admin_command_executed_ev: EventReader<AdminCommandExecuted>,
+    time: Res<Time<Virtual>>,
+    mut recorder: ResMut<ReplayRecorder>,
+) {
+    for player_joined in player_joined_ev.read() {
+        recorder.frame.player_joins.push(player_joined.0);
+    }
+    for player_left in player_left_ev.read() {
+        recorder.frame.player_leaves.push(player_left.0);
+    }
+    for admin_command in admin_command_executed_ev.read() {
+        recorder.frame.admin_commands.push(ReplayAdminCommand {
+            client_id: admin_command.client_id,
+            command: admin_command.command.clone(),
+        });
+    }
+    if time.relative_speed() != recorder.time_speed {
+        recorder.time_speed = time.relative_speed();
+        recorder.frame.time_speed = Some(recorder.time_speed);
+    }
+}
+
+fn record_replay_inputs(
+    tick_manager: Res<TickManager>,
+    client_player_map: Res<ClientPlayerMap>,
+    player_q: Query<(&ActionState<PlayerActions>, &InputBuffer<PlayerActions>)>,
+    mut recorder: ResMut<ReplayRecorder>,
+) {
+    let tick = tick_manager.tick();
+    for (client_id, player_entity) in client_player_map.0.iter() {
+        let Ok((action_state, buffer)) = player_q.get(*player_entity) else {
+            continue;
+        };
+
+        recorder.frame.inputs.push(ReplayInput {
+            client_id: *client_id,
+            action_state: action_state.clone(),
+            buffered: buffer.get(tick).is_some(),
+        });
+    }
+}
+
+fn record_replay_snapshot(
+    replay_tick: Res<ReplayTick>,
+    character_q: Query<(&Character, &Position, &Health), With<ReplicationTarget>>,
+    projectile_q: Query<(), (With<Projectile>, With<ReplicationTarget>)>,
+    mut recorder: ResMut<ReplayRecorder>,
+) {
+    if replay_tick.0 % SNAPSHOT_INTERVAL != 0 {
+        return;
+    }
+    recorder.frame.snapshot = Some(take_world_snapshot(&character_q, &projectile_q));
+}
+
+#[derive(Debug)]
+pub struct ReplayDivergence {
+    pub fixed_tick: u32,
+    pub expected: WorldSnapshot,
+    pub actual: WorldSnapshot,
+}
+
+#[derive(Debug)]
+pub struct ReplayReport {
+    pub header: ReplayHeader,
+    /// The skill db changed since the replay was recorded, divergences are expected
+    pub skill_db_mismatch: bool,
+    pub simulated_ticks: u32,
+    pub compared_snapshots: u32,
+    pub divergences: Vec<ReplayDivergence>,
+}
+
+#[derive(Resource)]
+struct ReplayPlayback {
+    frames: VecDeque<ReplayFrame>,
+    /// Admin commands of the last applied frames, executed once their joins and leaves are applied
+    admin_commands: Vec<ReplayAdminCommand>,
+    compared_snapshots: u32,
+    divergences: Vec<ReplayDivergence>,
+}
+
+impl ReplayPlayback {
+    fn current_frame(&self, replay_tick: &ReplayTick) -> Option<&ReplayFrame> {
+        self.frames
+            .front()
+            .filter(|frame| frame.fixed_tick == replay_tick.0)
+    }
+}
+
+fn read_replay(path: &Path) -> Result<(ReplayHeader, VecDeque<ReplayFrame>), bincode::Error> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let header: ReplayHeader = bincode::deserialize_from(&mut reader)?;
+
+    let mut frames = VecDeque::new();
+    loop {
+        match bincode::deserialize_from::<_, ReplayFrame>(&mut reader) {
+            Ok(frame) => frames.push_back(frame),
+            Err(err) => match *err {
+                // A replay of a running or crashed instance may be truncated
+                bincode::ErrorKind::Io(ref io_err) if io_err.kind() == ErrorKind::UnexpectedEof => {
+                    break
+                }
+                _ => return Err(err),
+            },
+        }
+    }
+
+    Ok((header, frames))
+}
+
+/// Re-simulate a recorded game instance as fast as possible and compare it with the recording.
+pub fn run_replay(path: &Path) -> Result<ReplayReport, bincode::Error> {
+    let (header, frames) = read_replay(path)?;
+    let last_tick = frames.back().map_or(0, |frame| frame.fixed_tick);
+
+    let mut app = build_game_world(vec![], header.rng_seed);
+
+    let skill_db_checksum = app.world().resource::<SkillDb>().checksum();
+    let skill_db_mismatch = skill_db_checksum != header.skill_db_checksum;
+    if skill_db_mismatch {
+        warn!("[run_replay] Skill db changed since the replay was recorded");
+    }
+    app.insert_resource(SelectedMap(header.map_name.clone()));
+
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(
+        1.0 / FIXED_TIMESTEP_HZ,
+    )))
+    .init_resource::<ReplayTick>()
+    .insert_resource(ReplayPlayback {
+        frames,
+        admin_commands: Vec::new(),
+        compared_snapshots: 0,
+        divergences: Vec::new(),
+    })
+    .add_systems(FixedFirst, progress_replay_tick)
+    .add_systems(FixedPreUpdate, apply_replay_inputs)
+    .add_systems(FixedPostUpdate, compare_replay_snapshot)
+    .add_systems(
+        Update,
+        (apply_replay_frame_events, apply_replay_admin_commands).chain(),
+    );
+    app.finish();
+    app.cleanup();
+
+    while app.world().resource::<ReplayTick>().0 < last_tick {
+        app.update();
+    }
+    // Events recorded after the last tick
+    app.update();
+
+    let playback = app.world_mut().remove_resource::<ReplayPlayback>().unwrap();
+
+    Ok(ReplayReport {
+        header,
+        skill_db_mismatch,
+        simulated_ticks: app.world().resource::<ReplayTick>().0,
+        compared_snapshots: playback.compared_snapshots,
+        divergences: playback.divergences,
+    })
+}
+
+fn apply_replay_inputs(
+    replay_tick: Res<ReplayTick>,
+    tick_manager: Res<TickManager>,
+    playback: Res<ReplayPlayback>,
+    client_player_map: Res<ClientPlayerMap>,
+    mut player_q: Query<(
+        &mut ActionState<PlayerActions>,
+        &mut InputBuffer<PlayerActions>,
+    )>,
+) {
+    let Some(frame) = playback.current_frame(&replay_tick) else {
+        return;
+    };
+
+    let tick = tick_manager.tick();
+    for input in frame.inputs.iter() {
+        let Some(player_entity) = client_player_map.0.get(&input.client_id) else {
+            error!("[apply_replay_inputs] Unknown client {:?}", input.client_id);
+            continue;
+        };
+        let Ok((mut action_state, mut buffer)) = player_q.get_mut(*player_entity) else {
+            continue;
+        };
+
+        *action_state = input.action_state.clone();
+        if input.buffered {
+            buffer.set(tick, &input.action_state);
+        }
+    }
+}
+
+fn compare_replay_snapshot(
+    replay_tick: Res<ReplayTick>,
+    character_q: Query<(&Character, &Position, &Health), With<ReplicationTarget>>,
+    projectile_q: Query<(), (With<Projectile>, With<ReplicationTarget>)>,
+    mut playback: ResMut<ReplayPlayback>,
+) {
+    let Some(expected) = playback
+        .current_frame(&replay_tick)
+        .and_then(|frame| frame.snapshot.clone())
+    else {
+        return;
+    };
+
+    let actual = take_world_snapshot(&character_q, &projectile_q);
+    playback.compared_snapshots += 1;
+    if !expected.matches(&actual) {
+        playback.divergences.push(ReplayDivergence {
+            fixed_tick: replay_tick.0,
+            expected,
+            actual,
+        });
+    }
+}
+
+fn apply_replay_frame_events(
+    mut commands: Commands,
+    replay_tick: Res<ReplayTick>,
+    mut playback: ResMut<ReplayPlayback>,
+    mut client_player_map: ResMut<ClientPlayerMap>,
+    mut time: ResMut<Time<Virtual>>,
+    player_client_q: Query<(Entity, &PlayerClient)>,
+    map: Res<Map>,
+) {
+    while playback
+        .frames
+        .front()
+        .is_some_and(|frame| frame.fixed_tick <= replay_tick.0)
+    {
+        let frame = playback.frames.pop_front().unwrap();
+        // Lightyear despawns the entities controlled by a client with its session
+        for client_id in frame.player_leaves {
+            if let Some(player) = client_player_map.0.remove(&client_id) {
+                commands.entity(player).despawn_recursive();
+            }
+            for (entity, player_client) in player_client_q.iter() {
+                if player_client.client_id == client_id {
+                    commands.entity(entity).despawn_recursive();
+                }
+            }
+        }
+        for client_id in frame.player_joins {
+            let player = spawn_player(&mut commands, &mut client_player_map, &map, client_id);
+            // Inputs are fed directly instead of being received from the client
+            commands.entity(player).insert((
+                ActionState::<PlayerActions>::default(),
+                InputBuffer::<PlayerActions>::default(),
+            ));
+        }
+        if let Some(time_speed) = frame.time_speed {
+            time.set_relative_speed(time_speed);
+        }
+        playback.admin_commands.extend(frame.admin_commands);
+    }
+}
+
+fn apply_replay_admin_commands(mut playback: ResMut<ReplayPlayback>, mut admin_world: AdminWorld) {
+    for admin_command in std::mem::take(&mut playback.admin_commands) {
+        if let Err(err) = admin_world.execute_text(&admin_command.command, admin_command.client_id)
+        {
+            error!(
+                "[apply_replay_admin_commands] `{}` failed: {}",
+                admin_command.command, err
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+    use crate::game::admin::{AdminClients, AdminCommandIssued};
+
+    const CLIENT_ID: ClientId = ClientId::Netcode(1);
+    const RNG_SEED: u64 = 42;
+
+    fn press_move_right(
+        tick_manager: Res<TickManager>,
+        mut player_q: Query<(
+            &mut ActionState<PlayerActions>,
+            &mut InputBuffer<PlayerActions>,
+        )>,
+    ) {
+        for (mut action_state, mut buffer) in player_q.iter_mut() {
+            action_state.press(&PlayerActions::MoveRight);
+            buffer.set(tick_manager.tick(), &action_state);
+        }
+    }
+
+    fn step(app: &mut App, frames: usize) {
+        for _ in 0..frames {
+            app.update();
+        }
+    }
+
+    /// A player walks, runs admin commands under a faster time then disconnects
+    fn record_session(path: &Path) {
+        let mut app = build_game_world(vec![], RNG_SEED);
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(
+            1.0 / FIXED_TIMESTEP_HZ,
+        )))
+        .add_plugins(ReplayRecordPlugin {
+            path: path.to_path_buf(),
+            rng_seed: RNG_SEED,
+        })
+        .add_systems(FixedPreUpdate, press_move_right);
+        app.finish();
+        app.cleanup();
+        step(&mut app, 10);
+
+        app.world_mut()
+            .run_system_once(
+                |mut commands: Commands,
+                 mut client_player_map: ResMut<ClientPlayerMap>,
+                 map: Res<Map>,
+                 mut player_joined_ev: EventWriter<PlayerJoined>| {
+                    let player =
+                        spawn_player(&mut commands, &mut client_player_map, &map, CLIENT_ID);
+                    commands.entity(player).insert((
+                        ActionState::<PlayerActions>::default(),
+                        InputBuffer::<PlayerActions>::default(),
+                    ));
+                    player_joined_ev.send(PlayerJoined(CLIENT_ID));
+                },
+            )
+            .unwrap();
+        step(&mut app, 70);
+
+        app.world_mut()
+            .resource_mut::<Time<Virtual>>()
+            .set_relative_speed(2.);
+        app.world_mut()
+            .resource_mut::<AdminClients>()
+            .0
+            .insert(CLIENT_ID);
+        for command in ["spawn 3", "health 50", "teleport 2 3"] {
+            app.world_mut().send_event(AdminCommandIssued {
+                client_id: CLIENT_ID,
+                command: command.to_string(),
+            });
+        }
+        step(&mut app, 70);
+
+        app.world_mut()
+            .resource_mut::<Time<Virtual>>()
+            .set_relative_speed(1.);
+        // What lightyear and handle_disconnections do when the client disconnects
+        app.world_mut()
+            .run_system_once(
+                |mut commands: Commands,
+                 mut client_player_map: ResMut<ClientPlayerMap>,
+                 player_client_q: Query<Entity, With<PlayerClient>>,
+                 mut player_left_ev: EventWriter<PlayerLeft>| {
+                    if let Some(player) = client_player_map.0.remove(&CLIENT_ID) {
+                        commands.entity(player).despawn_recursive();
+                    }
+                    for entity in player_client_q.iter() {
+                        commands.entity(entity).despawn_recursive();
+                    }
+                    player_left_ev.send(PlayerLeft(CLIENT_ID));
+                },
+            )
+            .unwrap();
+        step(&mut app, 70);
+
+        app.world_mut().send_event(AppExit::Success);
+        app.update();
+    }
+
+    #[test]
+    fn replay_matches_the_recorded_snapshots() {
+        let path = std::env::temp_dir().join(format!(
+            "lerp-replay-round-trip-{}.replay",
+            std::process::id()
+        ));
+        record_session(&path);
+
+        let (_, frames) = read_replay(&path).unwrap();
+        assert!(frames.iter().any(|frame| !frame.player_joins.is_empty()));
+        assert!(frames.iter().any(|frame| !frame.player_leaves.is_empty()));
+        assert_eq!(
+            frames
+                .iter()
+                .map(|frame| frame.admin_commands.len())
+                .sum::<usize>(),
+            3
+        );
+        assert_eq!(
+            frames
+                .iter()
+                .filter_map(|frame| frame.time_speed)
+                .collect::<Vec<_>>(),
+            vec![2., 1.]
+        );
+
+        let report = run_replay(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(!report.skill_db_mismatch);
+        assert!(report.compared_snapshots >= 3, "{report:?}");
+        assert!(report.divergences.is_empty(), "{:?}", report.divergences);
+    }
+}
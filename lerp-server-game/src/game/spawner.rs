@@ -0,0 +1,195 @@
+// Enemy spawners are placed on the `E` cells of the map and stay dormant until a player comes close.
+// Once activated, a spawner spawns its packs (several escalating waves in wave mode),
+// and starts again after a delay once all of them are killed.
+// Each spawner uses the settings preset named by the map for its cell, or the default one.
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use lerp_common_game::prelude::*;
+use serde::Deserialize;
+
+use super::data::load_data_file;
+use super::enemy::{spawn_enemy_pack, EnemyArchetypes};
+
+const SPAWNERS_FILE: &str = "spawners.json";
+const EMBEDDED_SPAWNERS: &str = include_str!("../../data/spawners.json");
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct WaveSettings {
+    /// Number of waves spawned after the activation
+    pub count: u32,
+    /// In seconds between two waves
+    pub interval: f32,
+    /// Enemies added to the pack size at each wave
+    pub pack_growth: u32,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct SpawnerSettings {
+    pub pack_size: u32,
+    pub archetype: String,
+    /// In meters, distance at which a player activates a spawner
+    pub activation_radius: f32,
+    /// In seconds, delay before spawning again once all packs are killed, never when not set
+    pub respawn_delay: Option<f32>,
+    pub waves: Option<WaveSettings>,
+}
+
+impl SpawnerSettings {
+    fn wave_count(&self) -> u32 {
+        self.waves.as_ref().map_or(1, |waves| waves.count.max(1))
+    }
+
+    fn pack_size(&self, wave: u32) -> u32 {
+        self.pack_size
+            + self
+                .waves
+                .as_ref()
+                .map_or(0, |waves| waves.pack_growth * wave)
+    }
+}
+
+/// Named spawner settings, the maps pick one per spawner
+#[derive(Resource, Deserialize, Clone, Debug)]
+pub struct SpawnerPresets {
+    /// Preset of the spawners the map does not name one for
+    pub default: String,
+    pub presets: HashMap<String, SpawnerSettings>,
+}
+
+impl SpawnerPresets {
+    pub fn load() -> Result<Self, String> {
+        let presets: Self = load_data_file(SPAWNERS_FILE, EMBEDDED_SPAWNERS)?;
+        if !presets.presets.contains_key(&presets.default) {
+            return Err(format!(
+                "Invalid {}: unknown default preset {}",
+                SPAWNERS_FILE, presets.default
+            ));
+        }
+        Ok(presets)
+    }
+
+    /// Settings of the preset, or the default ones when it is unknown
+    fn get(&self, preset: &str) -> &SpawnerSettings {
+        self.presets
+            .get(preset)
+            .unwrap_or(&self.presets[&self.default])
+    }
+}
+
+impl Default for SpawnerPresets {
+    fn default() -> Self {
+        Self::load().unwrap_or_else(|err| {
+            error!("[SpawnerPresets] {}, using the embedded presets", err);
+            serde_json::from_str(EMBEDDED_SPAWNERS).unwrap()
+        })
+    }
+}
+
+/// Server only, enemies are replicated but not their spawner
+#[derive(Component, Debug)]
+pub struct EnemySpawner {
+    pub position: Vec2,
+    /// See [`SpawnerPresets`], read on every update so that the reloaded settings apply
+    pub preset: String,
+    pub activated: bool,
+    /// Waves spawned since the activation or the last respawn
+    wave: u32,
+    /// Before the next wave or the respawn, the next pack is spawned right away when not set
+    timer: Option<Timer>,
+    /// Alive enemies spawned by this spawner
+    pack: Vec<Entity>,
+}
+
+pub(crate) fn spawn_enemy_spawners(
+    mut commands: Commands,
+    map: Res<Map>,
+    presets: Res<SpawnerPresets>,
+) {
+    for (position, preset) in map.enemy_spawn_positions.iter() {
+        let preset = match preset {
+            Some(preset) if presets.presets.contains_key(preset) => preset.clone(),
+            Some(preset) => {
+                error!(
+                    "[spawn_enemy_spawners] Unknown spawner preset {}, using {}",
+                    preset, presets.default
+                );
+                presets.default.clone()
+            }
+            None => presets.default.clone(),
+        };
+        commands.spawn(EnemySpawner {
+            position: *position,
+            preset,
+            activated: false,
+            wave: 0,
+            timer: None,
+            pack: Vec::new(),
+        });
+    }
+}
+
+pub(crate) fn update_enemy_spawners(
+    time: Res<Time>,
+    mut commands: Commands,
+    map: Res<Map>,
+    presets: Res<SpawnerPresets>,
+    archetypes: Res<EnemyArchetypes>,
+    player_q: Query<&Position, (With<Player>, With<Alive>)>,
+    // Spawned enemies only become Alive on the next tick
+    alive_enemy_q: Query<(), (With<Character>, Without<Dying>, Without<Dead>)>,
+    mut spawner_q: Query<&mut EnemySpawner>,
+) {
+    for mut spawner in spawner_q.iter_mut() {
+        let spawner = &mut *spawner;
+        let settings = presets.get(&spawner.preset);
+        let activation_radius = settings.activation_radius * PIXEL_METER;
+        spawner.pack.retain(|enemy| alive_enemy_q.contains(*enemy));
+
+        if !spawner.activated {
+            let player_in_range = player_q
+                .iter()
+                .any(|position| position.0.distance(spawner.position) <= activation_radius);
+            if !player_in_range {
+                continue;
+            }
+            spawner.activated = true;
+        }
+
+        if spawner.wave >= settings.wave_count() {
+            let Some(respawn_delay) = settings.respawn_delay else {
+                continue;
+            };
+            if !spawner.pack.is_empty() {
+                continue;
+            }
+            spawner.wave = 0;
+            spawner.timer = Some(Timer::from_seconds(respawn_delay, TimerMode::Once));
+        }
+
+        if let Some(timer) = &mut spawner.timer {
+            timer.tick(time.delta());
+            if !timer.finished() {
+                continue;
+            }
+        }
+
+        match archetypes.0.get(&settings.archetype) {
+            Some(archetype) => {
+                let pack_size = settings.pack_size(spawner.wave);
+                let pack =
+                    spawn_enemy_pack(&mut commands, &map, spawner.position, pack_size, archetype);
+                spawner.pack.extend(pack);
+            }
+            None => error!(
+                "[update_enemy_spawners] Unknown enemy archetype: {}",
+                settings.archetype
+            ),
+        }
+        spawner.wave += 1;
+        spawner.timer = settings
+            .waves
+            .as_ref()
+            .map(|waves| Timer::from_seconds(waves.interval, TimerMode::Once));
+    }
+}
@@ -14,8 +14,15 @@ use tokio::{
 use tracing::*;
 use uuid::Uuid;
 
+use crate::game::admin::AdminRequest;
+use crate::game::party::InstanceParty;
 use crate::game::{start_game_world, GameInstanceConfig};
+use admin::*;
 use lerp_common_game::prelude::*;
+use party::*;
+
+mod admin;
+pub(crate) mod party;
 
 const MIN_UDP_PORT: u16 = 34000;
 const MAX_UDP_PORT: u16 = 34005;
@@ -26,11 +33,14 @@ struct GameInstance {
     port: u16,
     thread_join_handle: Option<JoinHandle<()>>,
     in_exit_channel_tx: Option<oneshot::Sender<bool>>,
+    admin_tx: mpsc::Sender<AdminRequest>,
+    map: String,
 }
 
 #[derive(Clone)]
 struct AppStateDyn {
     pub instance_repo: Arc<dyn GameInstanceRepo>,
+    pub party_repo: Arc<dyn PartyRepo>,
 }
 
 trait GameInstanceRepo: Send + Sync {
@@ -79,20 +89,76 @@ impl GameInstanceRepo for InMemoryGameInstanceRepo {
     }
 }
 
+/// Members of a party share the instance started by their leader
 async fn post_server_start(
     State(state): State<AppStateDyn>,
+    Json(payload): Json<HttpStartServerInput>,
 ) -> (StatusCode, Json<HttpStartServerResponse>) {
+    let party = state.party_repo.get_by_member(&payload.username);
+    if let Some(party) = &party {
+        if let Some((port, uuid)) = party.instance {
+            let running_map = state.instance_repo.get(port).and_then(|game_instance| {
+                let lock = game_instance.lock().unwrap();
+                (lock.uuid == uuid).then(|| lock.map.clone())
+            });
+            if let Some(map) = running_map {
+                let response = HttpStartServerResponse {
+                    instance_port: port,
+                    instance_uuid: uuid,
+                    map,
+                };
+                return (StatusCode::OK, Json(response));
+            }
+        }
+
+        if party.leader != payload.username {
+            warn!("[post_server_start] Only the party leader can start an instance");
+            let response = HttpStartServerResponse {
+                instance_port: 0,
+                instance_uuid: Uuid::nil(),
+                map: String::new(),
+            };
+            return (StatusCode::FORBIDDEN, Json(response));
+        }
+    }
+
+    let mut map = payload.map.unwrap_or_else(|| DEFAULT_MAP_NAME.to_string());
+    // A new dungeon is generated when no seed is given
+    if map == GENERATED_MAP_PREFIX {
+        map = generated_map_name(Uuid::new_v4().as_u64_pair().0);
+    }
+    if let Err(err) = MapFile::from_name(&map) {
+        warn!("[post_server_start] Invalid map {}: {}", map, err);
+        let response = HttpStartServerResponse {
+            instance_port: 0,
+            instance_uuid: Uuid::nil(),
+            map,
+        };
+        return (StatusCode::BAD_REQUEST, Json(response));
+    }
+
     for port in MIN_UDP_PORT..=MAX_UDP_PORT {
         if state.instance_repo.get(port).is_some() {
             continue;
         }
 
         let (tx, rx) = oneshot::channel();
+        let (admin_tx, admin_rx) = mpsc::channel(16);
 
         let game_instance_config = GameInstanceConfig {
             port,
             exit_channel_rx: rx,
             instance_exit_tx: state.instance_repo.get_instance_exit_tx(),
+            party: InstanceParty {
+                party_id: party.as_ref().map(|party| party.id),
+                members: party
+                    .as_ref()
+                    .map(|party| party.members.iter().cloned().collect())
+                    .unwrap_or_default(),
+            },
+            party_repo: state.party_repo.clone(),
+            admin_rx,
+            map: map.clone(),
         };
         let thread_join_handle = thread::spawn(move || {
             start_game_world(game_instance_config);
@@ -104,11 +170,17 @@ async fn post_server_start(
             port,
             thread_join_handle: Some(thread_join_handle),
             in_exit_channel_tx: Some(tx),
+            admin_tx,
+            map: map.clone(),
         });
+        if let Some(party) = &party {
+            state.party_repo.set_instance(party.id, Some((port, uuid)));
+        }
 
         let response = HttpStartServerResponse {
             instance_port: port,
             instance_uuid: uuid,
+            map,
         };
         return (StatusCode::OK, Json(response));
     }
@@ -117,6 +189,7 @@ async fn post_server_start(
     let response = HttpStartServerResponse {
         instance_port: 0,
         instance_uuid: Uuid::nil(),
+        map,
     };
     (StatusCode::SERVICE_UNAVAILABLE, Json(response))
 }
@@ -152,17 +225,25 @@ async fn post_server_stop(
     (StatusCode::BAD_REQUEST, Json(response))
 }
 
-pub(crate) async fn start_http_api() {
+pub async fn start_http_api() {
     let (tx, mut rx) = mpsc::channel(100);
 
     let app_state_1 = AppStateDyn {
         instance_repo: Arc::new(InMemoryGameInstanceRepo::new(tx)),
+        party_repo: Arc::new(InMemoryPartyRepo::default()),
     };
     let app_state_2 = app_state_1.clone();
 
     let app = Router::new()
         .route("/server/start", post(post_server_start))
         .route("/server/stop", post(post_server_stop))
+        .route("/party/get", post(post_party_get))
+        .route("/party/create", post(post_party_create))
+        .route("/party/invite", post(post_party_invite))
+        .route("/party/accept", post(post_party_accept))
+        .route("/party/leave", post(post_party_leave))
+        .route("/party/leader", post(post_party_leader))
+        .route("/admin/command", post(post_admin_command))
         .with_state(app_state_1);
 
     let task = tokio::spawn(async move {
@@ -0,0 +1,59 @@
+// Admin commands are forwarded to the game world of the instance, which answers once executed.
+use axum::{extract::State, http::StatusCode, Json};
+use lightyear::prelude::ClientId;
+use tokio::sync::oneshot;
+use tracing::*;
+
+use crate::game::admin::{AdminConfig, AdminRequest};
+use lerp_common_game::prelude::*;
+
+use super::AppStateDyn;
+
+fn admin_response(
+    status: StatusCode,
+    message: String,
+) -> (StatusCode, Json<HttpAdminCommandResponse>) {
+    let response = HttpAdminCommandResponse {
+        success: status == StatusCode::OK,
+        message,
+    };
+    (status, Json(response))
+}
+
+pub(super) async fn post_admin_command(
+    State(state): State<AppStateDyn>,
+    Json(payload): Json<HttpAdminCommandInput>,
+) -> (StatusCode, Json<HttpAdminCommandResponse>) {
+    if !AdminConfig::default().is_valid_token(&payload.token) {
+        warn!("[post_admin_command] Invalid admin token");
+        return admin_response(StatusCode::FORBIDDEN, "Invalid admin token".to_string());
+    }
+
+    let admin_tx = state
+        .instance_repo
+        .get(payload.instance_port)
+        .and_then(|game_instance| {
+            let game_instance = game_instance.lock().unwrap();
+            (game_instance.uuid == payload.instance_uuid).then(|| game_instance.admin_tx.clone())
+        });
+    let Some(admin_tx) = admin_tx else {
+        warn!("[post_admin_command] Invalid instance");
+        return admin_response(StatusCode::BAD_REQUEST, "Invalid instance".to_string());
+    };
+
+    let (reply_tx, reply_rx) = oneshot::channel();
+    let request = AdminRequest {
+        command: payload.command,
+        client_id: payload.client_id.map(ClientId::Netcode),
+        reply_tx,
+    };
+    if admin_tx.send(request).await.is_err() {
+        return admin_response(StatusCode::GONE, "Instance stopped".to_string());
+    }
+
+    match reply_rx.await {
+        Ok(Ok(message)) => admin_response(StatusCode::OK, message),
+        Ok(Err(message)) => admin_response(StatusCode::BAD_REQUEST, message),
+        Err(_) => admin_response(StatusCode::GONE, "Instance stopped".to_string()),
+    }
+}
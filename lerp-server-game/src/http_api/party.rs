@@ -0,0 +1,382 @@
+// Parties are managed in the lobby, before starting an instance.
+// A user is in at most one party, the leader invites users and starts the instance of the party.
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use axum::{extract::State, http::StatusCode, Json};
+use tracing::*;
+use uuid::Uuid;
+
+use lerp_common_game::prelude::*;
+
+use super::AppStateDyn;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Party {
+    pub id: Uuid,
+    pub leader: String,
+    pub members: Vec<String>,
+    pub invited: Vec<String>,
+    /// Port and uuid of the instance started by the leader
+    pub instance: Option<(u16, Uuid)>,
+}
+
+impl Party {
+    fn to_http(&self) -> HttpParty {
+        HttpParty {
+            party_id: self.id,
+            leader: self.leader.clone(),
+            members: self.members.clone(),
+            invited: self.invited.clone(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) enum PartyError {
+    AlreadyInParty,
+    NotInParty,
+    NotLeader,
+    NotInvited,
+    PartyFull,
+    NotAMember,
+}
+
+impl PartyError {
+    fn message(&self) -> &'static str {
+        match self {
+            PartyError::AlreadyInParty => "Already in a party",
+            PartyError::NotInParty => "Not in a party",
+            PartyError::NotLeader => "Only the party leader can do this",
+            PartyError::NotInvited => "Not invited to this party",
+            PartyError::PartyFull => "Party is full",
+            PartyError::NotAMember => "Not a member of the party",
+        }
+    }
+}
+
+pub(crate) trait PartyRepo: Send + Sync {
+    fn get_by_member(&self, username: &str) -> Option<Party>;
+
+    fn get_invites(&self, username: &str) -> Vec<Party>;
+
+    fn create(&self, username: &str) -> Result<(), PartyError>;
+
+    fn invite(&self, username: &str, invitee: &str) -> Result<(), PartyError>;
+
+    fn accept(&self, username: &str, party_id: Uuid) -> Result<(), PartyError>;
+
+    fn leave(&self, username: &str) -> Result<(), PartyError>;
+
+    fn set_leader(&self, username: &str, leader: &str) -> Result<(), PartyError>;
+
+    fn set_instance(&self, party_id: Uuid, instance: Option<(u16, Uuid)>);
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct InMemoryPartyRepo {
+    map: Arc<Mutex<HashMap<Uuid, Party>>>,
+}
+
+fn find_member<'a>(parties: &'a mut HashMap<Uuid, Party>, username: &str) -> Option<&'a mut Party> {
+    parties
+        .values_mut()
+        .find(|party| party.members.iter().any(|member| member == username))
+}
+
+fn find_led<'a>(
+    parties: &'a mut HashMap<Uuid, Party>,
+    username: &str,
+) -> Result<&'a mut Party, PartyError> {
+    let party = find_member(parties, username).ok_or(PartyError::NotInParty)?;
+    if party.leader != username {
+        return Err(PartyError::NotLeader);
+    }
+    Ok(party)
+}
+
+impl PartyRepo for InMemoryPartyRepo {
+    fn get_by_member(&self, username: &str) -> Option<Party> {
+        find_member(&mut self.map.lock().unwrap(), username).cloned()
+    }
+
+    fn get_invites(&self, username: &str) -> Vec<Party> {
+        self.map
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|party| party.invited.iter().any(|invited| invited == username))
+            .cloned()
+            .collect()
+    }
+
+    fn create(&self, username: &str) -> Result<(), PartyError> {
+        let mut parties = self.map.lock().unwrap();
+        if find_member(&mut parties, username).is_some() {
+            return Err(PartyError::AlreadyInParty);
+        }
+
+        let party = Party {
+            id: Uuid::new_v4(),
+            leader: username.to_string(),
+            members: vec![username.to_string()],
+            invited: Vec::new(),
+            instance: None,
+        };
+        parties.insert(party.id, party);
+        Ok(())
+    }
+
+    fn invite(&self, username: &str, invitee: &str) -> Result<(), PartyError> {
+        let mut parties = self.map.lock().unwrap();
+        let party = find_led(&mut parties, username)?;
+        if party.members.iter().any(|member| member == invitee) {
+            return Err(PartyError::AlreadyInParty);
+        }
+        if party.members.len() + party.invited.len() >= PARTY_MAX_MEMBERS {
+            return Err(PartyError::PartyFull);
+        }
+        if !party.invited.iter().any(|invited| invited == invitee) {
+            party.invited.push(invitee.to_string());
+        }
+        Ok(())
+    }
+
+    fn accept(&self, username: &str, party_id: Uuid) -> Result<(), PartyError> {
+        let mut parties = self.map.lock().unwrap();
+        if find_member(&mut parties, username).is_some() {
+            return Err(PartyError::AlreadyInParty);
+        }
+        let party = parties.get_mut(&party_id).ok_or(PartyError::NotInvited)?;
+        let Some(index) = party.invited.iter().position(|invited| invited == username) else {
+            return Err(PartyError::NotInvited);
+        };
+
+        party.invited.remove(index);
+        party.members.push(username.to_string());
+
+        // A user joins a single party, other invites are dropped
+        for party in parties.values_mut() {
+            party.invited.retain(|invited| invited != username);
+        }
+        Ok(())
+    }
+
+    fn leave(&self, username: &str) -> Result<(), PartyError> {
+        let mut parties = self.map.lock().unwrap();
+        let party = find_member(&mut parties, username).ok_or(PartyError::NotInParty)?;
+
+        party.members.retain(|member| member != username);
+        if party.leader == username {
+            if let Some(member) = party.members.first() {
+                party.leader = member.clone();
+            }
+        }
+        if party.members.is_empty() {
+            let party_id = party.id;
+            parties.remove(&party_id);
+        }
+        Ok(())
+    }
+
+    fn set_leader(&self, username: &str, leader: &str) -> Result<(), PartyError> {
+        let mut parties = self.map.lock().unwrap();
+        let party = find_led(&mut parties, username)?;
+        if !party.members.iter().any(|member| member == leader) {
+            return Err(PartyError::NotAMember);
+        }
+        party.leader = leader.to_string();
+        Ok(())
+    }
+
+    fn set_instance(&self, party_id: Uuid, instance: Option<(u16, Uuid)>) {
+        if let Some(party) = self.map.lock().unwrap().get_mut(&party_id) {
+            party.instance = instance;
+        }
+    }
+}
+
+/// Current party state of the user, with the error of the request if any
+fn party_response(
+    state: &AppStateDyn,
+    username: &str,
+    result: Result<(), PartyError>,
+) -> (StatusCode, Json<HttpPartyResponse>) {
+    let (status, error) = match result {
+        Ok(()) => (StatusCode::OK, None),
+        Err(err) => {
+            warn!("[party_response] {}: {:?}", username, err);
+            (StatusCode::BAD_REQUEST, Some(err.message().to_string()))
+        }
+    };
+
+    let response = HttpPartyResponse {
+        party: state
+            .party_repo
+            .get_by_member(username)
+            .map(|party| party.to_http()),
+        invites: state
+            .party_repo
+            .get_invites(username)
+            .iter()
+            .map(Party::to_http)
+            .collect(),
+        error,
+    };
+    (status, Json(response))
+}
+
+pub(super) async fn post_party_get(
+    State(state): State<AppStateDyn>,
+    Json(payload): Json<HttpPartyInput>,
+) -> (StatusCode, Json<HttpPartyResponse>) {
+    party_response(&state, &payload.username, Ok(()))
+}
+
+pub(super) async fn post_party_create(
+    State(state): State<AppStateDyn>,
+    Json(payload): Json<HttpPartyInput>,
+) -> (StatusCode, Json<HttpPartyResponse>) {
+    let result = state.party_repo.create(&payload.username);
+    party_response(&state, &payload.username, result)
+}
+
+pub(super) async fn post_party_invite(
+    State(state): State<AppStateDyn>,
+    Json(payload): Json<HttpPartyMemberInput>,
+) -> (StatusCode, Json<HttpPartyResponse>) {
+    let result = state.party_repo.invite(&payload.username, &payload.member);
+    party_response(&state, &payload.username, result)
+}
+
+pub(super) async fn post_party_accept(
+    State(state): State<AppStateDyn>,
+    Json(payload): Json<HttpPartyAcceptInput>,
+) -> (StatusCode, Json<HttpPartyResponse>) {
+    let result = state.party_repo.accept(&payload.username, payload.party_id);
+    party_response(&state, &payload.username, result)
+}
+
+pub(super) async fn post_party_leave(
+    State(state): State<AppStateDyn>,
+    Json(payload): Json<HttpPartyInput>,
+) -> (StatusCode, Json<HttpPartyResponse>) {
+    let result = state.party_repo.leave(&payload.username);
+    party_response(&state, &payload.username, result)
+}
+
+pub(super) async fn post_party_leader(
+    State(state): State<AppStateDyn>,
+    Json(payload): Json<HttpPartyMemberInput>,
+) -> (StatusCode, Json<HttpPartyResponse>) {
+    let result = state
+        .party_repo
+        .set_leader(&payload.username, &payload.member);
+    party_response(&state, &payload.username, result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn party_of(repo: &InMemoryPartyRepo, username: &str) -> Party {
+        repo.get_by_member(username).unwrap()
+    }
+
+    #[test]
+    fn invited_user_joins_the_party_on_accept() {
+        let repo = InMemoryPartyRepo::default();
+        repo.create("leader").unwrap();
+        repo.invite("leader", "friend").unwrap();
+        let party = party_of(&repo, "leader");
+        assert_eq!(party.invited, vec!["friend".to_string()]);
+        assert!(repo.get_by_member("friend").is_none());
+        assert_eq!(repo.get_invites("friend").len(), 1);
+
+        repo.accept("friend", party.id).unwrap();
+        let party = party_of(&repo, "friend");
+        assert_eq!(
+            party.members,
+            vec!["leader".to_string(), "friend".to_string()]
+        );
+        assert!(party.invited.is_empty());
+        assert!(repo.get_invites("friend").is_empty());
+    }
+
+    #[test]
+    fn only_the_leader_invites() {
+        let repo = InMemoryPartyRepo::default();
+        assert!(matches!(
+            repo.invite("nobody", "friend"),
+            Err(PartyError::NotInParty)
+        ));
+
+        repo.create("leader").unwrap();
+        repo.invite("leader", "member").unwrap();
+        repo.accept("member", party_of(&repo, "leader").id).unwrap();
+        assert!(matches!(
+            repo.invite("member", "friend"),
+            Err(PartyError::NotLeader)
+        ));
+        assert!(matches!(
+            repo.invite("leader", "member"),
+            Err(PartyError::AlreadyInParty)
+        ));
+    }
+
+    #[test]
+    fn invites_are_limited_to_the_party_size() {
+        let repo = InMemoryPartyRepo::default();
+        repo.create("leader").unwrap();
+        for i in 1..PARTY_MAX_MEMBERS {
+            repo.invite("leader", &format!("friend{}", i)).unwrap();
+        }
+        assert!(matches!(
+            repo.invite("leader", "one too many"),
+            Err(PartyError::PartyFull)
+        ));
+    }
+
+    #[test]
+    fn accept_requires_an_invite_and_drops_the_others() {
+        let repo = InMemoryPartyRepo::default();
+        repo.create("leader").unwrap();
+        repo.create("other leader").unwrap();
+        let party_id = party_of(&repo, "leader").id;
+        let other_party_id = party_of(&repo, "other leader").id;
+        assert!(matches!(
+            repo.accept("friend", party_id),
+            Err(PartyError::NotInvited)
+        ));
+
+        repo.invite("leader", "friend").unwrap();
+        repo.invite("other leader", "friend").unwrap();
+        repo.accept("friend", party_id).unwrap();
+        assert!(party_of(&repo, "other leader").invited.is_empty());
+        assert!(matches!(
+            repo.accept("friend", other_party_id),
+            Err(PartyError::AlreadyInParty)
+        ));
+    }
+
+    #[test]
+    fn leaving_leader_hands_over_and_last_member_disbands() {
+        let repo = InMemoryPartyRepo::default();
+        repo.create("leader").unwrap();
+        repo.invite("leader", "friend").unwrap();
+        repo.accept("friend", party_of(&repo, "leader").id).unwrap();
+
+        repo.leave("leader").unwrap();
+        assert!(repo.get_by_member("leader").is_none());
+        let party = party_of(&repo, "friend");
+        assert_eq!(party.leader, "friend");
+        assert_eq!(party.members, vec!["friend".to_string()]);
+
+        repo.leave("friend").unwrap();
+        assert!(repo.get_by_member("friend").is_none());
+        assert!(repo.map.lock().unwrap().is_empty());
+        assert!(matches!(repo.leave("friend"), Err(PartyError::NotInParty)));
+    }
+}
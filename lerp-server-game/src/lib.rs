@@ -0,0 +1,3 @@
+pub mod game;
+pub mod http_api;
+pub mod loopback;
@@ -0,0 +1,249 @@
+// Run one game server world and N headless client worlds in the same process.
+// Worlds are connected through in-memory crossbeam channels instead of UDP sockets and
+// are stepped manually, which makes it possible to assert netcode behaviour from tests.
+use std::net::{Ipv4Addr, SocketAddr};
+use std::time::Duration;
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+use leafwing_input_manager::prelude::*;
+use lerp_common_game::prelude::*;
+use lightyear::prelude::client::{self, ClientCommands, Interpolated, Predicted};
+use lightyear::prelude::server::{self, ServerTransport};
+use lightyear::prelude::*;
+use lightyear::shared::replication::components::Controlled;
+
+use crate::game::enemy::{spawn_enemy, EnemyArchetypes, DEFAULT_ENEMY_ARCHETYPE};
+use crate::game::{build_game_world, netcode_config};
+
+/// Max number of frames to wait for all the clients to be connected and own a predicted player
+const MAX_INIT_FRAMES: usize = 1000;
+
+pub struct LoopbackStepper {
+    pub server_app: App,
+    pub client_apps: Vec<App>,
+    pub join_modes: Vec<JoinMode>,
+    pub frame_duration: Duration,
+}
+
+impl LoopbackStepper {
+    pub fn new(client_count: usize) -> Self {
+        Self::with_join_modes(vec![JoinMode::Player; client_count])
+    }
+
+    /// One client per given join mode
+    pub fn with_join_modes(join_modes: Vec<JoinMode>) -> Self {
+        let frame_duration = Duration::from_secs_f64(1.0 / FIXED_TIMESTEP_HZ);
+        let server_addr = SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 0);
+
+        let mut server_channels = Vec::new();
+        let mut client_apps = Vec::new();
+
+        for (i, join_mode) in join_modes.iter().enumerate() {
+            let (from_server_send, from_server_recv) = crossbeam_channel::unbounded();
+            let (to_server_send, to_server_recv) = crossbeam_channel::unbounded();
+            let client_addr = SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 10000 + i as u16);
+            server_channels.push((client_addr, to_server_recv, from_server_send));
+
+            let net_config = client::NetConfig::Netcode {
+                auth: client::Authentication::Manual {
+                    server_addr,
+                    client_id: i as u64 + 1,
+                    private_key: [0; 32],
+                    protocol_id: 0,
+                },
+                io: client::IoConfig::from_transport(client::ClientTransport::LocalChannel {
+                    recv: from_server_recv,
+                    send: to_server_send,
+                }),
+                config: client::NetcodeConfig::default(),
+            };
+
+            client_apps.push(build_loopback_client(
+                net_config,
+                *join_mode,
+                frame_duration,
+            ));
+        }
+
+        let mut server_app = build_game_world(
+            vec![server::NetConfig::Netcode {
+                config: netcode_config(),
+                io: server::IoConfig {
+                    transport: ServerTransport::Channels {
+                        channels: server_channels,
+                    },
+                    ..default()
+                },
+            }],
+            0,
+        );
+        server_app.insert_resource(TimeUpdateStrategy::ManualDuration(frame_duration));
+        server_app.finish();
+        server_app.cleanup();
+
+        Self {
+            server_app,
+            client_apps,
+            join_modes,
+            frame_duration,
+        }
+    }
+
+    /// Connect all the clients and step until each player controls its predicted player
+    /// and each spectator receives the interpolated world.
+    pub fn init(&mut self) {
+        for client_app in &mut self.client_apps {
+            client_app
+                .world_mut()
+                .run_system_once(|mut commands: Commands| commands.connect_client())
+                .unwrap();
+        }
+
+        for _ in 0..MAX_INIT_FRAMES {
+            self.frame_step();
+            if (0..self.client_apps.len()).all(|i| self.client_joined(i)) {
+                return;
+            }
+        }
+
+        panic!("[LoopbackStepper] Clients did not join in time");
+    }
+
+    fn client_joined(&mut self, client_index: usize) -> bool {
+        match self.join_modes[client_index] {
+            JoinMode::Player => self.client_player(client_index).is_some(),
+            JoinMode::Spectator => {
+                let world = self.client_world(client_index);
+                world
+                    .query_filtered::<Entity, (With<Player>, With<Interpolated>)>()
+                    .iter(world)
+                    .next()
+                    .is_some()
+            }
+        }
+    }
+
+    /// Advance every world by one frame, the server is always updated first.
+    pub fn frame_step(&mut self) {
+        self.server_app.update();
+        for client_app in &mut self.client_apps {
+            client_app.update();
+        }
+    }
+
+    pub fn frame_step_n(&mut self, n: usize) {
+        for _ in 0..n {
+            self.frame_step();
+        }
+    }
+
+    pub fn server_world(&mut self) -> &mut World {
+        self.server_app.world_mut()
+    }
+
+    pub fn client_world(&mut self, client_index: usize) -> &mut World {
+        self.client_apps[client_index].world_mut()
+    }
+
+    /// Predicted player entity controlled by the given client.
+    pub fn client_player(&mut self, client_index: usize) -> Option<Entity> {
+        let world = self.client_world(client_index);
+        world
+            .query_filtered::<Entity, (With<Player>, With<Predicted>, With<Controlled>)>()
+            .iter(world)
+            .next()
+    }
+
+    /// Server player entity owned by the given client.
+    pub fn server_player(&mut self, client_index: usize) -> Option<Entity> {
+        let client_id = ClientId::Netcode(client_index as u64 + 1);
+        let world = self.server_world();
+        world
+            .query::<&PlayerClient>()
+            .iter(world)
+            .find(|player_client| player_client.client_id == client_id)
+            .map(|player_client| player_client.player_ref)
+    }
+
+    /// Spawn an enemy of the default archetype on the server, it is replicated during the next frames.
+    pub fn spawn_server_enemy(&mut self, position: Vec2) -> Entity {
+        let world = self.server_world();
+        let archetype = world.resource::<EnemyArchetypes>().0[DEFAULT_ENEMY_ARCHETYPE].clone();
+        world
+            .run_system_once(move |mut commands: Commands| {
+                spawn_enemy(&mut commands, position, &archetype)
+            })
+            .unwrap()
+    }
+
+    /// Mutate the action state of the player controlled by the given client.
+    /// The inputs will be sent to the server during the next frames.
+    pub fn client_input(
+        &mut self,
+        client_index: usize,
+        f: impl FnOnce(&mut ActionState<PlayerActions>),
+    ) {
+        let player = self
+            .client_player(client_index)
+            .expect("[LoopbackStepper] Client does not control any player");
+        let mut entity = self.client_world(client_index).entity_mut(player);
+        let mut action_state = entity
+            .get_mut::<ActionState<PlayerActions>>()
+            .expect("[LoopbackStepper] Player has no ActionState");
+        f(&mut action_state);
+    }
+}
+
+fn build_loopback_client(
+    net: client::NetConfig,
+    join_mode: JoinMode,
+    frame_duration: Duration,
+) -> App {
+    let client_config = client::ClientConfig {
+        shared: shared_config(),
+        net,
+        replication: ReplicationConfig {
+            send_updates_mode: SendUpdatesMode::SinceLastAck,
+        },
+        prediction: client_prediction_config(),
+        ..default()
+    };
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, StatesPlugin))
+        .add_plugins(client::ClientPlugins::new(client_config))
+        .add_plugins(SharedPlugin)
+        .insert_resource(TimeUpdateStrategy::ManualDuration(frame_duration))
+        .insert_resource(join_mode)
+        .init_resource::<Username>()
+        .add_systems(Update, receive_map_data)
+        .add_systems(OnEnter(client::NetworkingState::Connected), send_join_game)
+        .add_systems(PreUpdate, init_loopback_player_input);
+    app.finish();
+    app.cleanup();
+    app
+}
+
+/// Headless equivalent of the client `handle_new_player`, without any key binding
+fn init_loopback_player_input(
+    mut commands: Commands,
+    player_q: Query<
+        Entity,
+        (
+            With<Player>,
+            With<Predicted>,
+            With<Controlled>,
+            Without<ActionState<PlayerActions>>,
+        ),
+    >,
+) {
+    for entity in player_q.iter() {
+        commands.entity(entity).insert((
+            InputMap::<PlayerActions>::default(),
+            ActionState::<PlayerActions>::default(),
+        ));
+    }
+}
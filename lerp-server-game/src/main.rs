@@ -1,10 +1,9 @@
-use bevy::log::Level;
-use http_api::start_http_api;
+use bevy::log::{error, info, warn, Level};
+use lerp_server_game::game::replay::run_replay;
+use lerp_server_game::http_api::start_http_api;
+use std::path::PathBuf;
 use tracing_subscriber::EnvFilter;
 
-pub(crate) mod game;
-mod http_api;
-
 #[tokio::main]
 async fn main() {
     tracing_subscriber::fmt()
@@ -15,5 +14,45 @@ async fn main() {
         )))
         .init();
 
+    let args: Vec<String> = std::env::args().collect();
+    if let Some(replay_path) = args
+        .iter()
+        .position(|arg| arg == "--replay")
+        .and_then(|i| args.get(i + 1))
+    {
+        replay(PathBuf::from(replay_path));
+        return;
+    }
+
     start_http_api().await;
 }
+
+/// Re-simulate a replay recorded with `LERP_REPLAY_DIR` and report any divergence
+fn replay(path: PathBuf) {
+    let report = match run_replay(&path) {
+        Ok(report) => report,
+        Err(err) => {
+            error!("Cannot read replay {}: {}", path.display(), err);
+            std::process::exit(1);
+        }
+    };
+
+    info!(
+        "Replayed {} ticks on map {}, {} snapshots compared",
+        report.simulated_ticks, report.header.map_name, report.compared_snapshots
+    );
+    if report.skill_db_mismatch {
+        warn!("Skill db changed since the replay was recorded");
+    }
+    if let Some(divergence) = report.divergences.first() {
+        error!(
+            "Replay diverged at tick {} ({} divergent snapshots)\nexpected: {:?}\nactual: {:?}",
+            divergence.fixed_tick,
+            report.divergences.len(),
+            divergence.expected,
+            divergence.actual
+        );
+        std::process::exit(1);
+    }
+    info!("Replay is deterministic");
+}
@@ -0,0 +1,274 @@
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use lerp_common_game::prelude::*;
+use lerp_server_game::game::bandwidth::{BandwidthStats, BANDWIDTH_REPORT_INTERVAL};
+use lerp_server_game::game::interest::InterestConfig;
+use lerp_server_game::loopback::LoopbackStepper;
+use lightyear::prelude::client::{Confirmed, Interpolated, Predicted};
+use lightyear::prelude::server::ReplicationTarget;
+
+fn init_stepper() -> LoopbackStepper {
+    let mut stepper = LoopbackStepper::new(1);
+    stepper.init();
+    stepper
+}
+
+#[test]
+fn predicted_projectiles_match_server_projectiles() {
+    let mut stepper = init_stepper();
+
+    let server_player = stepper.server_player(0).unwrap();
+    let player_position = stepper
+        .server_world()
+        .get::<Position>(server_player)
+        .unwrap()
+        .0;
+
+    stepper.client_input(0, |action_state| {
+        action_state.set_axis_pair(
+            &PlayerActions::Cursor,
+            player_position + Vec2::new(PIXEL_METER, 0.),
+        );
+        action_state.press(&PlayerActions::SkillSlot1);
+    });
+    stepper.frame_step_n(2);
+    stepper.client_input(0, |action_state| {
+        action_state.release(&PlayerActions::SkillSlot1);
+    });
+    stepper.frame_step_n(30);
+
+    let server_world = stepper.server_world();
+    let server_projectile_count = server_world
+        .query_filtered::<Entity, (With<Projectile>, With<ReplicationTarget>)>()
+        .iter(server_world)
+        .count();
+    assert!(
+        server_projectile_count > 0,
+        "Server did not spawn any projectile"
+    );
+
+    // Every confirmed projectile must have been matched with the projectile pre-spawned by the client
+    let client_world = stepper.client_world(0);
+    let confirmed_projectiles: Vec<_> = client_world
+        .query_filtered::<&Confirmed, With<Projectile>>()
+        .iter(client_world)
+        .map(|confirmed| confirmed.predicted)
+        .collect();
+    assert_eq!(confirmed_projectiles.len(), server_projectile_count);
+    assert!(confirmed_projectiles
+        .iter()
+        .all(|predicted| predicted.is_some()));
+}
+
+#[test]
+fn dead_is_replicated_to_clients() {
+    let mut stepper = init_stepper();
+
+    let server_player = stepper.server_player(0).unwrap();
+    let player_position = stepper
+        .server_world()
+        .get::<Position>(server_player)
+        .unwrap()
+        .0;
+    // Only enemies in the area of interest of the player are replicated
+    let enemy = stepper.spawn_server_enemy(player_position + Vec2::new(2. * PIXEL_METER, 0.));
+    stepper.frame_step_n(10);
+    assert!(stepper.server_world().get::<Alive>(enemy).is_some());
+    stepper
+        .server_world()
+        .get_mut::<Health>(enemy)
+        .unwrap()
+        .current = 0.;
+
+    // Dying lasts 0.8s before the character is Dead
+    stepper.frame_step_n(120);
+
+    assert!(stepper.server_world().get::<Dead>(enemy).is_some());
+
+    let client_world = stepper.client_world(0);
+    let dead_count = client_world
+        .query_filtered::<Entity, (With<Enemy>, With<Dead>, With<Predicted>)>()
+        .iter(client_world)
+        .count();
+    assert_eq!(dead_count, 1);
+}
+
+#[test]
+fn rollback_converges_to_server_state() {
+    let mut stepper = init_stepper();
+    stepper.frame_step_n(10);
+
+    // Move the server player without any input, the client can only find out through a rollback
+    let server_player = stepper.server_player(0).unwrap();
+    let expected_position = {
+        let mut position = stepper
+            .server_world()
+            .get_mut::<Position>(server_player)
+            .unwrap();
+        position.0 += Vec2::new(2. * PIXEL_METER, 0.);
+        position.0
+    };
+
+    stepper.frame_step_n(60);
+
+    let client_player = stepper.client_player(0).unwrap();
+    let client_position = stepper
+        .client_world(0)
+        .get::<Position>(client_player)
+        .unwrap()
+        .0;
+    assert!(
+        client_position.distance(expected_position) < 1.,
+        "Predicted position {client_position} did not converge to {expected_position}"
+    );
+}
+
+#[test]
+fn spectator_receives_interpolated_world_without_player() {
+    let mut stepper = LoopbackStepper::with_join_modes(vec![JoinMode::Player, JoinMode::Spectator]);
+    stepper.init();
+    stepper.frame_step_n(30);
+
+    let server_world = stepper.server_world();
+    let server_player_count = server_world
+        .query_filtered::<Entity, With<Player>>()
+        .iter(server_world)
+        .count();
+    assert_eq!(server_player_count, 1);
+
+    let spectator_world = stepper.client_world(1);
+    let predicted_count = spectator_world
+        .query_filtered::<Entity, With<Predicted>>()
+        .iter(spectator_world)
+        .count();
+    let interpolated_players = spectator_world
+        .query_filtered::<Entity, (With<Player>, With<Interpolated>)>()
+        .iter(spectator_world)
+        .count();
+    assert_eq!(predicted_count, 0);
+    assert_eq!(interpolated_players, 1);
+}
+
+#[test]
+fn far_entities_are_not_replicated() {
+    let mut stepper = init_stepper();
+    let config = stepper.server_world().resource::<InterestConfig>().clone();
+    let leave_radius = config.radius + config.hysteresis;
+
+    let server_player = stepper.server_player(0).unwrap();
+    let player_position = stepper
+        .server_world()
+        .get::<Position>(server_player)
+        .unwrap()
+        .0;
+
+    // Send one enemy away from the player once it was replicated
+    let enemy = stepper.spawn_server_enemy(player_position + Vec2::new(2. * PIXEL_METER, 0.));
+    stepper.frame_step_n(10);
+    stepper.server_world().get_mut::<Position>(enemy).unwrap().0 =
+        player_position + Vec2::new(leave_radius + 10. * PIXEL_METER, 0.);
+
+    stepper.frame_step_n(60);
+
+    let server_world = stepper.server_world();
+    let server_enemy_count = server_world
+        .query_filtered::<Entity, With<Enemy>>()
+        .iter(server_world)
+        .count();
+
+    let client_world = stepper.client_world(0);
+    let client_positions: Vec<Vec2> = client_world
+        .query_filtered::<&Position, With<Confirmed>>()
+        .iter(client_world)
+        .map(|position| position.0)
+        .collect();
+    let client_enemy_count = client_world
+        .query_filtered::<Entity, (With<Enemy>, With<Confirmed>)>()
+        .iter(client_world)
+        .count();
+
+    assert!(client_enemy_count < server_enemy_count);
+    // Entities may have moved a bit since they were last replicated
+    let margin = 2. * PIXEL_METER;
+    for position in client_positions {
+        assert!(
+            position.distance(player_position) <= leave_radius + margin,
+            "Entity at {position} is outside of the area of interest"
+        );
+    }
+}
+
+#[test]
+fn bandwidth_is_measured_on_the_transport() {
+    let mut stepper = init_stepper();
+    let report_frames = (BANDWIDTH_REPORT_INTERVAL.as_secs_f64() * FIXED_TIMESTEP_HZ) as usize;
+    stepper.frame_step_n(report_frames + 10);
+
+    let stats = stepper.server_world().resource::<BandwidthStats>();
+    let measured = stats
+        .last_measured
+        .expect("The transport bandwidth was not measured");
+    assert!(measured.sent > 0., "{measured:?}");
+    assert!(measured.received > 0., "{measured:?}");
+}
+
+/// Bytes per second sent by the server while enemies walk around out of range of the only player,
+/// from the transport and from the `Position` estimate
+fn sent_bandwidth_with_far_enemies(interest: InterestConfig) -> (f64, f32) {
+    let mut stepper = LoopbackStepper::new(1);
+    stepper.server_world().insert_resource(interest);
+    stepper.init();
+
+    let server_player = stepper.server_player(0).unwrap();
+    let player_position = stepper
+        .server_world()
+        .get::<Position>(server_player)
+        .unwrap()
+        .0;
+    let far_distance = InterestConfig::default().radius * 2.;
+    let enemies: Vec<Entity> = (0..100)
+        .map(|i| {
+            let direction = Vec2::from_angle(i as f32 * std::f32::consts::TAU / 100.);
+            stepper.spawn_server_enemy(player_position + direction * far_distance)
+        })
+        .collect();
+
+    let report_frames = (BANDWIDTH_REPORT_INTERVAL.as_secs_f64() * FIXED_TIMESTEP_HZ) as usize;
+    for frame in 0..2 * report_frames {
+        // Enemies without a target do not move by themselves
+        let offset = Vec2::new(if frame % 2 == 0 { 1. } else { -1. }, 0.);
+        for enemy in enemies.iter() {
+            stepper
+                .server_world()
+                .get_mut::<Position>(*enemy)
+                .unwrap()
+                .0 += offset;
+        }
+        stepper.frame_step();
+    }
+
+    let stats = stepper.server_world().resource::<BandwidthStats>();
+    let measured = stats
+        .last_measured
+        .expect("The transport bandwidth was not measured");
+    let seconds = BANDWIDTH_REPORT_INTERVAL.as_secs_f32();
+    let estimated = stats
+        .last_report
+        .get("Position")
+        .map_or(0., |position| position.bytes as f32 / seconds);
+    (measured.sent, estimated)
+}
+
+#[test]
+fn interest_reduces_the_sent_bandwidth() {
+    let with_interest = sent_bandwidth_with_far_enemies(InterestConfig::default());
+    // Every entity is in range of the player
+    let without_interest = sent_bandwidth_with_far_enemies(InterestConfig::with_radius(10000.));
+    println!(
+        "Sent with interest: {:.0} B/s (Position {:.0} B/s), without: {:.0} B/s (Position {:.0} B/s)",
+        with_interest.0, with_interest.1, without_interest.0, without_interest.1
+    );
+
+    assert!(with_interest.0 < without_interest.0);
+    assert!(with_interest.1 < without_interest.1);
+}